@@ -0,0 +1,36 @@
+//! Membandingkan jalur skalar dan SIMD untuk pemetaan brightness.
+//!
+//! Jalankan dengan `cargo bench --features simd --bench simd`.
+
+use aspix::{AsciiConfig, AsciiConverter};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use image::{DynamicImage, Rgb, RgbImage};
+
+fn brightness_mapping(c: &mut Criterion) {
+    // Ukuran sumber sama dengan grid agar resize tidak mendominasi waktu
+    let (width, height) = (320, 160);
+    let image = DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |x, y| {
+        Rgb([(x % 256) as u8, (y % 256) as u8, ((x + y) % 256) as u8])
+    }));
+
+    let mut group = c.benchmark_group("brightness_mapping");
+    for use_color in [false, true] {
+        for use_simd in [false, true] {
+            let converter = AsciiConverter::with_config(AsciiConfig {
+                width,
+                height,
+                use_color,
+                use_simd,
+                ..Default::default()
+            });
+            let name = format!("{}{}", if use_simd { "simd" } else { "scalar" }, if use_color { "_color" } else { "" });
+            group.bench_with_input(BenchmarkId::from_parameter(name), &image, |b, image| {
+                b.iter(|| converter.convert_image_to_art(black_box(image)).unwrap())
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, brightness_mapping);
+criterion_main!(benches);
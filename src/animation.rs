@@ -0,0 +1,343 @@
+//! Konversi animasi dari sumber frame yang dapat dipasang (GIF, video, webcam, generator).
+//!
+//! Semua sumber cukup mengimplementasikan `FrameSource`, lalu
+//! `AnimationConverter` menangani loop konversinya.
+
+use crate::{AsciiArt, AsciiConfig, AsciiConverter, ResourceLimits, SubtitleTrack, TemporalSmoothing, CAPTION_COLOR};
+use image::codecs::gif::GifDecoder;
+use image::{AnimationDecoder, DynamicImage, ImageDecoder};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Durasi frame default ketika sumber tidak menyediakan informasi waktu (10 fps).
+pub const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// Sumber frame untuk konversi animasi.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::FrameSource;
+/// use image::{DynamicImage, GrayImage, Luma};
+/// use std::time::Duration;
+///
+/// /// Generator gradasi yang bergeser setiap frame.
+/// struct Sweep { frame: u32 }
+///
+/// impl FrameSource for Sweep {
+///     fn next_frame(&mut self) -> Option<DynamicImage> {
+///         if self.frame == 10 {
+///             return None;
+///         }
+///         let offset = self.frame * 25;
+///         self.frame += 1;
+///         Some(DynamicImage::ImageLuma8(GrayImage::from_fn(64, 32, |x, _| Luma([(x * 4 + offset) as u8]))))
+///     }
+///
+///     fn frame_delay(&self) -> Duration {
+///         Duration::from_millis(40)
+///     }
+/// }
+/// ```
+pub trait FrameSource {
+    /// Mengambil frame berikutnya, atau `None` jika sumber sudah habis.
+    fn next_frame(&mut self) -> Option<DynamicImage>;
+
+    /// Durasi tampil frame yang terakhir dikembalikan oleh `next_frame`.
+    fn frame_delay(&self) -> Duration {
+        DEFAULT_FRAME_DELAY
+    }
+}
+
+/// Satu frame hasil konversi animasi.
+///
+/// * `art` - Grid hasil konversi frame
+/// * `content` - Hasil render sesuai konfigurasi (teks, HTML, atau ANSI)
+/// * `delay` - Durasi tampil frame
+#[derive(Debug, Clone)]
+pub struct AsciiFrame {
+    pub art: AsciiArt,
+    pub content: String,
+    pub delay: Duration,
+}
+
+/// Sumber frame dari daftar gambar yang sudah ada di memory.
+#[derive(Debug, Clone)]
+pub struct ImageSequence {
+    frames: VecDeque<(DynamicImage, Duration)>,
+    current_delay: Duration,
+}
+
+impl ImageSequence {
+    /// Membuat sumber dari daftar gambar dengan durasi yang sama untuk setiap frame.
+    pub fn new(frames: Vec<DynamicImage>, delay: Duration) -> Self {
+        Self {
+            frames: frames.into_iter().map(|frame| (frame, delay)).collect(),
+            current_delay: delay,
+        }
+    }
+
+    /// Membuat sumber dari pasangan gambar dan durasinya masing-masing.
+    pub fn with_delays(frames: Vec<(DynamicImage, Duration)>) -> Self {
+        Self {
+            frames: frames.into(),
+            current_delay: DEFAULT_FRAME_DELAY,
+        }
+    }
+}
+
+impl FrameSource for ImageSequence {
+    fn next_frame(&mut self) -> Option<DynamicImage> {
+        let (frame, delay) = self.frames.pop_front()?;
+        self.current_delay = delay;
+        Some(frame)
+    }
+
+    fn frame_delay(&self) -> Duration {
+        self.current_delay
+    }
+}
+
+/// Sumber frame dari file GIF animasi.
+///
+/// Frame didekode penuh saat dibuka; setiap frame sudah dikomposisi ke ukuran kanvas GIF.
+pub struct GifSource {
+    sequence: ImageSequence,
+}
+
+impl GifSource {
+    /// Membuka file GIF animasi.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GifSource)` - Sumber frame jika berhasil
+    /// * `Err(String)` - Pesan error jika file gagal dibuka atau didekode
+    pub fn open(path: &str) -> Result<Self, String> {
+        Self::open_with_limits(path, &ResourceLimits::default())
+    }
+
+    /// Membuka file GIF animasi dengan batas sumber daya.
+    ///
+    /// Ukuran file dan dimensi kanvas diperiksa sebelum decode, dan alokasi
+    /// decoder untuk setiap frame dibatasi sesuai `max_pixels`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GifSource)` - Sumber frame jika berhasil
+    /// * `Err(String)` - Pesan error jika file gagal dibuka, didekode, atau melampaui `limits`
+    pub fn open_with_limits(path: &str, limits: &ResourceLimits) -> Result<Self, String> {
+        limits.check_file(Path::new(path))?;
+        let file = File::open(path).map_err(|e| format!("Gagal membuka GIF: {}", e))?;
+        let mut decoder = GifDecoder::new(BufReader::new(file))
+            .map_err(|e| format!("Gagal mendekode GIF: {}", e))?;
+        decoder
+            .set_limits(limits.decoder_limits())
+            .map_err(|e| format!("Gagal mendekode GIF: {}", e))?;
+
+        let mut frames = Vec::new();
+        for frame in decoder.into_frames() {
+            let frame = frame.map_err(|e| format!("Gagal mendekode frame GIF: {}", e))?;
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            let delay = if numer == 0 {
+                DEFAULT_FRAME_DELAY
+            } else {
+                Duration::from_secs_f64(numer as f64 / denom.max(1) as f64 / 1000.0)
+            };
+            frames.push((DynamicImage::ImageRgba8(frame.into_buffer()), delay));
+        }
+
+        Ok(Self {
+            sequence: ImageSequence::with_delays(frames),
+        })
+    }
+}
+
+impl FrameSource for GifSource {
+    fn next_frame(&mut self) -> Option<DynamicImage> {
+        self.sequence.next_frame()
+    }
+
+    fn frame_delay(&self) -> Duration {
+        self.sequence.frame_delay()
+    }
+}
+
+/// Converter animasi yang menjalankan `AsciiConverter` untuk setiap frame dari `FrameSource`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AnimationConverter, AsciiConfig, ImageSequence};
+/// use image::DynamicImage;
+/// use std::time::Duration;
+///
+/// let frames = vec![DynamicImage::new_rgb8(16, 8); 3];
+/// let mut source = ImageSequence::new(frames, Duration::from_millis(50));
+/// let converter = AnimationConverter::new(AsciiConfig { width: 8, height: 4, ..Default::default() });
+///
+/// let ascii_frames = converter.convert_all(&mut source).unwrap();
+/// assert_eq!(ascii_frames.len(), 3);
+/// assert_eq!(ascii_frames[0].delay, Duration::from_millis(50));
+/// ```
+pub struct AnimationConverter {
+    converter: AsciiConverter,
+    subtitles: Option<SubtitleTrack>,
+    elapsed_nanos: AtomicU64,
+    smoothing: Option<TemporalSmoothing>,
+    /// Grid frame sebelumnya (sebelum line art, orientasi, dan caption) untuk penghalusan
+    previous: Mutex<Option<AsciiArt>>,
+}
+
+impl AnimationConverter {
+    /// Membuat converter animasi dengan konfigurasi yang dipakai untuk semua frame.
+    pub fn new(config: AsciiConfig) -> Self {
+        Self {
+            converter: AsciiConverter::with_config(config),
+            subtitles: None,
+            elapsed_nanos: AtomicU64::new(0),
+            smoothing: None,
+            previous: Mutex::new(None),
+        }
+    }
+
+    /// Mengembalikan konfigurasi yang dipakai untuk semua frame.
+    pub fn config(&self) -> &AsciiConfig {
+        self.converter.config()
+    }
+
+    /// Menempelkan subtitle pada baris bawah setiap frame sesuai waktunya.
+    ///
+    /// Waktu frame dihitung dari jumlah durasi frame sebelumnya sejak converter
+    /// dibuat, sejak `rewind` terakhir, atau sejak awal `convert_all`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AnimationConverter, AsciiConfig, ImageSequence, SubtitleTrack};
+    /// use image::DynamicImage;
+    /// use std::time::Duration;
+    ///
+    /// let track = SubtitleTrack::parse("1\n00:00:00,100 --> 00:00:00,200\nHai\n").unwrap();
+    /// let converter = AnimationConverter::new(AsciiConfig { width: 8, height: 4, ..Default::default() })
+    ///     .with_subtitles(track);
+    ///
+    /// let mut source = ImageSequence::new(vec![DynamicImage::new_rgb8(16, 8); 3], Duration::from_millis(100));
+    /// let frames = converter.convert_all(&mut source).unwrap();
+    /// assert!(!frames[0].content.contains("Hai"));
+    /// assert!(frames[1].content.ends_with("  Hai   \n"));
+    /// assert!(!frames[2].content.contains("Hai"));
+    ///
+    /// // Konversi ulang dimulai lagi dari waktu nol
+    /// let mut replay = ImageSequence::new(vec![DynamicImage::new_rgb8(16, 8); 3], Duration::from_millis(100));
+    /// assert_eq!(converter.convert_all(&mut replay).unwrap()[1].content, frames[1].content);
+    /// ```
+    pub fn with_subtitles(mut self, track: SubtitleTrack) -> Self {
+        self.subtitles = Some(track);
+        self
+    }
+
+    /// Mengaktifkan penghalusan temporal antar frame untuk mengurangi flicker.
+    ///
+    /// Frame harus dikonversi berurutan; `rewind` juga melupakan frame sebelumnya.
+    ///
+    /// # Examples
+    ///
+    /// Sprite pixel art tetap dipetakan satu piksel per dua sel saat dihaluskan.
+    ///
+    /// ```rust
+    /// use aspix::{AnimationConverter, AsciiConfig, ImageSequence, TemporalSmoothing};
+    /// use image::{DynamicImage, Rgb, RgbImage};
+    /// use std::time::Duration;
+    ///
+    /// let sprite = RgbImage::from_fn(4, 2, |x, y| if (x + y) % 2 == 0 { Rgb([0, 0, 0]) } else { Rgb([255, 255, 255]) });
+    /// let mut source = ImageSequence::new(vec![DynamicImage::ImageRgb8(sprite); 2], Duration::from_millis(100));
+    ///
+    /// let converter = AnimationConverter::new(AsciiConfig { pixel_art: true, ..Default::default() })
+    ///     .with_smoothing(TemporalSmoothing::Blend { alpha: 0.5 });
+    /// let frames = converter.convert_all(&mut source).unwrap();
+    /// assert_eq!(frames[1].art.to_text(), "@@  @@  \n  @@  @@\n");
+    /// ```
+    pub fn with_smoothing(mut self, smoothing: TemporalSmoothing) -> Self {
+        self.smoothing = Some(smoothing);
+        self
+    }
+
+    /// Mengembalikan waktu animasi ke awal, misalnya sebelum memutar ulang sumber.
+    pub fn rewind(&self) {
+        self.elapsed_nanos.store(0, Ordering::Relaxed);
+        *self.previous.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Mengkonversi frame berikutnya dari sumber.
+    ///
+    /// Waktu frame (untuk subtitle) dan frame sebelumnya (untuk penghalusan)
+    /// berlanjut dari panggilan sebelumnya; panggil `rewind` sebelum memutar
+    /// sumber baru atau memutar ulang sumber yang sama.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(Ok(AsciiFrame))` - Frame hasil konversi
+    /// * `Some(Err(String))` - Pesan error jika konversi frame gagal
+    /// * `None` - Jika sumber sudah habis
+    pub fn convert_next<S: FrameSource + ?Sized>(&self, source: &mut S) -> Option<Result<AsciiFrame, String>> {
+        let image = source.next_frame()?;
+        let delay = source.frame_delay();
+        let start = Duration::from_nanos(
+            self.elapsed_nanos
+                .fetch_add(delay.as_nanos() as u64, Ordering::Relaxed),
+        );
+        Some(self.convert_frame(&image, delay, start))
+    }
+
+    /// Mengkonversi semua frame dari sumber hingga habis.
+    ///
+    /// Waktu animasi dimulai dari awal (`rewind`) sehingga hasilnya tidak bergantung
+    /// pada konversi sebelumnya. Jangan gunakan untuk sumber tanpa akhir seperti
+    /// webcam; gunakan `convert_next` dalam loop.
+    pub fn convert_all<S: FrameSource + ?Sized>(&self, source: &mut S) -> Result<Vec<AsciiFrame>, String> {
+        self.rewind();
+        let mut frames = Vec::new();
+        while let Some(frame) = self.convert_next(source) {
+            frames.push(frame?);
+        }
+        Ok(frames)
+    }
+
+    /// Mengkonversi satu gambar yang mulai tampil pada `start` menjadi frame.
+    fn convert_frame(&self, image: &DynamicImage, delay: Duration, start: Duration) -> Result<AsciiFrame, String> {
+        let mut art = match &self.smoothing {
+            Some(smoothing) => self.smoothed_art(image, smoothing)?,
+            None => self.converter.convert_image_to_art(image)?,
+        };
+        if let Some(caption) = self.subtitles.as_ref().and_then(|track| track.text_at(start)) {
+            art.overlay_caption(&caption, CAPTION_COLOR);
+        }
+        let content = self.converter.render_art(&art);
+        Ok(AsciiFrame { art, content, delay })
+    }
+
+    /// Mengkonversi gambar dengan penghalusan terhadap frame sebelumnya.
+    ///
+    /// Penghalusan diterapkan pada grid mentah, sebelum line art dan orientasi,
+    /// agar glyph dapat dipilih ulang dari brightness. Tahap lain (termasuk mode
+    /// `pixel_art` dan `limits.timeout`) sama dengan konversi tanpa penghalusan.
+    fn smoothed_art(&self, image: &DynamicImage, smoothing: &TemporalSmoothing) -> Result<AsciiArt, String> {
+        let mut smooth = |converter: &AsciiConverter, mut art: AsciiArt| {
+            let mut previous = self.previous.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(before) = previous.as_ref() {
+                let glyph = converter.glyph_mapper();
+                art = smoothing.apply(art, before, glyph.as_ref().map(|glyph| glyph as &dyn Fn(f32) -> char));
+            }
+            *previous = Some(art.clone());
+            art
+        };
+        self.converter
+            .art_adjusted(image, Instant::now(), None, None, &mut smooth)
+            .map_err(String::from)
+    }
+}
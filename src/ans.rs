@@ -0,0 +1,219 @@
+//! Ekspor format scene art ANSI: `.ANS` dan `.XB` (XBin), lengkap dengan record SAUCE.
+//!
+//! File hasil dapat dibuka di editor seperti PabloDraw atau Moebius dan
+//! ditampilkan oleh software BBS. Glyph dan warna selalu dibatasi ke CP437 dan
+//! palet 16 warna VGA (lihat `AsciiArt::apply_retro`).
+
+use crate::motd::utc_date_time;
+use crate::retro::{cp437_byte, nearest_vga_index};
+use crate::{AsciiArt, AsciiConverter};
+use std::time::Instant;
+
+/// Byte EOF (Ctrl-Z) pemisah data dan record SAUCE.
+const EOF_MARKER: u8 = 0x1A;
+
+/// Panjang satu baris komentar SAUCE dan jumlah baris maksimumnya.
+pub(crate) const SAUCE_COMMENT_WIDTH: usize = 64;
+const SAUCE_MAX_COMMENTS: usize = 255;
+
+/// Tinggi font VGA standar yang ditulis pada header XBin.
+const XBIN_FONT_HEIGHT: u8 = 16;
+
+/// Urutan warna SGR ANSI ke indeks atribut CGA/VGA (biru dan merah bertukar).
+const ANSI_TO_CGA: [u8; 8] = [0, 4, 2, 6, 1, 5, 3, 7];
+
+/// Metadata record SAUCE yang ditambahkan di akhir file.
+///
+/// Teks dienkode ke CP437 lalu dipotong sesuai panjang field SAUCE
+/// (judul 35, penulis 20, grup 20 byte).
+///
+/// * `title` - Judul karya
+/// * `author` - Nama atau handle pembuat
+/// * `group` - Nama grup art
+/// * `date` - Tanggal `CCYYMMDD`; `None` memakai tanggal UTC hari ini
+/// * `comments` - Baris komentar (blok `COMNT`), masing-masing dipotong menjadi 64 byte;
+///   paling banyak 255 baris
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::SauceRecord;
+///
+/// let sauce = SauceRecord {
+///     title: "Kota Malam".to_string(),
+///     author: "fanny".to_string(),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SauceRecord {
+    pub title: String,
+    pub author: String,
+    pub group: String,
+    pub date: Option<String>,
+    pub comments: Vec<String>,
+}
+
+impl SauceRecord {
+    /// Menyusun blok komentar (jika ada) diikuti record SAUCE 128 byte.
+    ///
+    /// `data_type`/`file_type` mengikuti spesifikasi SAUCE 00 (1/1 untuk ANSi,
+    /// 6/0 untuk XBin); `width` dan `height` ditulis ke `TInfo1`/`TInfo2`.
+    fn to_bytes(&self, file_size: usize, data_type: u8, file_type: u8, width: u16, height: u16) -> Vec<u8> {
+        let date = self
+            .date
+            .clone()
+            .unwrap_or_else(|| utc_date_time().0.replace('-', ""));
+
+        let comments = &self.comments[..self.comments.len().min(SAUCE_MAX_COMMENTS)];
+        let mut record = Vec::with_capacity(128 + 5 + comments.len() * SAUCE_COMMENT_WIDTH);
+        if !comments.is_empty() {
+            record.extend_from_slice(b"COMNT");
+            for comment in comments {
+                push_field(&mut record, comment, SAUCE_COMMENT_WIDTH, b' ');
+            }
+        }
+        record.extend_from_slice(b"SAUCE00");
+        push_field(&mut record, &self.title, 35, b' ');
+        push_field(&mut record, &self.author, 20, b' ');
+        push_field(&mut record, &self.group, 20, b' ');
+        push_field(&mut record, &date, 8, b' ');
+        record.extend_from_slice(&(file_size as u32).to_le_bytes());
+        record.push(data_type);
+        record.push(file_type);
+        record.extend_from_slice(&width.to_le_bytes());
+        record.extend_from_slice(&height.to_le_bytes());
+        // TInfo3 dan TInfo4, jumlah baris komentar, lalu TFlags
+        record.extend_from_slice(&[0; 4]);
+        record.push(comments.len() as u8);
+        record.push(0);
+        push_field(&mut record, "IBM VGA", 22, 0);
+        record
+    }
+}
+
+/// Menulis teks CP437 dengan panjang tetap, dipotong atau diisi `padding`.
+fn push_field(record: &mut Vec<u8>, text: &str, length: usize, padding: u8) {
+    let mut bytes: Vec<u8> = text.chars().map(|ch| cp437_byte(ch).unwrap_or(b'?')).take(length).collect();
+    bytes.resize(length, padding);
+    record.extend_from_slice(&bytes);
+}
+
+impl AsciiArt {
+    /// Mengekspor grid sebagai file `.ANS` (CP437 + escape ANSI.SYS) dengan record SAUCE.
+    ///
+    /// Baris diakhiri CRLF; lebar dan tinggi grid dicatat pada SAUCE sehingga
+    /// viewer dapat menampilkan art selebar selain 80 kolom.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, SauceRecord};
+    ///
+    /// let cell = Cell { ch: '█', brightness: 0.0, color: [170, 0, 0] };
+    /// let art = AsciiArt::new(2, 1, vec![cell; 2]).unwrap();
+    /// let sauce = SauceRecord { date: Some("20240101".to_string()), ..Default::default() };
+    /// let ans = art.to_ans(&sauce);
+    /// assert!(ans.starts_with(b"\x1b[0;31m\xdb\xdb\x1b[0m\r\n\x1a"));
+    /// assert_eq!(&ans[ans.len() - 128..ans.len() - 121], b"SAUCE00");
+    /// assert_eq!(ans.len(), 15 + 1 + 128);
+    /// ```
+    pub fn to_ans(&self, sauce: &SauceRecord) -> Vec<u8> {
+        let mut art = self.clone();
+        art.apply_retro();
+
+        let mut output = Vec::new();
+        for ch in art.to_ansi16().chars() {
+            match ch {
+                '\n' => output.extend_from_slice(b"\r\n"),
+                '\x1b' => output.push(0x1B),
+                ch => output.push(cp437_byte(ch).unwrap_or(b'?')),
+            }
+        }
+        let file_size = output.len();
+        output.push(EOF_MARKER);
+        output.extend(sauce.to_bytes(file_size, 1, 1, clamp_u16(self.width()), clamp_u16(self.height())));
+        output
+    }
+
+    /// Mengekspor grid sebagai file XBin (`.XB`) dengan record SAUCE.
+    ///
+    /// Setiap sel disimpan sebagai pasangan byte karakter CP437 dan atribut
+    /// VGA (foreground di nibble bawah, latar hitam), tanpa font, palet, atau
+    /// kompresi tertanam.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, SauceRecord};
+    ///
+    /// let cell = Cell { ch: '#', brightness: 0.5, color: [85, 85, 255] };
+    /// let art = AsciiArt::new(3, 2, vec![cell; 6]).unwrap();
+    /// let xbin = art.to_xbin(&SauceRecord::default());
+    /// assert_eq!(&xbin[..11], b"XBIN\x1a\x03\x00\x02\x00\x10\x00");
+    /// assert_eq!(&xbin[11..13], &[b'#', 9]);
+    /// ```
+    pub fn to_xbin(&self, sauce: &SauceRecord) -> Vec<u8> {
+        let mut art = self.clone();
+        art.apply_retro();
+
+        let mut output = b"XBIN\x1a".to_vec();
+        output.extend_from_slice(&clamp_u16(art.width()).to_le_bytes());
+        output.extend_from_slice(&clamp_u16(art.height()).to_le_bytes());
+        // Tanpa palet, font, kompresi, maupun mode iCE
+        output.extend_from_slice(&[XBIN_FONT_HEIGHT, 0]);
+        for cell in art.cells() {
+            let index = nearest_vga_index(cell.color);
+            output.push(cp437_byte(cell.ch).unwrap_or(b'?'));
+            output.push(ANSI_TO_CGA[index % 8] + if index >= 8 { 8 } else { 0 });
+        }
+        let file_size = output.len();
+        output.push(EOF_MARKER);
+        output.extend(sauce.to_bytes(file_size, 6, 0, 0, 0));
+        output
+    }
+}
+
+impl AsciiConverter {
+    /// Mengkonversi gambar menjadi file `.ANS` dengan record SAUCE.
+    ///
+    /// Gunakan `retro: Some(RetroTerminal::default())` agar grid juga dibatasi ke 80×25.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, SauceRecord};
+    ///
+    /// let converter = AsciiConverter::new(80, 25);
+    /// if let Ok(ans) = converter.convert_to_ans("logo.png", &SauceRecord::default()) {
+    ///     std::fs::write("logo.ans", ans).unwrap();
+    /// }
+    /// ```
+    pub fn convert_to_ans(&self, image_path: &str, sauce: &SauceRecord) -> Result<Vec<u8>, String> {
+        let (art, sauce) = self.art_with_sauce(image_path, sauce)?;
+        Ok(art.to_ans(&sauce))
+    }
+
+    /// Mengkonversi gambar menjadi file XBin (`.XB`) dengan record SAUCE.
+    pub fn convert_to_xbin(&self, image_path: &str, sauce: &SauceRecord) -> Result<Vec<u8>, String> {
+        let (art, sauce) = self.art_with_sauce(image_path, sauce)?;
+        Ok(art.to_xbin(&sauce))
+    }
+
+    /// Mengkonversi gambar dan, jika `embed_metadata` aktif, melengkapi SAUCE dengan metadata sumber.
+    fn art_with_sauce(&self, image_path: &str, sauce: &SauceRecord) -> Result<(AsciiArt, SauceRecord), String> {
+        let started = Instant::now();
+        let (img, metadata) = self.open_with_metadata(image_path)?;
+        let art = self.art_since(&img, started, None)?;
+        let sauce = match metadata {
+            Some(metadata) => metadata.sauce(sauce),
+            None => sauce.clone(),
+        };
+        Ok((art, sauce))
+    }
+}
+
+/// Membatasi dimensi grid ke rentang field u16.
+fn clamp_u16(value: u32) -> u16 {
+    value.min(u16::MAX as u32) as u16
+}
@@ -0,0 +1,480 @@
+//! Hasil konversi terstruktur berupa grid sel.
+//!
+//! `AsciiArt` menyimpan karakter, brightness, dan warna setiap sel sehingga
+//! hasil konversi dapat dianalisis atau di-render ulang ke berbagai format
+//! tanpa memproses gambar dari awal.
+
+use crate::palette::median_cut;
+use crate::OutputFormat;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use unicode_width::UnicodeWidthChar;
+
+/// Awal dokumen HTML hingga tag `<body>`.
+pub(crate) const HTML_HEAD: &str = "<!DOCTYPE html>\n<html>\n<head>\n<style>\n\
+    body { background-color: #000; color: #fff; margin: 0; padding: 10px; }\n\
+    pre { font-family: monospace; font-size: 10px; line-height: 0.9; }\n\
+    </style>\n</head>\n<body>\n";
+
+/// Satu sel pada grid ASCII art.
+///
+/// * `ch` - Karakter yang ditampilkan
+/// * `brightness` - Brightness rata-rata blok sumber (0.0 - 1.0, sebelum `invert`)
+/// * `color` - Warna RGB sel sesuai `color_mode`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cell {
+    pub ch: char,
+    pub brightness: f32,
+    pub color: [u8; 3],
+}
+
+/// Gaya pemisah baris untuk output teks.
+///
+/// * `Lf` - `\n` (Unix, default)
+/// * `CrLf` - `\r\n` (Windows)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    /// Representasi string dari pemisah baris.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Opsi aksesibilitas untuk output HTML.
+///
+/// * `description` - Deskripsi gambar untuk pembaca layar (`aria-label`)
+/// * `caption` - Caption yang ditampilkan di bawah art (`<figcaption>`)
+/// * `text_fallback` - Menyertakan salinan teks biasa yang mudah disalin
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HtmlAccessibility {
+    pub description: String,
+    pub caption: Option<String>,
+    pub text_fallback: bool,
+}
+
+/// Grid sel hasil konversi dalam urutan baris (row-major).
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiArt, Cell};
+///
+/// let cell = Cell { ch: '#', brightness: 0.2, color: [255, 0, 0] };
+/// let art = AsciiArt::new(2, 1, vec![cell; 2]).unwrap();
+/// assert_eq!(art.to_text(), "##\n");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsciiArt {
+    width: u32,
+    height: u32,
+    cells: Vec<Cell>,
+}
+
+impl AsciiArt {
+    /// Membuat grid dari daftar sel berurutan baris.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AsciiArt)` - Jika jumlah sel sama dengan `width × height`
+    /// * `Err(String)` - Pesan error jika jumlah sel tidak cocok
+    pub fn new(width: u32, height: u32, cells: Vec<Cell>) -> Result<Self, String> {
+        if cells.len() != (width as usize) * (height as usize) {
+            return Err(format!(
+                "Jumlah sel ({}) tidak sesuai dengan ukuran {}x{}",
+                cells.len(),
+                width,
+                height
+            ));
+        }
+        Ok(Self { width, height, cells })
+    }
+
+    /// Lebar grid dalam karakter.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Tinggi grid dalam baris.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Semua sel dalam urutan baris.
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    /// Mengambil sel pada posisi `(x, y)`.
+    pub fn get(&self, x: u32, y: u32) -> Option<&Cell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells.get((y * self.width + x) as usize)
+    }
+
+    /// Mengambil sel pada posisi `(x, y)` untuk diubah.
+    pub fn get_mut(&mut self, x: u32, y: u32) -> Option<&mut Cell> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.cells.get_mut((y * self.width + x) as usize)
+    }
+
+    /// Iterator atas baris-baris grid.
+    pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        self.into_iter()
+    }
+
+    /// Me-render grid sebagai teks biasa, satu baris per `\n`.
+    pub fn to_text(&self) -> String {
+        self.to_text_with(LineEnding::Lf, false)
+    }
+
+    /// Me-render grid sebagai teks biasa dengan gaya newline dan trimming tertentu.
+    ///
+    /// Output diselaraskan untuk terminal: glyph selebar dua kolom (CJK, emoji) memakai
+    /// kolom sel berikutnya sehingga sel tersebut dilewati, glyph lebar di kolom terakhir
+    /// serta glyph tanpa lebar (karakter kontrol, combining mark) diganti spasi. Dengan
+    /// begitu setiap baris tetap tepat `width` kolom.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_ending` - Pemisah baris (`\n` atau `\r\n`)
+    /// * `trim_trailing_whitespace` - Menghapus spasi di akhir setiap baris
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, LineEnding};
+    ///
+    /// let cell = |ch| Cell { ch, brightness: 0.5, color: [0, 0, 0] };
+    /// let art = AsciiArt::new(3, 1, vec![cell('#'), cell(' '), cell(' ')]).unwrap();
+    /// assert_eq!(art.to_text_with(LineEnding::CrLf, true), "#\r\n");
+    ///
+    /// let wide = AsciiArt::new(4, 1, vec![cell('漢'), cell('#'), cell('#'), cell('字')]).unwrap();
+    /// assert_eq!(wide.to_text_with(LineEnding::Lf, false), "漢# \n");
+    /// ```
+    pub fn to_text_with(&self, line_ending: LineEnding, trim_trailing_whitespace: bool) -> String {
+        let mut output = String::with_capacity(self.cells.len() + self.height as usize * 2);
+        for row in self.rows() {
+            let start = output.len();
+            output.extend(terminal_glyphs(row).into_iter().map(|(_, ch)| ch));
+            if trim_trailing_whitespace {
+                let trimmed = output[start..].trim_end().len();
+                output.truncate(start + trimmed);
+            }
+            output.push_str(line_ending.as_str());
+        }
+        output
+    }
+
+    /// Me-render grid sebagai dokumen HTML dengan karakter berwarna.
+    pub fn to_html(&self) -> String {
+        self.to_html_with(None)
+    }
+
+    /// Me-render grid sebagai dokumen HTML dengan opsi aksesibilitas.
+    ///
+    /// Jika `accessibility` diisi, art dibungkus `<figure role="img">` dengan
+    /// `aria-label`, span berwarna disembunyikan dari pembaca layar, dan
+    /// (opsional) disertakan caption serta salinan teks biasa di dalam
+    /// `<details>` yang tetap berfungsi tanpa JavaScript.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, HtmlAccessibility};
+    ///
+    /// let art = AsciiArt::new(1, 1, vec![Cell { ch: '<', brightness: 0.5, color: [9, 9, 9] }]).unwrap();
+    /// let html = art.to_html_with(Some(&HtmlAccessibility {
+    ///     description: "Logo perusahaan".to_string(),
+    ///     caption: Some("Logo dalam ASCII".to_string()),
+    ///     text_fallback: true,
+    /// }));
+    /// assert!(html.contains("aria-label=\"Logo perusahaan\""));
+    /// assert!(html.contains("<figcaption>Logo dalam ASCII</figcaption>"));
+    /// assert!(html.contains("&lt;"));
+    /// ```
+    pub fn to_html_with(&self, accessibility: Option<&HtmlAccessibility>) -> String {
+        self.html_document(HTML_HEAD.to_string(), accessibility, |output| self.push_html_spans(output))
+    }
+
+    /// Me-render grid sebagai dokumen HTML dengan palet warna terbatas berbasis class CSS.
+    ///
+    /// Warna sel dikuantisasi menjadi paling banyak `palette_size` warna
+    /// (median cut), masing-masing didefinisikan sekali sebagai class di blok
+    /// `<style>`. Sel berurutan dengan class sama digabung dalam satu span,
+    /// sehingga ukuran file jauh lebih kecil dibanding style inline per karakter.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette_size` - Jumlah maksimum warna (class CSS)
+    /// * `accessibility` - Opsi aksesibilitas, sama seperti `to_html_with`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell};
+    ///
+    /// let red = Cell { ch: '#', brightness: 0.3, color: [250, 0, 0] };
+    /// let dark_red = Cell { color: [240, 10, 0], ..red };
+    /// let blue = Cell { color: [0, 0, 255], ..red };
+    /// let art = AsciiArt::new(3, 1, vec![red, dark_red, blue]).unwrap();
+    ///
+    /// let html = art.to_html_themed(2, None);
+    /// assert!(html.contains(".c0 { color: #f50500; }"));
+    /// assert!(html.contains(".c1 { color: #0000ff; }"));
+    /// assert!(html.contains("<span class=\"c0\">##</span><span class=\"c1\">#</span>"));
+    /// ```
+    pub fn to_html_themed(&self, palette_size: usize, accessibility: Option<&HtmlAccessibility>) -> String {
+        let colors: Vec<[u8; 3]> = self.cells.iter().map(|cell| cell.color).collect();
+        let (palette, classes) = median_cut(&colors, palette_size);
+
+        let rules: String = palette
+            .iter()
+            .enumerate()
+            .map(|(i, [r, g, b])| format!(".c{} {{ color: #{:02x}{:02x}{:02x}; }}\n", i, r, g, b))
+            .collect();
+        let head = HTML_HEAD.replacen("</style>", &format!("{}</style>", rules), 1);
+
+        self.html_document(head, accessibility, |output| self.push_html_class_spans(output, &classes))
+    }
+
+    /// Menyusun dokumen HTML lengkap di sekitar span yang dihasilkan `push_spans`.
+    fn html_document(
+        &self,
+        head: String,
+        accessibility: Option<&HtmlAccessibility>,
+        push_spans: impl Fn(&mut String),
+    ) -> String {
+        let mut output = head;
+
+        match accessibility {
+            Some(options) => {
+                output.push_str(&format!(
+                    "<figure role=\"img\" aria-label=\"{}\">\n<pre aria-hidden=\"true\">\n",
+                    escape_html(&options.description)
+                ));
+                push_spans(&mut output);
+                output.push_str("</pre>\n");
+                if let Some(caption) = &options.caption {
+                    output.push_str(&format!("<figcaption>{}</figcaption>\n", escape_html(caption)));
+                }
+                output.push_str("</figure>\n");
+                if options.text_fallback {
+                    output.push_str("<details>\n<summary>Teks biasa</summary>\n<pre>\n");
+                    output.push_str(&escape_html(&self.to_text()));
+                    output.push_str("</pre>\n</details>\n");
+                }
+                output.push_str("</body>\n</html>");
+            }
+            None => {
+                output.push_str("<pre>\n");
+                push_spans(&mut output);
+                output.push_str("</pre>\n</body>\n</html>");
+            }
+        }
+        output
+    }
+
+    /// Menambahkan span berwarna untuk setiap sel ke output HTML.
+    fn push_html_spans(&self, output: &mut String) {
+        for row in self.rows() {
+            for cell in row {
+                let [r, g, b] = cell.color;
+                output.push_str(&format!(
+                    "<span style=\"color:rgb({},{},{})\">{}</span>", r, g, b, escape_char(cell.ch)
+                ));
+            }
+            output.push_str("<br/>\n");
+        }
+    }
+
+    /// Menambahkan span ber-class untuk setiap run sel dengan warna palet yang sama.
+    fn push_html_class_spans(&self, output: &mut String, classes: &[usize]) {
+        let width = self.width.max(1) as usize;
+        for (row, row_classes) in self.rows().zip(classes.chunks(width)) {
+            let mut start = 0;
+            while start < row.len() {
+                let class = row_classes[start];
+                let end = (start..row.len()).find(|&i| row_classes[i] != class).unwrap_or(row.len());
+                output.push_str(&format!("<span class=\"c{}\">", class));
+                for cell in &row[start..end] {
+                    output.push_str(&escape_char(cell.ch));
+                }
+                output.push_str("</span>");
+                start = end;
+            }
+            output.push_str("<br/>\n");
+        }
+    }
+
+    /// Me-render grid sebagai karakter berwarna dengan escape ANSI truecolor.
+    ///
+    /// Escape warna hanya ditulis ketika warna berubah; sel berurutan dengan
+    /// warna sama memakai state SGR yang sedang aktif. Glyph lebar diselaraskan
+    /// seperti pada `to_text_with`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell};
+    ///
+    /// let red = Cell { ch: '#', brightness: 0.2, color: [255, 0, 0] };
+    /// let art = AsciiArt::new(3, 1, vec![red; 3]).unwrap();
+    /// assert_eq!(art.to_ansi(), "\x1b[38;2;255;0;0m###\x1b[0m\n");
+    /// ```
+    pub fn to_ansi(&self) -> String {
+        self.ansi_rows(terminal_glyphs, 38)
+    }
+
+    /// Me-render grid sebagai spasi dengan warna latar ANSI ("photo mode").
+    pub fn to_ansi_background(&self) -> String {
+        self.ansi_rows(|row| row.iter().map(|cell| (cell, ' ')).collect(), 48)
+    }
+
+    /// Menyusun output ANSI per baris dengan penggabungan warna berurutan.
+    ///
+    /// `glyphs` memilih sel dan karakter yang ditulis untuk setiap baris, dan
+    /// `sgr` adalah kode SGR warna (38 untuk foreground, 48 untuk background).
+    fn ansi_rows(&self, glyphs: for<'a> fn(&'a [Cell]) -> Vec<(&'a Cell, char)>, sgr: u8) -> String {
+        let mut output = String::new();
+        for row in self.rows() {
+            let mut active: Option<[u8; 3]> = None;
+            for (cell, ch) in glyphs(row) {
+                if active != Some(cell.color) {
+                    let [r, g, b] = cell.color;
+                    output.push_str(&format!("\x1b[{};2;{};{};{}m", sgr, r, g, b));
+                    active = Some(cell.color);
+                }
+                output.push(ch);
+            }
+            // Reset di akhir baris agar warna tidak bocor ke baris berikutnya
+            output.push_str("\x1b[0m\n");
+        }
+        output
+    }
+
+    /// Me-render grid ke format berwarna yang dipilih.
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Html => self.to_html(),
+            OutputFormat::Ansi => self.to_ansi(),
+            OutputFormat::AnsiBackground => self.to_ansi_background(),
+            OutputFormat::Emoji => self.to_emoji(),
+            OutputFormat::HtmlTable => self.to_html_table_document(&Default::default()),
+        }
+    }
+}
+
+/// Menampilkan art sebagai teks biasa, sama dengan `to_text`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiArt, Cell};
+///
+/// let cell = Cell { ch: '#', brightness: 0.2, color: [0, 0, 0] };
+/// let art = AsciiArt::new(2, 2, vec![cell; 4]).unwrap();
+/// assert_eq!(format!("{}", art), "##\n##\n");
+/// ```
+impl fmt::Display for AsciiArt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_text())
+    }
+}
+
+/// Iterasi baris demi baris, sama dengan `rows`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiArt, Cell};
+///
+/// let cell = |ch| Cell { ch, brightness: 0.5, color: [0, 0, 0] };
+/// let art = AsciiArt::new(2, 2, vec![cell('a'), cell('b'), cell('c'), cell('d')]).unwrap();
+/// let mut lines = Vec::new();
+/// for row in &art {
+///     lines.push(row.iter().map(|cell| cell.ch).collect::<String>());
+/// }
+/// assert_eq!(lines, ["ab", "cd"]);
+/// ```
+impl<'a> IntoIterator for &'a AsciiArt {
+    type Item = &'a [Cell];
+    type IntoIter = std::slice::Chunks<'a, Cell>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.chunks(self.width.max(1) as usize)
+    }
+}
+
+/// Mengakses sel pada posisi `(x, y)`; panic jika di luar grid (lihat `get` untuk versi aman).
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiArt, Cell};
+///
+/// let cell = Cell { ch: '#', brightness: 0.2, color: [0, 0, 0] };
+/// let mut art = AsciiArt::new(3, 2, vec![cell; 6]).unwrap();
+/// art[(2, 1)].ch = '@';
+/// assert_eq!(art[(2, 1)].ch, '@');
+/// assert_eq!(art.to_text(), "###\n##@\n");
+/// ```
+impl Index<(u32, u32)> for AsciiArt {
+    type Output = Cell;
+
+    fn index(&self, (x, y): (u32, u32)) -> &Cell {
+        let (width, height) = (self.width, self.height);
+        self.get(x, y).unwrap_or_else(|| panic!("Posisi ({}, {}) di luar grid {}x{}", x, y, width, height))
+    }
+}
+
+impl IndexMut<(u32, u32)> for AsciiArt {
+    fn index_mut(&mut self, (x, y): (u32, u32)) -> &mut Cell {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y).unwrap_or_else(|| panic!("Posisi ({}, {}) di luar grid {}x{}", x, y, width, height))
+    }
+}
+
+/// Memilih sel dan glyph yang ditulis agar satu baris tepat selebar jumlah selnya di terminal.
+///
+/// Glyph selebar dua kolom menutupi sel berikutnya (yang dilewati); glyph lebar di kolom
+/// terakhir dan glyph tanpa lebar diganti spasi.
+fn terminal_glyphs(row: &[Cell]) -> Vec<(&Cell, char)> {
+    let mut glyphs = Vec::with_capacity(row.len());
+    let mut cells = row.iter();
+    while let Some(cell) = cells.next() {
+        match cell.ch.width() {
+            Some(1) => glyphs.push((cell, cell.ch)),
+            Some(2) if cells.next().is_some() => glyphs.push((cell, cell.ch)),
+            _ => glyphs.push((cell, ' ')),
+        }
+    }
+    glyphs
+}
+
+/// Meng-escape karakter khusus HTML pada sebuah string.
+pub(crate) fn escape_html(text: &str) -> String {
+    text.chars().map(escape_char).collect()
+}
+
+/// Meng-escape satu karakter untuk disisipkan ke HTML.
+fn escape_char(ch: char) -> String {
+    match ch {
+        '<' => "&lt;".to_string(),
+        '>' => "&gt;".to_string(),
+        '&' => "&amp;".to_string(),
+        '"' => "&quot;".to_string(),
+        _ => ch.to_string(),
+    }
+}
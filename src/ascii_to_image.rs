@@ -0,0 +1,73 @@
+//! Konversi balik: memperkirakan gambar grayscale dari ASCII art.
+//!
+//! Setiap karakter dipetakan kembali ke tingkat kecerahan melalui
+//! `CoverageTable`, lalu digambar sebagai blok piksel seukuran sel. Berguna
+//! untuk preview, membandingkan dua hasil konversi, dan menguji seberapa
+//! setia hasil konversi terhadap gambar asli.
+
+use crate::CoverageTable;
+use image::{GrayImage, Luma};
+
+/// Merekonstruksi gambar grayscale perkiraan dari ASCII art teks biasa.
+///
+/// Cakupan tinta dinormalisasi terhadap cakupan terbesar pada tabel, sehingga
+/// glyph terpadat menjadi hitam dan spasi menjadi putih. Karakter yang tidak
+/// ada di tabel dianggap abu-abu tengah. Baris yang lebih pendek diisi spasi.
+///
+/// # Arguments
+///
+/// * `art` - ASCII art teks biasa (tanpa escape ANSI atau HTML)
+/// * `table` - Tabel cakupan glyph, misalnya `CoverageTable::builtin()`
+/// * `cell_width` - Lebar satu sel karakter dalam piksel output
+/// * `cell_height` - Tinggi satu sel karakter dalam piksel output
+///
+/// # Returns
+///
+/// Gambar grayscale berukuran `(kolom × cell_width, baris × cell_height)`
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{ascii_to_image, CoverageTable};
+///
+/// let image = ascii_to_image("██  \n..##\n", &CoverageTable::builtin(), 2, 4);
+/// assert_eq!(image.dimensions(), (8, 8));
+/// assert_eq!(image.get_pixel(0, 0)[0], 0);
+/// assert_eq!(image.get_pixel(7, 0)[0], 255);
+/// ```
+pub fn ascii_to_image(art: &str, table: &CoverageTable, cell_width: u32, cell_height: u32) -> GrayImage {
+    let rows: Vec<Vec<char>> = art.lines().map(|line| line.chars().collect()).collect();
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0) as u32;
+    let cell_width = cell_width.max(1);
+    let cell_height = cell_height.max(1);
+
+    let max_coverage = table
+        .entries()
+        .iter()
+        .map(|entry| entry.coverage)
+        .fold(0.0f32, f32::max);
+    let max_coverage = if max_coverage > 0.0 { max_coverage } else { 1.0 };
+
+    let mut image = GrayImage::from_pixel(
+        columns * cell_width,
+        rows.len() as u32 * cell_height,
+        Luma([255]),
+    );
+
+    for (row, line) in rows.iter().enumerate() {
+        for (column, &glyph) in line.iter().enumerate() {
+            let coverage = table.coverage(glyph).unwrap_or(max_coverage / 2.0);
+            let value = ((1.0 - coverage / max_coverage).clamp(0.0, 1.0) * 255.0).round() as u8;
+
+            let base_x = column as u32 * cell_width;
+            let base_y = row as u32 * cell_height;
+            for dy in 0..cell_height {
+                for dx in 0..cell_width {
+                    image.put_pixel(base_x + dx, base_y + dy, Luma([value]));
+                }
+            }
+        }
+    }
+
+    image
+}
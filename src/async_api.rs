@@ -0,0 +1,158 @@
+//! API async untuk runtime tokio.
+//!
+//! Inti konversi tetap sinkron dan berat di CPU, sehingga setiap konversi
+//! dijalankan lewat `spawn_blocking` agar thread executor tidak terblokir.
+
+use crate::{AsciiConverter, BatchItem, BatchProcessor};
+use futures::stream::{self, Stream, StreamExt};
+use std::path::PathBuf;
+use tokio::task;
+
+impl AsciiConverter {
+    /// Versi async dari `convert`.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_path` - Path ke file gambar yang akan dikonversi
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - ASCII art dalam bentuk string jika berhasil
+    /// * `Err(String)` - Pesan error jika gagal
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use aspix::AsciiConverter;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let converter = AsciiConverter::new(100, 50);
+    ///     match converter.convert_async("image.jpg").await {
+    ///         Ok(ascii) => println!("{}", ascii),
+    ///         Err(e) => eprintln!("Error: {}", e),
+    ///     }
+    /// }
+    /// ```
+    pub async fn convert_async(&self, image_path: impl Into<PathBuf>) -> Result<String, String> {
+        let converter = self.clone();
+        let path = image_path.into();
+        run_blocking(move || converter.convert(&path.to_string_lossy())).await
+    }
+
+    /// Versi async dari `convert_from_bytes`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiConverter;
+    /// use image::{DynamicImage, ImageOutputFormat};
+    /// use std::io::Cursor;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let mut png = Vec::new();
+    ///     DynamicImage::new_rgb8(8, 8).write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png).unwrap();
+    ///
+    ///     let ascii = AsciiConverter::new(4, 2).convert_bytes_async(png).await.unwrap();
+    ///     assert_eq!(ascii.lines().count(), 2);
+    /// }
+    /// ```
+    pub async fn convert_bytes_async(&self, bytes: Vec<u8>) -> Result<String, String> {
+        let converter = self.clone();
+        run_blocking(move || converter.convert_from_bytes(&bytes)).await
+    }
+
+    /// Mengunduh gambar dari URL lalu mengkonversinya tanpa memblokir executor.
+    ///
+    /// Body dibaca per chunk. Jika `limits.max_bytes` diisi, unduhan ditolak lebih
+    /// awal berdasarkan `Content-Length` dan dihentikan begitu data yang diterima
+    /// melampaui batas, sehingga respons besar tidak pernah ditampung utuh.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - URL HTTP(S) gambar
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - ASCII art dalam bentuk string jika berhasil
+    /// * `Err(String)` - Pesan error jika unduhan atau konversi gagal, atau batas terlampaui
+    pub async fn convert_from_url_async(&self, url: &str) -> Result<String, String> {
+        let limits = self.config().limits;
+        let mut response = reqwest::get(url)
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Gagal mengunduh gambar: {}", e))?;
+        if let Some(length) = response.content_length() {
+            limits.check_bytes(length)?;
+        }
+
+        let mut bytes = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| format!("Gagal mengunduh gambar: {}", e))?
+        {
+            limits.check_bytes((bytes.len() + chunk.len()) as u64)?;
+            bytes.extend_from_slice(&chunk);
+        }
+        self.convert_bytes_async(bytes).await
+    }
+}
+
+impl BatchProcessor {
+    /// Memproses banyak gambar sebagai `Stream` async.
+    ///
+    /// Paling banyak sejumlah thread yang diatur (`with_threads`) konversi
+    /// berjalan bersamaan; hasil dikirim segera setelah selesai sehingga
+    /// dapat tiba tidak berurutan (lihat `BatchItem::index`).
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Daftar path gambar yang akan dikonversi
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use aspix::{AsciiConfig, BatchProcessor};
+    /// use futures::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let processor = BatchProcessor::new(AsciiConfig::default()).with_threads(4);
+    ///     let mut items = processor.process_stream(vec!["a.png", "b.png"]);
+    ///     while let Some(item) = items.next().await {
+    ///         println!("{}: {}", item.path.display(), item.result.is_ok());
+    ///     }
+    /// }
+    /// ```
+    pub fn process_stream<I, P>(&self, paths: I) -> impl Stream<Item = BatchItem> + Send + 'static
+    where
+        I: IntoIterator<Item = P>,
+        I::IntoIter: Send + 'static,
+        P: Into<PathBuf>,
+    {
+        let converter = AsciiConverter::with_config(self.config.clone());
+        stream::iter(paths.into_iter().enumerate())
+            .map(move |(index, path)| {
+                let converter = converter.clone();
+                let path: PathBuf = path.into();
+                async move {
+                    let job_path = path.clone();
+                    let result = run_blocking(move || converter.convert(&job_path.to_string_lossy())).await;
+                    BatchItem { index, path, result }
+                }
+            })
+            .buffer_unordered(self.threads)
+    }
+}
+
+/// Menjalankan pekerjaan berat CPU di thread pool blocking tokio.
+async fn run_blocking<F>(job: F) -> Result<String, String>
+where
+    F: FnOnce() -> Result<String, String> + Send + 'static,
+{
+    task::spawn_blocking(job)
+        .await
+        .map_err(|e| format!("Gagal menjalankan konversi: {}", e))?
+}
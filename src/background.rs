@@ -0,0 +1,132 @@
+//! Deteksi background untuk mengisolasi subjek gambar.
+//!
+//! Piksel background ditandai pada gambar yang sudah di-resize (sebelum
+//! penyesuaian contrast/brightness), lalu sel yang didominasi background
+//! di-render sebagai spasi sehingga subjek lebih menonjol.
+
+use image::{DynamicImage, GenericImageView};
+use std::collections::VecDeque;
+
+/// Metode deteksi background.
+///
+/// * `FloodFill` - Flood-fill dari tepi gambar: piksel yang terhubung ke tepi dan warnanya
+///   berbeda paling banyak `tolerance` (per kanal) dari rata-rata warna keempat sudut
+///   dianggap background. Cocok untuk foto produk atau logo dengan latar polos
+/// * `ChromaKey` - Semua piksel yang warnanya berbeda paling banyak `tolerance` (per kanal)
+///   dari `key` dianggap background, di mana pun letaknya (green screen)
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConverter, AsciiConfig, BackgroundRemoval};
+/// use image::{DynamicImage, Rgb, RgbImage};
+///
+/// // Kotak gelap di tengah latar hijau
+/// let image = RgbImage::from_fn(16, 16, |x, y| {
+///     if (4..12).contains(&x) && (4..12).contains(&y) { Rgb([20, 20, 20]) } else { Rgb([0, 255, 0]) }
+/// });
+/// let converter = AsciiConverter::with_config(AsciiConfig {
+///     width: 4,
+///     height: 4,
+///     background_removal: Some(BackgroundRemoval::ChromaKey { key: [0, 255, 0], tolerance: 40 }),
+///     ..Default::default()
+/// });
+/// let ascii = converter.convert_image(&DynamicImage::ImageRgb8(image)).unwrap();
+/// let lines: Vec<&str> = ascii.lines().collect();
+/// assert_eq!(lines[0], "    ");
+/// assert_eq!(lines[3], "    ");
+/// assert!(!lines[1][1..3].contains(' '));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundRemoval {
+    FloodFill { tolerance: u8 },
+    ChromaKey { key: [u8; 3], tolerance: u8 },
+}
+
+/// Penanda background per piksel, berurutan baris.
+pub(crate) struct BackgroundMask {
+    width: u32,
+    height: u32,
+    mask: Vec<bool>,
+}
+
+impl BackgroundMask {
+    /// Mendeteksi background pada gambar dengan metode tertentu.
+    pub(crate) fn detect(image: &DynamicImage, mode: BackgroundRemoval) -> Self {
+        let (width, height) = image.dimensions();
+        let mask = match mode {
+            BackgroundRemoval::ChromaKey { key, tolerance } => image
+                .pixels()
+                .map(|(_, _, pixel)| within([pixel[0], pixel[1], pixel[2]], key, tolerance))
+                .collect(),
+            BackgroundRemoval::FloodFill { tolerance } => flood_fill(image, tolerance),
+        };
+        Self { width, height, mask }
+    }
+
+    /// Apakah piksel `(x, y)` termasuk background.
+    pub(crate) fn is_background(&self, x: u32, y: u32) -> bool {
+        x < self.width && y < self.height && self.mask[(y * self.width + x) as usize]
+    }
+}
+
+/// Flood-fill dari semua piksel tepi yang cocok dengan warna referensi sudut.
+fn flood_fill(image: &DynamicImage, tolerance: u8) -> Vec<bool> {
+    let (width, height) = image.dimensions();
+    let mut mask = vec![false; (width as usize) * (height as usize)];
+    if width == 0 || height == 0 {
+        return mask;
+    }
+
+    let rgb = |x: u32, y: u32| {
+        let pixel = image.get_pixel(x, y);
+        [pixel[0], pixel[1], pixel[2]]
+    };
+
+    // Referensi: rata-rata warna keempat sudut
+    let corners = [rgb(0, 0), rgb(width - 1, 0), rgb(0, height - 1), rgb(width - 1, height - 1)];
+    let reference = [0, 1, 2].map(|c| (corners.iter().map(|p| p[c] as u32).sum::<u32>() / 4) as u8);
+
+    let mut queue = VecDeque::new();
+    let visit = |x: u32, y: u32, mask: &mut Vec<bool>, queue: &mut VecDeque<(u32, u32)>| {
+        let index = (y * width + x) as usize;
+        if !mask[index] && within(rgb(x, y), reference, tolerance) {
+            mask[index] = true;
+            queue.push_back((x, y));
+        }
+    };
+
+    for x in 0..width {
+        visit(x, 0, &mut mask, &mut queue);
+        visit(x, height - 1, &mut mask, &mut queue);
+    }
+    for y in 0..height {
+        visit(0, y, &mut mask, &mut queue);
+        visit(width - 1, y, &mut mask, &mut queue);
+    }
+
+    while let Some((x, y)) = queue.pop_front() {
+        if x > 0 {
+            visit(x - 1, y, &mut mask, &mut queue);
+        }
+        if x + 1 < width {
+            visit(x + 1, y, &mut mask, &mut queue);
+        }
+        if y > 0 {
+            visit(x, y - 1, &mut mask, &mut queue);
+        }
+        if y + 1 < height {
+            visit(x, y + 1, &mut mask, &mut queue);
+        }
+    }
+
+    mask
+}
+
+/// Apakah selisih setiap kanal warna tidak melebihi toleransi.
+fn within(color: [u8; 3], reference: [u8; 3], tolerance: u8) -> bool {
+    color
+        .iter()
+        .zip(reference.iter())
+        .all(|(&a, &b)| a.abs_diff(b) <= tolerance)
+}
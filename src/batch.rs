@@ -0,0 +1,157 @@
+//! Pipeline batch multi-thread untuk mengkonversi banyak gambar sekaligus.
+//!
+//! Semua worker mengambil pekerjaan dari satu antrian bersama yang dibatasi
+//! kapasitasnya, sehingga worker yang selesai lebih cepat otomatis mengambil
+//! item berikutnya dan pembacaan daftar file tidak berlari jauh di depan
+//! proses konversi (backpressure).
+
+use crate::{AsciiConfig, AsciiConverter};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Hasil konversi satu item dalam batch.
+///
+/// * `index` - Urutan item pada input (hasil dapat tiba tidak berurutan)
+/// * `path` - Path gambar yang diproses
+/// * `result` - ASCII art atau pesan error
+#[derive(Debug, Clone)]
+pub struct BatchItem {
+    pub index: usize,
+    pub path: PathBuf,
+    pub result: Result<String, String>,
+}
+
+/// Pemroses batch dengan thread pool dan antrian terbatas.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, BatchProcessor};
+///
+/// let processor = BatchProcessor::new(AsciiConfig::default())
+///     .with_threads(4)
+///     .with_queue_capacity(64);
+///
+/// for item in processor.process(vec!["a.png", "b.png"]) {
+///     match item.result {
+///         Ok(ascii) => println!("{}:\n{}", item.path.display(), ascii),
+///         Err(e) => eprintln!("{}: {}", item.path.display(), e),
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchProcessor {
+    pub(crate) config: AsciiConfig,
+    pub(crate) threads: usize,
+    queue_capacity: usize,
+}
+
+impl BatchProcessor {
+    /// Membuat pemroses batch dengan jumlah thread sesuai jumlah core
+    /// dan kapasitas antrian dua kali jumlah thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Konfigurasi yang dipakai untuk setiap gambar
+    pub fn new(config: AsciiConfig) -> Self {
+        let threads = thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        Self {
+            config,
+            threads,
+            queue_capacity: threads * 2,
+        }
+    }
+
+    /// Mengatur jumlah thread worker (minimal 1).
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Mengatur kapasitas antrian pekerjaan dan antrian hasil (minimal 1).
+    ///
+    /// Jika antrian hasil penuh karena konsumen lambat, worker akan menunggu.
+    pub fn with_queue_capacity(mut self, capacity: usize) -> Self {
+        self.queue_capacity = capacity.max(1);
+        self
+    }
+
+    /// Memulai pemrosesan dan mengembalikan channel hasil per item.
+    ///
+    /// Fungsi ini langsung kembali; pekerjaan berjalan di thread latar belakang.
+    /// Channel tertutup setelah semua item selesai diproses.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Daftar path gambar yang akan dikonversi
+    pub fn process<I, P>(&self, paths: I) -> Receiver<BatchItem>
+    where
+        I: IntoIterator<Item = P> + Send + 'static,
+        I::IntoIter: Send,
+        P: Into<PathBuf>,
+    {
+        let (job_tx, job_rx) = mpsc::sync_channel::<(usize, PathBuf)>(self.queue_capacity);
+        let (result_tx, result_rx) = mpsc::sync_channel(self.queue_capacity);
+        let job_rx = Arc::new(Mutex::new(job_rx));
+
+        for _ in 0..self.threads {
+            let job_rx = Arc::clone(&job_rx);
+            let result_tx = result_tx.clone();
+            let converter = AsciiConverter::with_config(self.config.clone());
+            thread::spawn(move || worker(converter, job_rx, result_tx));
+        }
+
+        thread::spawn(move || {
+            for (index, path) in paths.into_iter().enumerate() {
+                if job_tx.send((index, path.into())).is_err() {
+                    break;
+                }
+            }
+        });
+
+        result_rx
+    }
+
+    /// Memproses semua item dan mengembalikan hasilnya sesuai urutan input.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Daftar path gambar yang akan dikonversi
+    pub fn process_all<I, P>(&self, paths: I) -> Vec<BatchItem>
+    where
+        I: IntoIterator<Item = P> + Send + 'static,
+        I::IntoIter: Send,
+        P: Into<PathBuf>,
+    {
+        let mut items: Vec<BatchItem> = self.process(paths).into_iter().collect();
+        items.sort_by_key(|item| item.index);
+        items
+    }
+}
+
+/// Loop worker: ambil pekerjaan dari antrian bersama sampai antrian ditutup.
+fn worker(
+    converter: AsciiConverter,
+    jobs: Arc<Mutex<Receiver<(usize, PathBuf)>>>,
+    results: SyncSender<BatchItem>,
+) {
+    loop {
+        // Lock hanya dipegang selama mengambil pekerjaan, bukan selama konversi
+        let job = match jobs.lock() {
+            Ok(receiver) => receiver.recv(),
+            Err(_) => break,
+        };
+        let Ok((index, path)) = job else {
+            break;
+        };
+
+        let result = converter.convert(&path.to_string_lossy());
+        if results.send(BatchItem { index, path, result }).is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Cache hasil konversi berdasarkan isi gambar dan konfigurasi.
+//!
+//! Kunci cache terdiri dari versi crate, konfigurasi, dan bytes gambar,
+//! sehingga aset yang sama dengan pengaturan yang sama langsung dikembalikan
+//! tanpa dekode maupun konversi ulang. Hash FNV-1a 64-bit dari kunci hanya
+//! dipakai untuk memilih slot; kunci lengkap disimpan bersama hasilnya dan
+//! dibandingkan utuh, sehingga input yang kebetulan (atau sengaja dibuat)
+//! memiliki hash sama tidak pernah menerima hasil milik input lain.
+
+use crate::{AsciiConfig, AsciiConverter};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Cache konversi: LRU di memory dengan penyimpanan disk opsional.
+///
+/// Aman dibagi antar thread (misalnya di dalam `Arc` pada web service).
+/// Karena kunci lengkap ikut disimpan, setiap entri memakai memory (dan disk)
+/// sebesar data gambar sumbernya ditambah hasil konversinya.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConverter, ConversionCache};
+/// use image::{DynamicImage, ImageOutputFormat};
+/// use std::io::Cursor;
+///
+/// let mut png = Vec::new();
+/// DynamicImage::new_rgb8(8, 8).write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png).unwrap();
+///
+/// let cache = ConversionCache::new(64);
+/// let converter = AsciiConverter::new(4, 2);
+/// let first = converter.convert_bytes_cached(&png, &cache).unwrap();
+/// let second = converter.convert_bytes_cached(&png, &cache).unwrap();
+/// assert_eq!(first, second);
+/// assert_eq!(cache.len(), 1);
+/// ```
+pub struct ConversionCache {
+    capacity: usize,
+    disk_dir: Option<PathBuf>,
+    state: Mutex<LruState>,
+}
+
+/// Entri cache beserta penanda waktu akses terakhir.
+#[derive(Default)]
+struct LruState {
+    tick: u64,
+    entries: HashMap<u64, CacheEntry>,
+}
+
+/// Satu entri: kunci lengkap, hasil konversi, dan waktu akses terakhir.
+struct CacheEntry {
+    key: Vec<u8>,
+    value: String,
+    last_used: u64,
+}
+
+impl ConversionCache {
+    /// Membuat cache di memory dengan kapasitas maksimum `capacity` entri.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            disk_dir: None,
+            state: Mutex::new(LruState::default()),
+        }
+    }
+
+    /// Menambahkan penyimpanan disk di direktori `dir` (dibuat jika belum ada).
+    ///
+    /// Entri di disk tidak dibatasi kapasitas LRU dan bertahan antar proses.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ConversionCache)` - Cache dengan penyimpanan disk
+    /// * `Err(String)` - Pesan error jika direktori gagal dibuat
+    pub fn with_disk_store(mut self, dir: impl Into<PathBuf>) -> Result<Self, String> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| format!("Gagal membuat direktori cache: {}", e))?;
+        self.disk_dir = Some(dir);
+        Ok(self)
+    }
+
+    /// Jumlah entri di memory.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Apakah cache memory kosong.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Menghapus semua entri di memory (penyimpanan disk tidak disentuh).
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Mengambil hasil untuk kunci, dari memory lalu dari disk.
+    ///
+    /// Entri hanya dipakai jika kunci lengkapnya sama persis dengan `key`.
+    fn get(&self, key: &[u8]) -> Option<String> {
+        let slot = fnv1a(FNV_OFFSET, key);
+        {
+            let mut state = self.lock();
+            state.tick += 1;
+            let tick = state.tick;
+            if let Some(entry) = state.entries.get_mut(&slot).filter(|entry| entry.key == key) {
+                entry.last_used = tick;
+                return Some(entry.value.clone());
+            }
+        }
+
+        let (key_path, value_path) = self.disk_paths(slot)?;
+        if fs::read(key_path).ok()? != key {
+            return None;
+        }
+        let value = fs::read_to_string(value_path).ok()?;
+        self.insert_memory(slot, key.to_vec(), value.clone());
+        Some(value)
+    }
+
+    /// Menyimpan hasil ke memory dan (jika aktif) ke disk.
+    fn insert(&self, key: &[u8], value: &str) {
+        let slot = fnv1a(FNV_OFFSET, key);
+        if let Some((key_path, value_path)) = self.disk_paths(slot) {
+            // Kegagalan menulis cache disk tidak boleh menggagalkan konversi.
+            // Kunci lama dihapus dulu agar hasil baru tidak pernah dipasangkan dengan kunci lama.
+            let _ = fs::remove_file(&key_path);
+            if fs::write(value_path, value).is_ok() {
+                let _ = fs::write(key_path, key);
+            }
+        }
+        self.insert_memory(slot, key.to_vec(), value.to_string());
+    }
+
+    fn insert_memory(&self, slot: u64, key: Vec<u8>, value: String) {
+        let mut state = self.lock();
+        state.tick += 1;
+        let tick = state.tick;
+        if !state.entries.contains_key(&slot) && state.entries.len() >= self.capacity {
+            // Buang entri yang paling lama tidak diakses
+            if let Some(oldest) = state.entries.iter().min_by_key(|(_, entry)| entry.last_used).map(|(k, _)| *k) {
+                state.entries.remove(&oldest);
+            }
+        }
+        state.entries.insert(slot, CacheEntry { key, value, last_used: tick });
+    }
+
+    /// Path file kunci lengkap dan file hasil untuk sebuah slot di penyimpanan disk.
+    fn disk_paths(&self, slot: u64) -> Option<(PathBuf, PathBuf)> {
+        self.disk_dir
+            .as_ref()
+            .map(|dir| (dir.join(format!("{:016x}.key", slot)), dir.join(format!("{:016x}.txt", slot))))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LruState> {
+        // Cache tetap dapat dipakai meskipun thread lain panic saat memegang lock
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl AsciiConverter {
+    /// Mengkonversi file gambar dengan memakai cache.
+    ///
+    /// Converter dengan hook sel (`with_cell_hook`) selalu dikonversi ulang
+    /// karena closure tidak dapat dijadikan bagian dari kunci cache.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - ASCII art dari cache atau hasil konversi baru
+    /// * `Err(String)` - Pesan error jika file gagal dibaca atau dikonversi
+    pub fn convert_cached(&self, image_path: &str, cache: &ConversionCache) -> Result<String, String> {
+        let bytes = fs::read(image_path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+        self.convert_bytes_cached(&bytes, cache)
+    }
+
+    /// Mengkonversi gambar dari bytes dengan memakai cache.
+    pub fn convert_bytes_cached(&self, bytes: &[u8], cache: &ConversionCache) -> Result<String, String> {
+        if self.on_cell.is_some() {
+            return self.convert_from_bytes(bytes);
+        }
+
+        let key = cache_key(self.config(), bytes);
+        if let Some(cached) = cache.get(&key) {
+            return Ok(cached);
+        }
+
+        let ascii = self.convert_from_bytes(bytes)?;
+        cache.insert(&key, &ascii);
+        Ok(ascii)
+    }
+}
+
+impl AsciiConfig {
+    /// Sidik jari konfigurasi: hash FNV-1a 64-bit dari representasi `Debug` seluruh field.
+    ///
+    /// Sama antar proses untuk build yang sama, sehingga dapat disimpan untuk
+    /// memeriksa apakah output lama dibuat dengan pengaturan yang sama. Format
+    /// `Debug` tidak dijamin stabil, jadi nilainya dapat berubah setelah crate
+    /// atau compiler diperbarui; perlakukan perbedaan sebagai "mungkin berubah".
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiConfig;
+    ///
+    /// let config = AsciiConfig::default();
+    /// assert_eq!(config.fingerprint(), AsciiConfig::default().fingerprint());
+    /// assert_ne!(config.fingerprint(), AsciiConfig { width: 80, ..Default::default() }.fingerprint());
+    /// ```
+    pub fn fingerprint(&self) -> u64 {
+        fnv1a(FNV_OFFSET, format!("{:?}", self).as_bytes())
+    }
+}
+
+/// Kunci lengkap: versi crate dan konfigurasi (satu baris masing-masing), lalu bytes gambar.
+///
+/// Versi crate ikut disertakan agar hasil di disk dari versi lain yang
+/// algoritmanya mungkin berbeda tidak dipakai ulang.
+fn cache_key(config: &AsciiConfig, bytes: &[u8]) -> Vec<u8> {
+    let mut key = format!("aspix {}\n{:?}\n", env!("CARGO_PKG_VERSION"), config).into_bytes();
+    key.extend_from_slice(bytes);
+    key
+}
+
+/// Melanjutkan hash FNV-1a 64-bit dari `hash` dengan `bytes`.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |hash, &byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+}
@@ -0,0 +1,242 @@
+//! Kalibrasi densitas karakter berdasarkan font.
+//!
+//! Setiap font terminal menggambar glyph dengan cakupan tinta yang berbeda,
+//! sehingga urutan gelap-terang bawaan bisa terlihat salah pada font tertentu.
+//! Modul ini menyediakan `CoverageTable` yang dapat diisi secara manual,
+//! diukur dari bitmap glyph yang sudah dirender, atau (dengan feature `font`)
+//! diukur langsung dari file font TTF/OTF. Hasilnya dapat diurutkan menjadi
+//! ramp karakter untuk `AsciiConfig::custom_chars`.
+
+use crate::converter::{ASCII_CHARS, DETAILED_ASCII_CHARS, HIGH_DENSITY_CHARS, QUADRANT_CHARS};
+use image::GrayImage;
+
+/// Cakupan tinta dari satu glyph.
+///
+/// * `glyph` - Karakter yang diukur
+/// * `coverage` - Proporsi area sel yang tertutup tinta (0.0 - 1.0)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphCoverage {
+    pub glyph: char,
+    pub coverage: f32,
+}
+
+/// Tabel cakupan tinta untuk sekumpulan glyph.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::CoverageTable;
+///
+/// let table = CoverageTable::from_pairs(&[('.', 0.05), ('@', 0.62), (' ', 0.0), ('+', 0.2)]);
+/// assert_eq!(table.sorted_ramp(), vec!['@', '+', '.', ' ']);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CoverageTable {
+    entries: Vec<GlyphCoverage>,
+}
+
+impl CoverageTable {
+    /// Membuat tabel cakupan kosong.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Membuat tabel dari pasangan `(glyph, coverage)` yang sudah diketahui.
+    ///
+    /// # Arguments
+    ///
+    /// * `pairs` - Daftar pasangan karakter dan cakupan tintanya (0.0 - 1.0)
+    pub fn from_pairs(pairs: &[(char, f32)]) -> Self {
+        let mut table = Self::new();
+        for &(glyph, coverage) in pairs {
+            table.insert(glyph, coverage);
+        }
+        table
+    }
+
+    /// Menambahkan atau memperbarui cakupan sebuah glyph.
+    pub fn insert(&mut self, glyph: char, coverage: f32) {
+        let coverage = coverage.clamp(0.0, 1.0);
+        match self.entries.iter_mut().find(|entry| entry.glyph == glyph) {
+            Some(entry) => entry.coverage = coverage,
+            None => self.entries.push(GlyphCoverage { glyph, coverage }),
+        }
+    }
+
+    /// Mengukur cakupan glyph dari bitmap yang sudah dirender lalu menyimpannya.
+    ///
+    /// Bitmap diperlakukan sebagai mask tinta: nilai 255 berarti piksel
+    /// tertutup penuh, 0 berarti kosong. Bitmap sebaiknya berukuran satu sel
+    /// penuh (termasuk spasi di sekitar glyph) agar hasil antar glyph sebanding.
+    ///
+    /// # Returns
+    ///
+    /// Nilai cakupan yang terukur (0.0 - 1.0)
+    pub fn measure_bitmap(&mut self, glyph: char, bitmap: &GrayImage) -> f32 {
+        let total = (bitmap.width() * bitmap.height()) as f32;
+        let coverage = if total > 0.0 {
+            bitmap.pixels().map(|p| p[0] as f32 / 255.0).sum::<f32>() / total
+        } else {
+            0.0
+        };
+        self.insert(glyph, coverage);
+        coverage
+    }
+
+    /// Mengukur cakupan setiap glyph dalam `glyphs` dari file font.
+    ///
+    /// Setiap glyph dirasterisasi pada sel monospace berukuran `advance × line height`
+    /// dengan tinggi `px_size` piksel.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_bytes` - Isi file font TTF/OTF
+    /// * `glyphs` - Karakter yang akan diukur
+    /// * `px_size` - Ukuran font dalam piksel saat dirasterisasi
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CoverageTable)` - Tabel hasil pengukuran
+    /// * `Err(String)` - Pesan error jika font tidak dapat dibaca
+    #[cfg(feature = "font")]
+    pub fn from_font(font_bytes: &[u8], glyphs: &str, px_size: f32) -> Result<Self, String> {
+        use ab_glyph::{point, Font, FontRef, ScaleFont};
+
+        let font = FontRef::try_from_slice(font_bytes)
+            .map_err(|e| format!("Gagal membaca font: {}", e))?;
+        let scaled = font.as_scaled(px_size);
+
+        let cell_width = scaled.h_advance(font.glyph_id('M')).ceil().max(1.0);
+        let cell_height = (scaled.ascent() - scaled.descent()).ceil().max(1.0);
+        let cell_area = cell_width * cell_height;
+
+        let mut table = Self::new();
+        for glyph in glyphs.chars() {
+            let positioned = font
+                .glyph_id(glyph)
+                .with_scale_and_position(px_size, point(0.0, scaled.ascent()));
+
+            let mut ink = 0.0;
+            if let Some(outlined) = font.outline_glyph(positioned) {
+                outlined.draw(|_, _, c| ink += c);
+            }
+            table.insert(glyph, ink / cell_area);
+        }
+
+        Ok(table)
+    }
+
+    /// Membuat tabel perkiraan untuk semua karakter bawaan Aspix.
+    ///
+    /// Cakupan diturunkan dari posisi karakter pada ramp bawaan (karakter pertama
+    /// dianggap paling padat), sedangkan karakter quadrant dihitung dari jumlah
+    /// sub-blok yang terisi. Cocok sebagai tabel default ketika font target
+    /// tidak diketahui.
+    pub fn builtin() -> Self {
+        let mut table = Self::new();
+
+        // Ramp dasar diutamakan karena paling sering dipakai; glyph yang muncul
+        // di beberapa ramp memakai nilai dari ramp pertama yang memuatnya
+        let basic: Vec<char> = ASCII_CHARS.iter().map(|&b| b as char).collect();
+        let high_density: Vec<char> = HIGH_DENSITY_CHARS.iter().filter_map(|s| s.chars().next()).collect();
+        let detailed: Vec<char> = DETAILED_ASCII_CHARS.iter().map(|&b| b as char).collect();
+        for ramp in [&basic, &high_density, &detailed] {
+            let last = (ramp.len() - 1) as f32;
+            for (i, &glyph) in ramp.iter().enumerate() {
+                if table.coverage(glyph).is_none() {
+                    table.insert(glyph, 1.0 - i as f32 / last);
+                }
+            }
+        }
+
+        for (mask, &glyph) in QUADRANT_CHARS.iter().enumerate() {
+            if table.coverage(glyph).is_none() {
+                table.insert(glyph, (mask as u32).count_ones() as f32 / 4.0);
+            }
+        }
+
+        table
+    }
+
+    /// Mencari cakupan sebuah glyph.
+    pub fn coverage(&self, glyph: char) -> Option<f32> {
+        self.entries
+            .iter()
+            .find(|entry| entry.glyph == glyph)
+            .map(|entry| entry.coverage)
+    }
+
+    /// Mengembalikan entri tabel dalam urutan penyisipan.
+    pub fn entries(&self) -> &[GlyphCoverage] {
+        &self.entries
+    }
+
+    /// Mengurutkan semua glyph dari cakupan terbesar (gelap) ke terkecil (terang).
+    ///
+    /// Urutan ini sama dengan konvensi ramp bawaan sehingga hasilnya dapat
+    /// langsung dipakai sebagai `AsciiConfig::custom_chars`.
+    pub fn sorted_ramp(&self) -> Vec<char> {
+        let mut entries = self.entries.clone();
+        entries.sort_by(|a, b| b.coverage.total_cmp(&a.coverage));
+        entries.into_iter().map(|entry| entry.glyph).collect()
+    }
+
+    /// Memilih `levels` glyph yang cakupannya tersebar paling merata.
+    ///
+    /// Untuk setiap target cakupan yang dibagi rata antara cakupan maksimum dan
+    /// minimum, glyph dengan cakupan terdekat dipilih (tanpa duplikat). Berguna
+    /// untuk membentuk ramp pendek yang gradasinya linear pada font tertentu.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::CoverageTable;
+    ///
+    /// let table = CoverageTable::from_pairs(&[
+    ///     ('@', 0.6), ('#', 0.5), ('%', 0.45), ('+', 0.3), (':', 0.1), (' ', 0.0),
+    /// ]);
+    /// assert_eq!(table.choose_ramp(3), vec!['@', '+', ' ']);
+    /// ```
+    pub fn choose_ramp(&self, levels: usize) -> Vec<char> {
+        let mut sorted = self.entries.clone();
+        sorted.sort_by(|a, b| b.coverage.total_cmp(&a.coverage));
+
+        if levels == 0 || sorted.is_empty() {
+            return Vec::new();
+        }
+        if levels >= sorted.len() {
+            return sorted.into_iter().map(|entry| entry.glyph).collect();
+        }
+
+        let max = sorted[0].coverage;
+        let min = sorted[sorted.len() - 1].coverage;
+        let mut used = vec![false; sorted.len()];
+        let mut ramp = Vec::with_capacity(levels);
+
+        for level in 0..levels {
+            let t = if levels > 1 { level as f32 / (levels - 1) as f32 } else { 0.0 };
+            let target = max - (max - min) * t;
+
+            let best = sorted
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !used[*i])
+                .min_by(|(_, a), (_, b)| {
+                    (a.coverage - target).abs().total_cmp(&(b.coverage - target).abs())
+                })
+                .map(|(i, _)| i);
+
+            if let Some(i) = best {
+                used[i] = true;
+                ramp.push(sorted[i].glyph);
+            }
+        }
+
+        // Jaga urutan gelap → terang meski pemilihan terdekat melompat
+        ramp.sort_by(|a, b| {
+            let coverage = |g: &char| self.entries.iter().find(|e| e.glyph == *g).map(|e| e.coverage).unwrap_or(0.0);
+            coverage(b).total_cmp(&coverage(a))
+        });
+        ramp
+    }
+}
@@ -0,0 +1,138 @@
+//! Penempatan art pada kanvas berukuran tetap, misalnya tepat 80×24 untuk MOTD.
+
+use crate::{AsciiArt, Cell, CoverageTable};
+
+/// Perataan horizontal art di dalam kanvas.
+///
+/// * `Left` - Rata kiri
+/// * `Center` - Di tengah (sisa kolom ganjil diletakkan di kanan)
+/// * `Right` - Rata kanan
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HorizontalAlign {
+    Left,
+    #[default]
+    Center,
+    Right,
+}
+
+/// Perataan vertikal art di dalam kanvas.
+///
+/// * `Top` - Rata atas
+/// * `Middle` - Di tengah (sisa baris ganjil diletakkan di bawah)
+/// * `Bottom` - Rata bawah
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerticalAlign {
+    Top,
+    #[default]
+    Middle,
+    Bottom,
+}
+
+/// Kanvas berukuran tetap tempat art diletakkan.
+///
+/// Art yang lebih kecil diberi padding `fill_char`; art yang lebih besar dipotong
+/// dengan perataan yang sama, sehingga output selalu tepat `width × height` sel.
+///
+/// * `width` - Lebar kanvas dalam karakter
+/// * `height` - Tinggi kanvas dalam baris
+/// * `h_align` - Perataan horizontal
+/// * `v_align` - Perataan vertikal
+/// * `fill_char` - Karakter padding
+///
+/// Jumlah sel kanvas dibatasi `MAX_INTERNAL_PIXELS`; konfigurasi dengan kanvas
+/// yang lebih besar ditolak oleh `AsciiConfig::validate`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiArt, Canvas, Cell, HorizontalAlign, VerticalAlign};
+///
+/// let cell = Cell { ch: '#', brightness: 0.2, color: [0, 0, 0] };
+/// let art = AsciiArt::new(2, 1, vec![cell; 2]).unwrap();
+/// let canvas = Canvas { width: 6, height: 3, fill_char: '.', ..Default::default() };
+/// assert_eq!(art.on_canvas(&canvas).to_text(), "......\n..##..\n......\n");
+///
+/// let corner = Canvas { h_align: HorizontalAlign::Right, v_align: VerticalAlign::Bottom, ..canvas };
+/// assert_eq!(art.on_canvas(&corner).to_text(), "......\n......\n....##\n");
+///
+/// // Melalui konfigurasi, output konversi selalu tepat 80×24
+/// use aspix::{AsciiConfig, AsciiConverter};
+/// use image::DynamicImage;
+///
+/// let config = AsciiConfig { width: 40, height: 10, canvas: Some(Canvas::default()), ..Default::default() };
+/// let motd = AsciiConverter::with_config(config).convert_image(&DynamicImage::new_rgb8(80, 40)).unwrap();
+/// assert_eq!(motd.lines().count(), 24);
+/// assert!(motd.lines().all(|line| line.chars().count() == 80));
+///
+/// // Kanvas raksasa ditolak sebelum sel dialokasikan
+/// use aspix::ConfigIssue;
+///
+/// let huge = AsciiConfig { canvas: Some(Canvas { width: 100_000, height: 100_000, ..Default::default() }), ..Default::default() };
+/// assert_eq!(huge.validate().unwrap_err(), [ConfigIssue::CanvasTooLarge { width: 100_000, height: 100_000 }]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Canvas {
+    pub width: u32,
+    pub height: u32,
+    pub h_align: HorizontalAlign,
+    pub v_align: VerticalAlign,
+    pub fill_char: char,
+}
+
+impl Default for Canvas {
+    /// Membuat kanvas default:
+    /// * width: 80
+    /// * height: 24
+    /// * h_align: Center
+    /// * v_align: Middle
+    /// * fill_char: ' '
+    fn default() -> Self {
+        Self {
+            width: 80,
+            height: 24,
+            h_align: HorizontalAlign::Center,
+            v_align: VerticalAlign::Middle,
+            fill_char: ' ',
+        }
+    }
+}
+
+impl AsciiArt {
+    /// Meletakkan art pada kanvas berukuran tetap.
+    ///
+    /// Sel pengisi berwarna putih dengan brightness dari cakupan tinta `fill_char`
+    /// (seperti `parse_plain`), sehingga spasi dianggap paling terang. Melalui
+    /// konfigurasi, warna pengisi mengikuti `AsciiConfig::theme` (hitam untuk `Theme::Light`).
+    pub fn on_canvas(&self, canvas: &Canvas) -> AsciiArt {
+        self.on_canvas_colored(canvas, [255, 255, 255])
+    }
+
+    /// Seperti `on_canvas`, dengan warna sel pengisi `fill_color`.
+    pub(crate) fn on_canvas_colored(&self, canvas: &Canvas, fill_color: [u8; 3]) -> AsciiArt {
+        let coverage = CoverageTable::builtin().coverage(canvas.fill_char).unwrap_or(0.5);
+        let fill = Cell { ch: canvas.fill_char, brightness: 1.0 - coverage, color: fill_color };
+
+        let left = match canvas.h_align {
+            HorizontalAlign::Left => 0,
+            HorizontalAlign::Center => (canvas.width as i64 - self.width() as i64).div_euclid(2),
+            HorizontalAlign::Right => canvas.width as i64 - self.width() as i64,
+        };
+        let top = match canvas.v_align {
+            VerticalAlign::Top => 0,
+            VerticalAlign::Middle => (canvas.height as i64 - self.height() as i64).div_euclid(2),
+            VerticalAlign::Bottom => canvas.height as i64 - self.height() as i64,
+        };
+
+        let mut cells = Vec::with_capacity(canvas.width as usize * canvas.height as usize);
+        for y in 0..canvas.height as i64 {
+            for x in 0..canvas.width as i64 {
+                let source = match (u32::try_from(x - left), u32::try_from(y - top)) {
+                    (Ok(source_x), Ok(source_y)) => self.get(source_x, source_y),
+                    _ => None,
+                };
+                cells.push(*source.unwrap_or(&fill));
+            }
+        }
+        AsciiArt::new(canvas.width, canvas.height, cells).expect("jumlah sel sesuai ukuran kanvas")
+    }
+}
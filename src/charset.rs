@@ -0,0 +1,233 @@
+//! Set karakter (ramp) beserta validasi dan pengurutan otomatis.
+//!
+//! Ramp disusun dari glyph paling padat (gelap) ke paling kosong (terang).
+//! Ramp kustom dengan urutan yang salah menghasilkan gambar yang tampak
+//! terbalik tanpa pesan error, sehingga modul ini menyediakan pemeriksaan
+//! urutan berdasarkan `CoverageTable` serta pengurutan ulang otomatis, dan
+//! `CharsetRegistry` untuk merujuk ramp lewat nama.
+
+use crate::converter::{ASCII_CHARS, DETAILED_ASCII_CHARS, HIGH_DENSITY_CHARS};
+use crate::CoverageTable;
+use std::collections::BTreeMap;
+
+/// Ramp blok arsir dari penuh ke kosong.
+const BLOCK_RAMP: &str = "█▓▒░ ";
+
+/// Ramp braille berdasarkan jumlah titik (8 sampai 0).
+const BRAILLE_RAMP: &str = "⣿⣷⣶⣦⣤⣄⣀⡀\u{2800}";
+
+/// Ramp yang seluruh glyph-nya ada di code page 437 (DOS).
+const CP437_RAMP: &str = "█▓▒░≡=+:· ";
+
+/// Masalah yang ditemukan saat memvalidasi ramp.
+///
+/// * `Reversed` - Seluruh ramp tersusun terang → gelap; gunakan `auto_sort` atau `invert`
+/// * `OutOfOrder` - Glyph pada `index` lebih padat daripada glyph sebelumnya
+/// * `UnknownGlyph` - Cakupan glyph tidak ada di tabel sehingga urutannya tidak dapat diperiksa
+/// * `Duplicate` - Glyph sudah muncul sebelumnya di ramp
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CharsetWarning {
+    Reversed,
+    OutOfOrder { index: usize, glyph: char },
+    UnknownGlyph { index: usize, glyph: char },
+    Duplicate { index: usize, glyph: char },
+}
+
+/// Ramp karakter dari gelap ke terang.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, Charset, CharsetWarning};
+///
+/// let charset = Charset::new(" .:-=+*#%@");
+/// assert_eq!(charset.validate(), vec![CharsetWarning::Reversed]);
+///
+/// let sorted = charset.auto_sort();
+/// assert_eq!(sorted.as_chars(), &['@', '%', '#', '*', '+', '=', '-', ':', '.', ' ']);
+/// assert!(sorted.validate().is_empty());
+///
+/// let config = AsciiConfig { custom_chars: Some(sorted.into_chars()), ..Default::default() };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Charset {
+    glyphs: Vec<char>,
+}
+
+impl Charset {
+    /// Membuat charset dari string glyph dengan urutan apa adanya.
+    pub fn new(glyphs: &str) -> Self {
+        Self {
+            glyphs: glyphs.chars().collect(),
+        }
+    }
+
+    /// Membuat charset dari daftar glyph.
+    pub fn from_chars(glyphs: Vec<char>) -> Self {
+        Self { glyphs }
+    }
+
+    /// Glyph dalam urutan ramp.
+    pub fn as_chars(&self) -> &[char] {
+        &self.glyphs
+    }
+
+    /// Mengambil daftar glyph, misalnya untuk `AsciiConfig::custom_chars`.
+    pub fn into_chars(self) -> Vec<char> {
+        self.glyphs
+    }
+
+    /// Mengurutkan glyph berdasarkan tabel cakupan bawaan (`CoverageTable::builtin`).
+    pub fn auto_sort(&self) -> Self {
+        self.auto_sort_with(&CoverageTable::builtin())
+    }
+
+    /// Mengurutkan glyph dari cakupan terbesar ke terkecil berdasarkan `table`,
+    /// misalnya tabel yang diukur dari font terminal dengan `CoverageTable::from_font`.
+    ///
+    /// Pengurutan stabil; glyph yang tidak ada di tabel diletakkan di akhir
+    /// dengan urutan semula.
+    pub fn auto_sort_with(&self, table: &CoverageTable) -> Self {
+        let mut known: Vec<(char, f32)> = Vec::new();
+        let mut unknown = Vec::new();
+        for &glyph in &self.glyphs {
+            match table.coverage(glyph) {
+                Some(coverage) => known.push((glyph, coverage)),
+                None => unknown.push(glyph),
+            }
+        }
+        known.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        Self {
+            glyphs: known.into_iter().map(|(glyph, _)| glyph).chain(unknown).collect(),
+        }
+    }
+
+    /// Memeriksa urutan ramp terhadap tabel cakupan bawaan.
+    pub fn validate(&self) -> Vec<CharsetWarning> {
+        self.validate_with(&CoverageTable::builtin())
+    }
+
+    /// Memeriksa urutan ramp terhadap `table`.
+    ///
+    /// Ramp yang seluruhnya terbalik dilaporkan sekali sebagai `Reversed`
+    /// alih-alih sebagai banyak `OutOfOrder`.
+    ///
+    /// # Returns
+    ///
+    /// Daftar peringatan; kosong jika ramp sudah urut dari gelap ke terang
+    pub fn validate_with(&self, table: &CoverageTable) -> Vec<CharsetWarning> {
+        let mut warnings = Vec::new();
+        let mut known = Vec::new();
+
+        for (index, &glyph) in self.glyphs.iter().enumerate() {
+            if self.glyphs[..index].contains(&glyph) {
+                warnings.push(CharsetWarning::Duplicate { index, glyph });
+                continue;
+            }
+            match table.coverage(glyph) {
+                Some(coverage) => known.push((index, glyph, coverage)),
+                None => warnings.push(CharsetWarning::UnknownGlyph { index, glyph }),
+            }
+        }
+
+        let out_of_order: Vec<(usize, char)> = known
+            .windows(2)
+            .filter(|pair| pair[1].2 > pair[0].2)
+            .map(|pair| (pair[1].0, pair[1].1))
+            .collect();
+
+        let reversed = known.len() > 1 && out_of_order.len() == known.len() - 1;
+        if reversed {
+            warnings.insert(0, CharsetWarning::Reversed);
+        } else {
+            warnings.extend(
+                out_of_order
+                    .into_iter()
+                    .map(|(index, glyph)| CharsetWarning::OutOfOrder { index, glyph }),
+            );
+        }
+
+        warnings
+    }
+}
+
+/// Daftar charset bernama, agar file konfigurasi dan CLI dapat merujuk ramp lewat string.
+///
+/// Registry baru sudah berisi ramp bawaan: `standard`, `detailed`,
+/// `high_density`, `blocks`, `braille`, dan `cp437`. Nama tidak
+/// membedakan huruf besar dan kecil.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, Charset, CharsetRegistry};
+///
+/// let mut registry = CharsetRegistry::new();
+/// registry.register("Runes", Charset::new("ᛞᛗᛒᚦᛁ ")).unwrap();
+///
+/// let name = "braille"; // misalnya dari argumen CLI
+/// let charset = registry.get(name).ok_or_else(|| format!("Charset `{}` tidak dikenal", name)).unwrap();
+/// let config = AsciiConfig { custom_chars: Some(charset.as_chars().to_vec()), ..Default::default() };
+///
+/// assert_eq!(registry.get("runes").unwrap().as_chars()[0], 'ᛞ');
+/// assert!(registry.names().contains(&"cp437"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct CharsetRegistry {
+    charsets: BTreeMap<String, Charset>,
+}
+
+impl CharsetRegistry {
+    /// Membuat registry berisi ramp bawaan.
+    pub fn new() -> Self {
+        let builtins = [
+            ("standard", ASCII_CHARS.iter().map(|&b| b as char).collect()),
+            ("detailed", DETAILED_ASCII_CHARS.iter().map(|&b| b as char).collect()),
+            ("high_density", HIGH_DENSITY_CHARS.iter().filter_map(|s| s.chars().next()).collect()),
+            ("blocks", BLOCK_RAMP.chars().collect()),
+            ("braille", BRAILLE_RAMP.chars().collect()),
+            ("cp437", CP437_RAMP.chars().collect()),
+        ];
+        Self {
+            charsets: builtins
+                .into_iter()
+                .map(|(name, glyphs)| (name.to_string(), Charset::from_chars(glyphs)))
+                .collect(),
+        }
+    }
+
+    /// Mendaftarkan charset dengan nama tertentu, menggantikan charset lama bernama sama.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Jika berhasil didaftarkan
+    /// * `Err(String)` - Pesan error jika nama atau charset kosong
+    pub fn register(&mut self, name: &str, charset: Charset) -> Result<(), String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Gagal mendaftarkan charset: nama tidak boleh kosong".to_string());
+        }
+        if charset.as_chars().is_empty() {
+            return Err(format!("Gagal mendaftarkan charset `{}`: ramp tidak boleh kosong", name));
+        }
+        self.charsets.insert(name.to_lowercase(), charset);
+        Ok(())
+    }
+
+    /// Mencari charset berdasarkan nama.
+    pub fn get(&self, name: &str) -> Option<&Charset> {
+        self.charsets.get(&name.trim().to_lowercase())
+    }
+
+    /// Nama semua charset yang terdaftar, urut alfabet.
+    pub fn names(&self) -> Vec<&str> {
+        self.charsets.keys().map(String::as_str).collect()
+    }
+}
+
+impl Default for CharsetRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,111 @@
+//! Pemecahan output menjadi beberapa pesan agar muat di batas platform chat.
+//!
+//! Output hanya dipotong di antara baris, sehingga baris art dan escape ANSI
+//! (yang selalu di-reset di akhir baris) tidak pernah terbelah. Untuk platform
+//! dengan batas per baris seperti IRC, gunakan `max_lines` 1.
+
+use crate::AsciiArt;
+
+/// Batas karakter satu pesan Discord.
+pub const DISCORD_MAX_CHARS: usize = 2000;
+
+/// Satu potongan output beserta labelnya.
+///
+/// * `index` - Urutan potongan, dimulai dari 1
+/// * `total` - Jumlah seluruh potongan
+/// * `label` - Label urutan, misalnya `"2/3"`
+/// * `text` - Isi potongan (baris utuh, termasuk pemisah barisnya)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    pub index: usize,
+    pub total: usize,
+    pub label: String,
+    pub text: String,
+}
+
+impl AsciiArt {
+    /// Memecah output teks biasa menjadi potongan yang masing-masing muat di batas platform.
+    ///
+    /// Lihat `split_output` untuk output berwarna (ANSI) atau emoji.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_chars` - Jumlah karakter maksimum per potongan (termasuk newline)
+    /// * `max_lines` - Jumlah baris maksimum per potongan
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Chunk>)` - Potongan berurutan
+    /// * `Err(String)` - Jika batas bernilai 0 atau satu baris sudah melebihi `max_chars`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell};
+    ///
+    /// let cell = Cell { ch: '#', brightness: 0.2, color: [0, 0, 0] };
+    /// let art = AsciiArt::new(4, 5, vec![cell; 20]).unwrap();
+    /// let chunks = art.split_chunks(12, 10).unwrap();
+    /// assert_eq!(chunks.len(), 3);
+    /// assert_eq!(chunks[0].text, "####\n####\n");
+    /// assert_eq!(chunks[2].label, "3/3");
+    /// ```
+    pub fn split_chunks(&self, max_chars: usize, max_lines: usize) -> Result<Vec<Chunk>, String> {
+        split_output(&self.to_text(), max_chars, max_lines)
+    }
+}
+
+/// Memecah output yang sudah di-render (teks, ANSI, emoji) di antara baris.
+///
+/// Karakter dihitung sebagai code point Unicode, termasuk escape ANSI dan newline.
+/// Label tidak termasuk dalam hitungan; sisakan ruang jika label ikut dikirim.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{split_output, AsciiArt, Cell};
+///
+/// let red = Cell { ch: '#', brightness: 0.2, color: [255, 0, 0] };
+/// let art = AsciiArt::new(3, 4, vec![red; 12]).unwrap();
+/// let chunks = split_output(&art.to_ansi(), 100, 3).unwrap();
+/// assert_eq!(chunks.iter().map(|chunk| chunk.text.lines().count()).collect::<Vec<_>>(), vec![3, 1]);
+/// assert!(chunks.iter().all(|chunk| chunk.text.ends_with("\x1b[0m\n")));
+/// ```
+pub fn split_output(output: &str, max_chars: usize, max_lines: usize) -> Result<Vec<Chunk>, String> {
+    if max_chars == 0 || max_lines == 0 {
+        return Err("Batas karakter dan baris per potongan harus lebih dari 0".to_string());
+    }
+
+    let mut texts = Vec::new();
+    let mut current = String::new();
+    let (mut chars, mut lines) = (0, 0);
+    for (number, line) in output.split_inclusive('\n').enumerate() {
+        let length = line.chars().count();
+        if length > max_chars {
+            return Err(format!(
+                "Baris {} ({} karakter) melebihi batas {} karakter per potongan",
+                number + 1,
+                length,
+                max_chars
+            ));
+        }
+        if chars + length > max_chars || lines == max_lines {
+            texts.push(std::mem::take(&mut current));
+            chars = 0;
+            lines = 0;
+        }
+        current.push_str(line);
+        chars += length;
+        lines += 1;
+    }
+    if !current.is_empty() {
+        texts.push(current);
+    }
+
+    let total = texts.len();
+    Ok(texts
+        .into_iter()
+        .enumerate()
+        .map(|(i, text)| Chunk { index: i + 1, total, label: format!("{}/{}", i + 1, total), text })
+        .collect())
+}
@@ -0,0 +1,141 @@
+//! Contrast-limited adaptive histogram equalization (CLAHE).
+//!
+//! Gambar dibagi menjadi tile; setiap tile mendapat kurva ekualisasi sendiri
+//! dari histogram luminance yang puncaknya dipotong (`clip_limit`) agar noise
+//! tidak ikut diperkuat. Kurva antar tile diinterpolasi bilinear sehingga
+//! tidak muncul batas kotak, dan bayangan maupun highlight tetap berdetail.
+
+use crate::converter::color_luma;
+use image::{DynamicImage, Rgba};
+
+/// Opsi CLAHE.
+///
+/// * `tile_size` - Ukuran tile (per sisi) dalam piksel internal setelah resize
+/// * `clip_limit` - Batas tinggi bin histogram relatif terhadap rata-rata bin
+///   (1.0 = tanpa ekualisasi, 2.0 - 4.0 umumnya cukup, lebih tinggi = contrast lebih kuat)
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, ClaheOptions};
+///
+/// let config = AsciiConfig {
+///     clahe: Some(ClaheOptions { tile_size: 16, clip_limit: 3.0 }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClaheOptions {
+    pub tile_size: u32,
+    pub clip_limit: f32,
+}
+
+impl Default for ClaheOptions {
+    /// Tile 8×8 piksel dengan clip limit 2.0.
+    fn default() -> Self {
+        Self {
+            tile_size: 8,
+            clip_limit: 2.0,
+        }
+    }
+}
+
+/// Menerapkan CLAHE pada luminance gambar; warna diskalakan mengikuti perubahan luminance.
+pub(crate) fn apply(img: &DynamicImage, options: ClaheOptions) -> DynamicImage {
+    let mut image = img.to_rgba8();
+    let (width, height) = image.dimensions();
+    if width == 0 || height == 0 {
+        return DynamicImage::ImageRgba8(image);
+    }
+
+    let luma: Vec<u8> = image.pixels().map(|p| luminance([p[0], p[1], p[2]])).collect();
+    let tile_size = options.tile_size.max(1);
+    let tiles_x = width.div_ceil(tile_size);
+    let tiles_y = height.div_ceil(tile_size);
+
+    let mut lookups = Vec::with_capacity((tiles_x * tiles_y) as usize);
+    for ty in 0..tiles_y {
+        for tx in 0..tiles_x {
+            lookups.push(tile_lookup(&luma, width, height, tx, ty, tile_size, options.clip_limit));
+        }
+    }
+
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        let old = luma[(y * width + x) as usize];
+        let new = interpolate(&lookups, tiles_x, tiles_y, tile_size, x, y, old);
+        rescale(pixel, old, new);
+    }
+
+    DynamicImage::ImageRgba8(image)
+}
+
+/// Luminance dalam rentang 0 - 255, dengan bobot yang sama seperti pemetaan karakter.
+fn luminance(color: [u8; 3]) -> u8 {
+    (color_luma(color) * 255.0).round() as u8
+}
+
+/// Membangun tabel pemetaan luminance untuk satu tile dari histogram yang dipotong.
+fn tile_lookup(luma: &[u8], width: u32, height: u32, tx: u32, ty: u32, tile_size: u32, clip_limit: f32) -> [u8; 256] {
+    let x0 = tx * tile_size;
+    let y0 = ty * tile_size;
+    let x1 = (x0 + tile_size).min(width);
+    let y1 = (y0 + tile_size).min(height);
+
+    let mut histogram = [0.0f32; 256];
+    for y in y0..y1 {
+        for x in x0..x1 {
+            histogram[luma[(y * width + x) as usize] as usize] += 1.0;
+        }
+    }
+    let pixels = ((x1 - x0) * (y1 - y0)) as f32;
+
+    // Potong puncak histogram lalu bagikan kelebihannya rata ke semua bin
+    let limit = clip_limit.max(1.0) * pixels / 256.0;
+    let mut excess = 0.0;
+    for bin in histogram.iter_mut() {
+        if *bin > limit {
+            excess += *bin - limit;
+            *bin = limit;
+        }
+    }
+    let share = excess / 256.0;
+
+    let mut lookup = [0u8; 256];
+    let mut cumulative = 0.0;
+    for (value, count) in histogram.iter().enumerate() {
+        cumulative += count + share;
+        lookup[value] = (cumulative / pixels * 255.0).round().clamp(0.0, 255.0) as u8;
+    }
+    lookup
+}
+
+/// Interpolasi bilinear pemetaan dari empat tile terdekat berdasarkan pusat tile.
+fn interpolate(lookups: &[[u8; 256]], tiles_x: u32, tiles_y: u32, tile_size: u32, x: u32, y: u32, value: u8) -> u8 {
+    let position = |coord: u32, tiles: u32| {
+        let t = ((coord as f32 + 0.5) / tile_size as f32 - 0.5).clamp(0.0, (tiles - 1) as f32);
+        let low = t.floor() as u32;
+        let high = (low + 1).min(tiles - 1);
+        (low, high, t - low as f32)
+    };
+    let (x_low, x_high, fx) = position(x, tiles_x);
+    let (y_low, y_high, fy) = position(y, tiles_y);
+
+    let sample = |tx: u32, ty: u32| lookups[(ty * tiles_x + tx) as usize][value as usize] as f32;
+    let top = sample(x_low, y_low) * (1.0 - fx) + sample(x_high, y_low) * fx;
+    let bottom = sample(x_low, y_high) * (1.0 - fx) + sample(x_high, y_high) * fx;
+    (top * (1.0 - fy) + bottom * fy).round() as u8
+}
+
+/// Menskalakan kanal warna agar luminance piksel menjadi `new`, mempertahankan hue.
+fn rescale(pixel: &mut Rgba<u8>, old: u8, new: u8) {
+    if old == 0 {
+        pixel[0] = new;
+        pixel[1] = new;
+        pixel[2] = new;
+        return;
+    }
+    let ratio = new as f32 / old as f32;
+    for c in 0..3 {
+        pixel[c] = (pixel[c] as f32 * ratio).round().clamp(0.0, 255.0) as u8;
+    }
+}
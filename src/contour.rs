@@ -0,0 +1,162 @@
+//! Mode line art: penelusuran kontur dan render garis tepi dengan karakter box-drawing.
+
+use crate::{AsciiArt, Cell};
+
+/// Opsi mode line art.
+///
+/// Sel dengan brightness di bawah `threshold` dianggap bentuk (tinta gelap di
+/// atas latar terang); dengan `invert`, sel terang yang menjadi bentuk. Tepi
+/// setiap bentuk ditelusuri dengan algoritma Moore neighbor lalu digambar
+/// dengan `─│┌┐└┘╱╲`; sel lain dikosongkan.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, AsciiConverter, LineArt};
+/// use image::{DynamicImage, GrayImage, Luma};
+///
+/// // Kotak hitam di atas latar putih
+/// let square = GrayImage::from_fn(8, 6, |x, y| {
+///     if (2..6).contains(&x) && (1..5).contains(&y) { Luma([0]) } else { Luma([255]) }
+/// });
+/// let config = AsciiConfig { width: 8, height: 6, line_art: Some(LineArt::default()), ..Default::default() };
+/// let art = AsciiConverter::with_config(config)
+///     .convert_image(&DynamicImage::ImageLuma8(square))
+///     .unwrap();
+///
+/// assert_eq!(art, "        \n  ┌──┐  \n  │  │  \n  │  │  \n  └──┘  \n        \n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineArt {
+    pub threshold: f32,
+}
+
+impl Default for LineArt {
+    /// Threshold 0.5.
+    fn default() -> Self {
+        Self { threshold: 0.5 }
+    }
+}
+
+/// Tetangga Moore searah jarum jam (y ke bawah), dimulai dari barat.
+const NEIGHBORS: [(i32, i32); 8] = [(-1, 0), (-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1)];
+
+/// Mengganti isi grid dengan garis kontur bentuk-bentuknya.
+///
+/// Warna dan brightness sel dipertahankan agar output berwarna tetap mengikuti sumber.
+pub(crate) fn trace(art: &AsciiArt, options: &LineArt, invert: bool) -> AsciiArt {
+    let (width, height) = (art.width() as i32, art.height() as i32);
+    let inside: Vec<bool> = art
+        .cells()
+        .iter()
+        .map(|cell| (cell.brightness < options.threshold) != invert)
+        .collect();
+    let is_inside = |x: i32, y: i32| x >= 0 && y >= 0 && x < width && y < height && inside[(y * width + x) as usize];
+
+    let mut glyphs = vec![' '; inside.len()];
+    let mut traced = vec![false; inside.len()];
+
+    for y in 0..height {
+        for x in 0..width {
+            // Awal kontur baru: sel bentuk dengan latar di sebelah kirinya
+            if !is_inside(x, y) || is_inside(x - 1, y) || traced[(y * width + x) as usize] {
+                continue;
+            }
+            let contour = moore_trace((x, y), &is_inside, (width * height * 4) as usize);
+            for (i, &(cx, cy)) in contour.iter().enumerate() {
+                let previous = contour[(i + contour.len() - 1) % contour.len()];
+                let next = contour[(i + 1) % contour.len()];
+                let index = (cy * width + cx) as usize;
+                traced[index] = true;
+                glyphs[index] = contour_glyph((previous.0 - cx, previous.1 - cy), (next.0 - cx, next.1 - cy));
+            }
+        }
+    }
+
+    let cells = art
+        .cells()
+        .iter()
+        .zip(glyphs)
+        .map(|(cell, ch)| Cell { ch, ..*cell })
+        .collect();
+    AsciiArt::new(art.width(), art.height(), cells).expect("dimensi grid tidak berubah")
+}
+
+/// Menelusuri satu kontur dari `start` dengan kriteria berhenti Jacob.
+fn moore_trace(start: (i32, i32), is_inside: &impl Fn(i32, i32) -> bool, max_steps: usize) -> Vec<(i32, i32)> {
+    let mut contour = vec![start];
+    let mut current = start;
+    // Arah dari sel saat ini ke sel latar terakhir yang diperiksa (awalnya barat)
+    let mut backtrack = 0;
+    let start_backtrack = backtrack;
+
+    for _ in 0..max_steps {
+        let found = (1..=8)
+            .map(|step| (backtrack + step) % 8)
+            .find(|&direction| is_inside(current.0 + NEIGHBORS[direction].0, current.1 + NEIGHBORS[direction].1));
+        let Some(direction) = found else {
+            break;
+        };
+
+        let previous = current;
+        current = (previous.0 + NEIGHBORS[direction].0, previous.1 + NEIGHBORS[direction].1);
+        // Sel latar sebelum `direction`, dilihat dari sel yang baru
+        let checked = (direction + 7) % 8;
+        let background = (previous.0 + NEIGHBORS[checked].0, previous.1 + NEIGHBORS[checked].1);
+        backtrack = NEIGHBORS
+            .iter()
+            .position(|&(dx, dy)| (current.0 + dx, current.1 + dy) == background)
+            .unwrap_or((direction + 4) % 8);
+
+        if current == start && backtrack == start_backtrack {
+            break;
+        }
+        contour.push(current);
+    }
+    contour
+}
+
+/// Memilih glyph dari arah ke sel kontur sebelumnya dan berikutnya.
+fn contour_glyph(a: (i32, i32), b: (i32, i32)) -> char {
+    let diagonal = |(dx, dy): (i32, i32)| dx != 0 && dy != 0;
+    let rising = |(dx, dy): (i32, i32)| dx * dy < 0;
+
+    match (diagonal(a), diagonal(b)) {
+        (false, false) => {
+            let horizontal = |(_, dy): (i32, i32)| dy == 0;
+            match (horizontal(a), horizontal(b)) {
+                (true, true) => '─',
+                (false, false) => '│',
+                _ => {
+                    let (dx, dy) = (a.0 + b.0, a.1 + b.1);
+                    match (dx > 0, dy > 0) {
+                        (true, true) => '┌',
+                        (false, true) => '┐',
+                        (true, false) => '└',
+                        (false, false) => '┘',
+                    }
+                }
+            }
+        }
+        (true, true) if rising(a) == rising(b) => diagonal_glyph(a),
+        // Dua diagonal berbeda membentuk ujung runcing
+        (true, true) => {
+            if a.0 == b.0 {
+                '│'
+            } else {
+                '─'
+            }
+        }
+        // Satu diagonal dan satu lurus (anak tangga): ikuti sumbu yang lurus
+        (true, false) => if b.1 == 0 { '─' } else { '│' },
+        (false, true) => if a.1 == 0 { '─' } else { '│' },
+    }
+}
+
+fn diagonal_glyph((dx, dy): (i32, i32)) -> char {
+    if dx * dy < 0 {
+        '╱'
+    } else {
+        '╲'
+    }
+}
@@ -53,26 +53,261 @@
 //! }
 //! ```
 
-use image::{DynamicImage, GenericImageView, GrayImage, io::Reader as ImageReader, imageops::FilterType};
+use image::{DynamicImage, GenericImageView, ImageError, ImageFormat, io::Reader as ImageReader, imageops::FilterType};
 use std::path::Path;
 use std::fs;
+use std::io::{Cursor, Read};
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::background::BackgroundMask;
+use crate::clahe;
+use crate::contour;
+use crate::crop;
+use crate::depth;
+use crate::format::FTYP_HEADER_LEN;
+#[cfg(feature = "heif")]
+use crate::heif;
+use crate::layers;
+use crate::linear;
+use crate::palette;
+use crate::rng::Rng;
+use crate::theme::{text_color, themed_html};
+#[cfg(feature = "simd")]
+use crate::simd;
+#[cfg(feature = "svg")]
+use crate::svg;
+use crate::tiled;
+use crate::trace::stage_span;
+use crate::report::PipelineProbe;
+use crate::{AsciiArt, BackgroundRemoval, Canvas, DepthOptions, BayerMatrix, Cell, ClaheOptions, ColorGradient, CrtEffect, ConfigIssue, ConversionError, DetectedFormat, EmojiPalette, LineArt, Orientation, Recolor, HtmlAccessibility, HtmlTableOptions, LineEnding, ResourceLimits, RetroTerminal, SourceMetadata, Theme, ToneCurve, WhiteBalance, RETRO_COLUMNS, RETRO_ROWS};
 
 /// Set karakter ASCII dasar yang digunakan untuk konversi, diurutkan dari gelap ke terang.
 /// Cocok untuk output yang sederhana dan jelas.
-const ASCII_CHARS: &[u8] = b"@%#*+=-:. ";
+pub(crate) const ASCII_CHARS: &[u8] = b"@%#*+=-:. ";
 
 /// Set karakter ASCII yang lebih detail untuk hasil yang lebih halus.
 /// Menyediakan gradasi yang lebih baik antara area gelap dan terang.
-const DETAILED_ASCII_CHARS: &[u8] = b"$@B%8&WM#*oahkbdpqwmZO0QLCJUYXzcvunxrjft/\\|()1{}[]?-_+~<>i!lI;:,\"^`'. ";
+pub(crate) const DETAILED_ASCII_CHARS: &[u8] = b"$@B%8&WM#*oahkbdpqwmZO0QLCJUYXzcvunxrjft/\\|()1{}[]?-_+~<>i!lI;:,\"^`'. ";
 
 /// Set karakter densitas tinggi untuk hasil yang sangat detail.
 /// Menggunakan kombinasi karakter untuk menciptakan berbagai tingkat gelap-terang.
-const HIGH_DENSITY_CHARS: &[&str] = &[
+pub(crate) const HIGH_DENSITY_CHARS: &[&str] = &[
     "█", "▓", "▒", "░", "▄", "▀", "■", "▪", "●", "◆", 
     "◉", "◍", "◎", "○", "☉", "◌", "◊", "♦", "♢", "•", 
     ".", " "
 ];
 
+/// Karakter quadrant Unicode untuk pola sub-piksel 2×2.
+/// Indeks adalah bitmask: kiri-atas = 1, kanan-atas = 2, kiri-bawah = 4, kanan-bawah = 8.
+pub(crate) const QUADRANT_CHARS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛',
+    '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// Brightness sebuah warna (0.0 - 1.0) dengan formula yang sama seperti pemetaan
+/// karakter: R*0.3 + G*0.59 + B*0.11.
+pub(crate) fn color_luma([r, g, b]: [u8; 3]) -> f32 {
+    (r as f32 * 0.3 + g as f32 * 0.59 + b as f32 * 0.11) / 255.0
+}
+
+/// Format output yang digunakan ketika `use_color` aktif.
+/// 
+/// * `Html` - Dokumen HTML dengan karakter berwarna (default)
+/// * `Ansi` - Karakter berwarna dengan escape sequence ANSI truecolor untuk terminal
+/// * `AnsiBackground` - "Photo mode": setiap sel berupa spasi dengan warna latar ANSI,
+///   mengabaikan karakter sehingga terminal menjadi layar piksel beresolusi rendah
+/// * `Emoji` - Mosaik emoji berwarna terdekat (lihat `AsciiArt::to_emoji`), untuk platform
+///   chat yang membuang ANSI dan HTML
+/// * `HtmlTable` - Dokumen HTML berisi tabel sel berwarna berukuran tetap (lihat
+///   `AsciiArt::to_html_table`), untuk email dan CMS yang tidak merender monospace dengan benar
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use aspix::{AsciiConverter, AsciiConfig, OutputFormat};
+/// 
+/// let converter = AsciiConverter::with_config(AsciiConfig {
+///     use_color: true,
+///     output_format: OutputFormat::AnsiBackground,
+///     ..Default::default()
+/// });
+/// if let Ok(pixels) = converter.convert("photo.jpg") {
+///     print!("{}", pixels);
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Html,
+    Ansi,
+    AnsiBackground,
+    Emoji,
+    HtmlTable,
+}
+
+/// Cara menentukan warna setiap karakter pada output berwarna.
+/// 
+/// * `Source` - Menggunakan warna asli gambar (dengan `color_saturation`)
+/// * `Duotone` - Memetakan brightness ke gradasi dua warna, dari `dark` ke `light`
+/// * `Tint` - Satu warna yang intensitasnya mengikuti brightness (misalnya hijau "Matrix")
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use aspix::{AsciiConfig, ColorMode};
+/// 
+/// let sepia = AsciiConfig {
+///     use_color: true,
+///     color_mode: ColorMode::Duotone { dark: [40, 26, 13], light: [255, 236, 200] },
+///     ..Default::default()
+/// };
+/// let matrix = AsciiConfig {
+///     use_color: true,
+///     color_mode: ColorMode::Tint([0, 255, 70]),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    #[default]
+    Source,
+    Duotone { dark: [u8; 3], light: [u8; 3] },
+    Tint([u8; 3]),
+}
+
+impl ColorMode {
+    /// Menghitung warna akhir dari warna rata-rata sel (0.0 - 1.0) dan brightness-nya.
+    pub(crate) fn apply(&self, source: [f32; 3], brightness: f32, saturation: f32) -> [u8; 3] {
+        match *self {
+            ColorMode::Source => source.map(|c| ((c * saturation + (1.0 - saturation) * 0.5) * 255.0) as u8),
+            ColorMode::Duotone { dark, light } => {
+                let t = brightness.clamp(0.0, 1.0);
+                [0, 1, 2].map(|i| (dark[i] as f32 + (light[i] as f32 - dark[i] as f32) * t) as u8)
+            }
+            ColorMode::Tint(color) => {
+                let t = brightness.clamp(0.0, 1.0);
+                color.map(|c| (c as f32 * t) as u8)
+            }
+        }
+    }
+}
+
+/// Rotasi gambar sumber searah jarum jam, diterapkan sebelum resize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Rotation {
+    #[default]
+    None,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+}
+
+/// Strategi pencampuran set karakter berdasarkan brightness.
+/// 
+/// Sel yang sangat gelap (di bawah `dark_threshold`) atau sangat terang (di atas
+/// `light_threshold`) memakai set densitas tinggi yang blok-bloknya kuat di
+/// ujung-ujung skala, sedangkan midtone memakai ramp ASCII detail yang
+/// gradasinya lebih halus. Threshold dibandingkan dengan brightness setelah `invert`.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use aspix::{AsciiConfig, MixedCharset};
+/// 
+/// let config = AsciiConfig {
+///     mixed_charset: Some(MixedCharset { dark_threshold: 0.2, light_threshold: 0.9 }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MixedCharset {
+    pub dark_threshold: f32,
+    pub light_threshold: f32,
+}
+
+impl Default for MixedCharset {
+    /// Set densitas tinggi di bawah 0.15 dan di atas 0.85.
+    fn default() -> Self {
+        Self {
+            dark_threshold: 0.15,
+            light_threshold: 0.85,
+        }
+    }
+}
+
+/// Kepadatan karakter adaptif berdasarkan detail lokal.
+/// 
+/// Detail sel diukur sebagai simpangan baku luminance pada blok sel beserta satu
+/// piksel di sekelilingnya. Sel dengan detail di atas `detail_threshold` (tepi,
+/// tekstur) memakai ramp ASCII detail yang gradasinya halus, sedangkan area datar
+/// tetap memakai ramp biasa yang sederhana, sehingga detail tampak lebih tajam
+/// tanpa menambah ukuran grid.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use aspix::{AdaptiveDensity, AsciiConfig};
+/// 
+/// let config = AsciiConfig {
+///     adaptive_density: Some(AdaptiveDensity { detail_threshold: 0.05 }),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveDensity {
+    pub detail_threshold: f32,
+}
+
+impl Default for AdaptiveDensity {
+    /// Ramp detail untuk sel dengan simpangan baku luminance di atas 0.08.
+    fn default() -> Self {
+        Self { detail_threshold: 0.08 }
+    }
+}
+
+/// Cara menurunkan brightness dan warna satu sel dari blok piksel sumbernya.
+/// 
+/// * `Mean` - Rata-rata semua piksel (default, gradasi paling halus)
+/// * `Median` - Piksel dengan brightness median (tahan terhadap noise)
+/// * `Max` - Piksel paling terang (menjaga detail terang kecil seperti bintang atau kilau)
+/// * `Min` - Piksel paling gelap (menjaga garis tipis gelap)
+/// * `Center` - Piksel di tengah blok (tajam, tanpa perataan)
+/// 
+/// Selain `Mean`, setiap sel mencakup minimal `SAMPLING_BLOCK` × `SAMPLING_BLOCK`
+/// piksel internal dan warna sel diambil dari piksel yang terpilih.
+/// 
+/// # Examples
+/// 
+/// ```rust
+/// use aspix::{AsciiConfig, AsciiConverter, Sampling};
+/// use image::{DynamicImage, GrayImage, Luma};
+/// 
+/// // Langit gelap dengan satu bintang kecil
+/// let mut sky = GrayImage::new(64, 64);
+/// sky.put_pixel(40, 20, Luma([255]));
+/// let sky = DynamicImage::ImageLuma8(sky);
+/// 
+/// let convert = |sampling| {
+///     let config = AsciiConfig { width: 8, height: 8, sampling, ..Default::default() };
+///     AsciiConverter::with_config(config).convert_image(&sky).unwrap()
+/// };
+/// assert!(convert(Sampling::Mean).chars().all(|c| c == '@' || c == '\n'));
+/// assert!(convert(Sampling::Max).chars().any(|c| c != '@' && c != '\n'));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Sampling {
+    #[default]
+    Mean,
+    Median,
+    Max,
+    Min,
+    Center,
+}
+
+/// Ukuran blok minimum (per sisi) untuk strategi sampling selain `Sampling::Mean`.
+pub const SAMPLING_BLOCK: u32 = 4;
+
 /// Konfigurasi untuk mengatur perilaku konversi ASCII.
 /// 
 /// Struct ini memungkinkan kustomisasi penuh atas proses konversi,
@@ -84,12 +319,104 @@ const HIGH_DENSITY_CHARS: &[&str] = &[
 /// * `height` - Tinggi output ASCII dalam baris
 /// * `use_detailed_chars` - Menggunakan set karakter detail untuk hasil yang lebih halus
 /// * `use_high_density` - Menggunakan karakter densitas tinggi (Uni3ode blocks) untuk detail ekstrim
-/// * `use_color` - Menghasilkan output berwarna (format HTML)
-/// * `color_saturation` - Intensitas warna (0.0 - 1.0)
+/// * `use_color` - Menghasilkan output berwarna (format sesuai `output_format`)
+/// * `output_format` - Format output berwarna: HTML, ANSI, ANSI background, atau mosaik emoji
+/// * `line_ending` - Pemisah baris untuk output teks (LF atau CRLF)
+/// * `trim_trailing_whitespace` - Menghapus spasi di akhir baris pada output teks
+/// * `embed_metadata` - Menyematkan metadata sumber (nama file, dimensi, tanggal EXIF, sidik jari
+///   konfigurasi) pada output `convert`, `convert_from_bytes`, `convert_to_ans`, dan
+///   `convert_to_xbin`, lihat `SourceMetadata`
+/// * `html_accessibility` - Opsi aksesibilitas (aria-label, caption, teks biasa) untuk output HTML
+/// * `html_palette_size` - Jika diisi, output HTML memakai paling banyak sejumlah warna ini
+///   sebagai class CSS (lihat `AsciiArt::to_html_themed`) alih-alih style inline per karakter
+/// * `html_table` - Ukuran sel dan tampilan karakter untuk `OutputFormat::HtmlTable`
+/// * `emoji_palette` - Palet kustom untuk `OutputFormat::Emoji` (`None` memakai `EmojiPalette::builtin`)
+/// * `color_mode` - Sumber warna: warna asli, duotone, atau tint satu warna
+/// * `color_saturation` - Intensitas warna (0.0 - 1.0), hanya untuk `ColorMode::Source`
+/// * `dominant_colors` - Jika diisi dengan N, warna setiap sel diganti warna terdekat dari N warna
+///   dominan gambar (lihat `palette::dominant_colors`) untuk tampilan kompak ala poster
+/// * `gradient` - Jika diisi, warna sel diganti gradien berdasarkan posisinya di grid
+///   (menggantikan `color_mode`), lihat `ColorGradient`
+/// * `crt` - Jika diisi, warna sel diberi efek layar CRT (scanline, color bleed, vignette), lihat `CrtEffect`
+/// * `retro` - Jika diisi, output disimulasikan sebagai terminal DOS: glyph CP437, palet
+///   16 warna VGA, ANSI 16 warna, dan (opsional) grid maksimal 80×25, lihat `RetroTerminal`
 /// * `invert` - Membalik hasil konversi (gelap menjadi terang dan sebaliknya)
+/// * `theme` - Jika diisi, arah ramp dan latar HTML disesuaikan dengan tema terminal;
+///   `Theme::Dark` membalik ramp sekali lagi di atas `invert`, dan `Theme::Auto` dideteksi
+///   sekali saat pertama kali dipakai, lihat `Theme`
+/// * `white_balance` - Jika diisi, gain per channel dihitung otomatis dari gambar untuk
+///   menetralkan color cast sebelum contrast dan brightness, lihat `WhiteBalance`
+/// * `channel_gains` - Pengali manual channel `[r, g, b]` (1.0 adalah normal), diterapkan bersama
+///   gain white balance sebelum contrast dan brightness
 /// * `contrast` - Nilai contrast (1.0 adalah normal, >1.0 menambah contrast, <1.0 mengurangi)
 /// * `brightness` - Nilai brightness (1.0 adalah normal, >1.0 lebih terang, <1.0 lebih gelap)
+/// * `curve` - Jika diisi, luminance setiap piksel dipetakan melalui kurva tone ini sebelum
+///   pemetaan karakter, untuk membentuk bayangan/midtone/highlight secara presisi
+/// * `saturation` - Pengali saturasi gambar sebelum konversi dalam ruang HSV
+///   (1.0 adalah normal, 0.0 grayscale, >1.0 lebih vivid)
+/// * `hue_rotation` - Pergeseran hue dalam derajat sebelum konversi (0.0 = tanpa perubahan)
 /// * `scale` - Skala resolusi internal (lebih tinggi = lebih detail, default 1.0)
+/// * `sampling` - Cara brightness sel diturunkan dari blok piksel sumbernya (lihat `Sampling`)
+/// * `linear_light` - Rata-rata warna (resize dan blok sel) dihitung di ruang linear-light
+///   lalu dikembalikan ke sRGB, sehingga sel berisi campuran warna tidak menjadi terlalu
+///   gelap; brightness sel ikut mengikuti intensitas cahaya sebenarnya
+/// * `pixel_art` - Preset untuk sumber pixel art: setiap piksel sumber menjadi tepat satu sel
+///   (atau 2×1 sel jika muat, agar tetap persegi) dengan resize nearest-neighbor; `width` dan
+///   `height` menjadi batas maksimum (gambar yang lebih besar diperkecil dengan faktor bulat),
+///   sedangkan dithering dan smart crop dinonaktifkan
+/// * `rotate` - Rotasi gambar sumber (0/90/180/270 derajat) sebelum resize
+/// * `flip_horizontal` - Mencerminkan gambar sumber secara horizontal sebelum resize
+/// * `flip_vertical` - Mencerminkan gambar sumber secara vertikal sebelum resize
+/// * `orientation` - Rotasi grid hasil konversi (untuk layar vertikal atau banner menyamping);
+///   `width` dan `height` tetap mengacu pada grid sebelum diputar
+/// * `canvas` - Jika diisi, grid (setelah diputar) diletakkan pada kanvas berukuran tetap dengan
+///   padding atau dipotong sesuai perataan, misalnya tepat 80×24 untuk MOTD, lihat `Canvas`
+/// * `sharpen_amount` - Kekuatan unsharp mask setelah resize (0.0 = nonaktif, 0.5 - 1.5 umumnya cukup)
+/// * `sharpen_radius` - Radius (sigma) blur untuk unsharp mask dalam piksel internal
+/// * `mixed_charset` - Jika diisi, set densitas tinggi dipakai untuk area sangat gelap/terang dan
+///   ramp detail untuk midtone (mengabaikan `use_detailed_chars`, `use_high_density`, dan `custom_chars`)
+/// * `adaptive_density` - Jika diisi, sel berdetail tinggi (tepi, tekstur) memakai ramp ASCII detail
+///   sedangkan area datar memakai ramp biasa; lihat `AdaptiveDensity`
+/// * `line_art` - Jika diisi, hanya garis tepi bentuk yang di-render dengan karakter box-drawing
+///   (`─│┌┐└┘╱╲`), cocok untuk logo dan diagram; lihat `LineArt`
+/// * `use_quadrants` - Mode quadrant: setiap sel mewakili pola 2×2 sub-piksel (`▘▝▖▗▚▞▌▐█`),
+///   menggandakan resolusi efektif pada kedua sumbu
+/// * `dither_noise` - Amplitudo noise acak yang ditambahkan ke brightness sebelum pemetaan
+///   karakter (0.0 = nonaktif), memecah banding pada gradasi halus
+/// * `ordered_dither` - Jika diisi, brightness digeser sesuai pola Bayer pada posisi sel (selebar satu
+///   tingkat ramp) sebelum pemetaan karakter; stabil antar frame animasi. Tidak berlaku pada mode quadrant
+/// * `seed` - Seed untuk semua keacakan (dithering acak, dll.); dengan seed yang sama,
+///   input dan konfigurasi yang sama selalu menghasilkan output identik. `None` memakai waktu sistem
+/// * `custom_chars` - Ramp karakter kustom (gelap → terang) yang menggantikan set bawaan,
+///   misalnya hasil `CoverageTable::sorted_ramp` untuk font tertentu
+/// * `use_simd` - Memakai jalur cepat SIMD untuk luminance dan pemetaan karakter, hanya
+///   ketika satu sel tepat satu piksel (`scale` 1.0, tanpa quadrant, dithering acak atau ordered,
+///   mixed charset, kepadatan adaptif, hook sel, atau background removal); hasil identik dengan
+///   jalur biasa. Tanpa feature `simd` opsi ini diabaikan
+/// * `tile_height` - Jika diisi, gambar diperkecil per strip setinggi nilai ini (dalam piksel sumber)
+///   agar gambar raksasa dapat dikonversi dengan memori terbatas
+/// * `smart_crop` - Memotong gambar ke area paling menarik (kepadatan tepi tertinggi) dengan
+///   rasio aspek grid target sebelum resize, agar subjek tidak hilang atau gepeng. Rasio aspek
+///   target mengasumsikan tinggi sel dua kali lebarnya
+/// * `clahe` - Jika diisi, menerapkan CLAHE (ekualisasi histogram adaptif per tile) sebelum
+///   penyesuaian contrast agar bayangan dan highlight tetap berdetail
+/// * `background_removal` - Jika diisi, sel yang didominasi background (flood-fill dari tepi
+///   atau chroma key) di-render sebagai spasi agar subjek menonjol
+/// * `limits` - Batas ukuran data, jumlah piksel, dan durasi konversi, lihat `ResourceLimits`
+///
+/// # Examples
+///
+/// Sprite 4×2 dengan `pixel_art` menjadi grid 8×2: setiap piksel tepat dua sel.
+///
+/// ```rust
+/// use aspix::{AsciiConfig, AsciiConverter};
+/// use image::{DynamicImage, Rgb, RgbImage};
+///
+/// let sprite = RgbImage::from_fn(4, 2, |x, y| if (x + y) % 2 == 0 { Rgb([0, 0, 0]) } else { Rgb([255, 255, 255]) });
+/// let converter = AsciiConverter::with_config(AsciiConfig { pixel_art: true, ..AsciiConfig::default() });
+/// let art = converter.convert_image_to_art(&DynamicImage::ImageRgb8(sprite)).unwrap();
+/// assert_eq!(art.to_text(), "@@  @@  \n  @@  @@\n");
+/// ```
 #[derive(Debug, Clone)]
 pub struct AsciiConfig {
     pub width: u32,
@@ -97,11 +424,69 @@ pub struct AsciiConfig {
     pub use_detailed_chars: bool,
     pub use_high_density: bool,
     pub use_color: bool,
+    pub output_format: OutputFormat,
+    pub line_ending: LineEnding,
+    pub trim_trailing_whitespace: bool,
+    pub embed_metadata: bool,
+    pub html_accessibility: Option<HtmlAccessibility>,
+    pub html_palette_size: Option<usize>,
+    pub html_table: HtmlTableOptions,
+    pub emoji_palette: Option<EmojiPalette>,
+    pub color_mode: ColorMode,
     pub color_saturation: f32,
+    pub dominant_colors: Option<usize>,
+    pub gradient: Option<ColorGradient>,
+    pub crt: Option<CrtEffect>,
+    pub retro: Option<RetroTerminal>,
     pub invert: bool,
+    pub theme: Option<Theme>,
+    pub white_balance: Option<WhiteBalance>,
+    pub channel_gains: [f32; 3],
     pub contrast: f32,
     pub brightness: f32,
+    pub curve: Option<ToneCurve>,
+    pub saturation: f32,
+    pub hue_rotation: f32,
     pub scale: f32,
+    pub sampling: Sampling,
+    pub linear_light: bool,
+    pub pixel_art: bool,
+    pub rotate: Rotation,
+    pub flip_horizontal: bool,
+    pub flip_vertical: bool,
+    pub orientation: Orientation,
+    pub canvas: Option<Canvas>,
+    pub sharpen_amount: f32,
+    pub sharpen_radius: f32,
+    pub mixed_charset: Option<MixedCharset>,
+    pub adaptive_density: Option<AdaptiveDensity>,
+    pub line_art: Option<LineArt>,
+    pub use_quadrants: bool,
+    pub dither_noise: f32,
+    pub ordered_dither: Option<BayerMatrix>,
+    pub seed: Option<u64>,
+    pub custom_chars: Option<Vec<char>>,
+    pub use_simd: bool,
+    pub tile_height: Option<u32>,
+    pub smart_crop: bool,
+    pub clahe: Option<ClaheOptions>,
+    pub background_removal: Option<BackgroundRemoval>,
+    pub limits: ResourceLimits,
+}
+
+impl AsciiConfig {
+    /// Apakah ramp dibalik: `invert`, dibalik lagi untuk tema gelap.
+    pub(crate) fn inverted(&self) -> bool {
+        self.invert != (self.theme.map(Theme::resolve) == Some(Theme::Dark))
+    }
+
+    /// Skala internal efektif; sampling selain `Mean` membutuhkan blok berisi banyak piksel.
+    pub(crate) fn internal_scale(&self) -> f32 {
+        match self.sampling {
+            Sampling::Mean => self.scale,
+            _ => self.scale.max(SAMPLING_BLOCK as f32),
+        }
+    }
 }
 
 impl Default for AsciiConfig {
@@ -115,11 +500,54 @@ impl Default for AsciiConfig {
     /// * use_detailed_chars: false
     /// * use_high_density: false
     /// * use_color: false
+    /// * output_format: OutputFormat::Html
+    /// * line_ending: LineEnding::Lf
+    /// * trim_trailing_whitespace: false
+    /// * embed_metadata: false
+    /// * html_accessibility: None
+    /// * html_palette_size: None
+    /// * html_table: HtmlTableOptions::default()
+    /// * emoji_palette: None
+    /// * color_mode: ColorMode::Source
     /// * color_saturation: 0.7
+    /// * dominant_colors: None
+    /// * gradient: None
+    /// * crt: None
+    /// * retro: None
     /// * invert: false
+    /// * theme: None
+    /// * white_balance: None
+    /// * channel_gains: [1.0, 1.0, 1.0]
     /// * contrast: 1.0
     /// * brightness: 1.0
+    /// * curve: None
+    /// * saturation: 1.0
+    /// * hue_rotation: 0.0
     /// * scale: 1.0
+    /// * sampling: Sampling::Mean
+    /// * linear_light: false
+    /// * pixel_art: false
+    /// * rotate: Rotation::None
+    /// * flip_horizontal: false
+    /// * flip_vertical: false
+    /// * orientation: Orientation::Normal
+    /// * canvas: None
+    /// * sharpen_amount: 0.0
+    /// * sharpen_radius: 1.0
+    /// * mixed_charset: None
+    /// * adaptive_density: None
+    /// * line_art: None
+    /// * use_quadrants: false
+    /// * dither_noise: 0.0
+    /// * ordered_dither: None
+    /// * seed: None
+    /// * custom_chars: None
+    /// * use_simd: false
+    /// * tile_height: None
+    /// * smart_crop: false
+    /// * clahe: None
+    /// * background_removal: None
+    /// * limits: tanpa batas tambahan (`ResourceLimits::default()`)
     fn default() -> Self {
         Self {
             width: 100,
@@ -127,21 +555,74 @@ impl Default for AsciiConfig {
             use_detailed_chars: false,
             use_high_density: false,
             use_color: false,
+            output_format: OutputFormat::Html,
+            line_ending: LineEnding::Lf,
+            trim_trailing_whitespace: false,
+            embed_metadata: false,
+            html_accessibility: None,
+            html_palette_size: None,
+            html_table: HtmlTableOptions::default(),
+            emoji_palette: None,
+            color_mode: ColorMode::Source,
             color_saturation: 0.7,
+            dominant_colors: None,
+            gradient: None,
+            crt: None,
+            retro: None,
             invert: false,
+            theme: None,
+            white_balance: None,
+            channel_gains: [1.0; 3],
             contrast: 1.0,
             brightness: 1.0,
+            curve: None,
+            saturation: 1.0,
+            hue_rotation: 0.0,
             scale: 1.0,
+            sampling: Sampling::Mean,
+            linear_light: false,
+            pixel_art: false,
+            rotate: Rotation::None,
+            flip_horizontal: false,
+            flip_vertical: false,
+            orientation: Orientation::Normal,
+            canvas: None,
+            sharpen_amount: 0.0,
+            sharpen_radius: 1.0,
+            mixed_charset: None,
+            adaptive_density: None,
+            line_art: None,
+            use_quadrants: false,
+            dither_noise: 0.0,
+            ordered_dither: None,
+            seed: None,
+            custom_chars: None,
+            use_simd: false,
+            tile_height: None,
+            smart_crop: false,
+            clahe: None,
+            background_removal: None,
+            limits: ResourceLimits::default(),
         }
     }
 }
 
+/// Hook per sel yang dapat menggantikan pilihan karakter dan warna.
+/// 
+/// Dipanggil dengan posisi `(x, y)`, brightness rata-rata (0.0 - 1.0, sebelum
+/// `invert`), dan warna rata-rata sumber. Kembalikan `Some(Cell)` untuk
+/// mengganti sel, atau `None` untuk memakai hasil bawaan.
+pub type CellHook = dyn Fn(u32, u32, f32, [u8; 3]) -> Option<Cell> + Send + Sync;
+
 /// Struct utama untuk mengkonversi gambar menjadi ASCII art.
 /// 
 /// `AsciiConverter` menyediakan metode-metode untuk mengkonversi gambar
 /// menjadi ASCII art dengan berbagai opsi kustomisasi.
+#[derive(Clone)]
 pub struct AsciiConverter {
     config: AsciiConfig,
+    pub(crate) on_cell: Option<Arc<CellHook>>,
+    issues: Vec<ConfigIssue>,
 }
 
 impl AsciiConverter {
@@ -160,17 +641,19 @@ impl AsciiConverter {
     /// let converter = AsciiConverter::new(80, 40);
     /// ```
     pub fn new(width: u32, height: u32) -> Self {
-        Self {
-            config: AsciiConfig {
-                width,
-                height,
-                ..Default::default()
-            }
-        }
+        Self::with_config(AsciiConfig {
+            width,
+            height,
+            ..Default::default()
+        })
     }
 
     /// Membuat instance baru dengan konfigurasi kustom.
     /// 
+    /// Konfigurasi diperiksa dengan `AsciiConfig::validate`; jika tidak valid,
+    /// setiap konversi mengembalikan error yang mencantumkan semua masalahnya.
+    /// Dengan `retro.clamp_size`, grid diperkecil proporsional agar muat di layar 80×25.
+    /// 
     /// # Arguments
     /// 
     /// * `config` - Struct `AsciiConfig` yang berisi semua pengaturan kustom
@@ -191,12 +674,64 @@ impl AsciiConverter {
     /// 
     /// let converter = AsciiConverter::with_config(config);
     /// ```
-    pub fn with_config(config: AsciiConfig) -> Self {
-        Self { config }
+    pub fn with_config(mut config: AsciiConfig) -> Self {
+        if config.retro.is_some_and(|retro| retro.clamp_size) {
+            // Batas layar berlaku untuk grid output, yang tertukar jika diputar
+            let (columns, rows) = match config.orientation {
+                Orientation::Normal => (RETRO_COLUMNS, RETRO_ROWS),
+                Orientation::Rotate90 | Orientation::Rotate270 => (RETRO_ROWS, RETRO_COLUMNS),
+            };
+            // Diperkecil proporsional agar rasio aspek grid tetap
+            let factor = (columns as f32 / config.width.max(1) as f32)
+                .min(rows as f32 / config.height.max(1) as f32)
+                .min(1.0);
+            config.width = ((config.width as f32 * factor).round() as u32).clamp(1, columns).min(config.width);
+            config.height = ((config.height as f32 * factor).round() as u32).clamp(1, rows).min(config.height);
+        }
+        let issues = config.validate().err().unwrap_or_default();
+        Self { config, on_cell: None, issues }
+    }
+
+    /// Memasang hook yang dipanggil untuk setiap sel selama konversi.
+    /// 
+    /// Memungkinkan penggantian karakter atau warna per sel (misalnya memaksa
+    /// area logo, atau pemetaan kustom) tanpa mengubah converter.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `hook` - Fungsi `(x, y, brightness, rgb) -> Option<Cell>`
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use aspix::{AsciiConverter, Cell};
+    /// use image::DynamicImage;
+    /// 
+    /// // Isi kolom pertama dengan '|' berwarna merah
+    /// let converter = AsciiConverter::new(4, 2).with_cell_hook(|x, _, brightness, _| {
+    ///     (x == 0).then_some(Cell { ch: '|', brightness, color: [255, 0, 0] })
+    /// });
+    /// let ascii = converter.convert_image(&DynamicImage::new_rgb8(8, 4)).unwrap();
+    /// assert_eq!(ascii, "|@@@\n|@@@\n");
+    /// ```
+    pub fn with_cell_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(u32, u32, f32, [u8; 3]) -> Option<Cell> + Send + Sync + 'static,
+    {
+        self.on_cell = Some(Arc::new(hook));
+        self
+    }
+
+    /// Mengembalikan konfigurasi yang sedang digunakan converter.
+    pub fn config(&self) -> &AsciiConfig {
+        &self.config
     }
 
     /// Mengkonversi gambar dari path file menjadi ASCII art.
     /// 
+    /// Dengan feature `heif`, foto HEIC dan AVIF juga dapat dibuka; dengan
+    /// feature `svg`, file vektor dirasterisasi sesuai ukuran grid.
+    /// 
     /// # Arguments
     /// 
     /// * `image_path` - Path ke file gambar yang akan dikonversi
@@ -218,12 +753,42 @@ impl AsciiConverter {
     /// }
     /// ```
     pub fn convert(&self, image_path: &str) -> Result<String, String> {
-        let img = ImageReader::open(&Path::new(image_path))
-            .map_err(|e| format!("Gagal membuka gambar: {}", e))?
-            .decode()
-            .map_err(|e| format!("Gagal mendekode gambar: {}", e))?;
+        self.try_convert(image_path).map_err(String::from)
+    }
 
-        self.process_image(&img)
+    /// Seperti `convert`, dengan error bertipe yang memisahkan batas sumber daya.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `image_path` - Path ke file gambar yang akan dikonversi
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(String)` - ASCII art dalam bentuk string jika berhasil
+    /// * `Err(ConversionError::Limit)` - Jika salah satu `ResourceLimits` terlampaui
+    /// * `Err(ConversionError::Failed)` - Jika gagal karena alasan lain
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use aspix::{AsciiConverter, ConversionError};
+    /// 
+    /// let converter = AsciiConverter::new(100, 50);
+    /// match converter.try_convert("image.jpg") {
+    ///     Ok(ascii) => println!("{}", ascii),
+    ///     Err(ConversionError::Limit(limit)) => eprintln!("Terlalu besar: {}", limit.message()),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn try_convert(&self, image_path: &str) -> Result<String, ConversionError> {
+        let started = Instant::now();
+        let (img, metadata) = self.open_with_metadata(image_path)?;
+        let art = self.art_traced(&img, started, None, None)?;
+        let output = self.render_art(&art);
+        Ok(match metadata {
+            Some(metadata) => metadata.embed(output, &self.config),
+            None => output,
+        })
     }
 
     /// Mengkonversi data bytes gambar menjadi ASCII art.
@@ -241,7 +806,7 @@ impl AsciiConverter {
     /// 
     /// # Examples
     /// 
-    /// ```rust
+    /// ```rust,no_run
     /// use aspix::AsciiConverter;
     /// 
     /// let converter = AsciiConverter::new(100, 50);
@@ -251,216 +816,810 @@ impl AsciiConverter {
     /// }
     /// ```
     pub fn convert_from_bytes(&self, bytes: &[u8]) -> Result<String, String> {
-        let img = image::load_from_memory(bytes)
+        self.try_convert_from_bytes(bytes).map_err(String::from)
+    }
+
+    /// Seperti `convert_from_bytes`, dengan error bertipe yang memisahkan batas sumber daya.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bytes` - Data bytes gambar
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(String)` - ASCII art dalam bentuk string jika berhasil
+    /// * `Err(ConversionError::Limit)` - Jika salah satu `ResourceLimits` terlampaui
+    /// * `Err(ConversionError::Failed)` - Jika gagal karena alasan lain
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use aspix::{AsciiConfig, AsciiConverter, ConversionError, LimitExceeded, ResourceLimits};
+    /// use image::{ImageOutputFormat, RgbImage};
+    /// use std::io::Cursor;
+    /// 
+    /// let mut png = Vec::new();
+    /// RgbImage::new(64, 64).write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png).unwrap();
+    /// 
+    /// let limits = ResourceLimits { max_pixels: Some(1_000), ..ResourceLimits::default() };
+    /// let converter = AsciiConverter::with_config(AsciiConfig { limits, ..AsciiConfig::default() });
+    /// assert!(matches!(
+    ///     converter.try_convert_from_bytes(&png),
+    ///     Err(ConversionError::Limit(LimitExceeded::Pixels { .. }))
+    /// ));
+    /// ```
+    pub fn try_convert_from_bytes(&self, bytes: &[u8]) -> Result<String, ConversionError> {
+        let started = Instant::now();
+        self.config.limits.check_data(bytes)?;
+        let img = self.decode_bytes(bytes)?;
+        let art = self.art_traced(&img, started, None, None)?;
+        let output = self.render_art(&art);
+        if self.config.embed_metadata {
+            return Ok(SourceMetadata::from_image(&img, Some(bytes), None, &self.config).embed(output, &self.config));
+        }
+        Ok(output)
+    }
+
+    /// Mendekode data bytes gambar (termasuk HEIF dan SVG jika feature-nya aktif).
+    /// 
+    /// Format dideteksi dari magic bytes terlebih dahulu agar format yang tidak
+    /// didukung menghasilkan pesan yang menyebut format dan feature yang dibutuhkan.
+    fn decode_bytes(&self, bytes: &[u8]) -> Result<DynamicImage, ConversionError> {
+        let _span = stage_span!("decode", bytes = bytes.len());
+        match self.check_format(bytes).map_err(String::from)? {
+            #[cfg(feature = "heif")]
+            DetectedFormat::Heif | DetectedFormat::Avif => return heif::decode(bytes, &self.config.limits),
+            #[cfg(feature = "svg")]
+            DetectedFormat::Svg => {
+                let (target_width, target_height) = self.target_dimensions();
+                let img = svg::rasterize(bytes, target_width, target_height)?;
+                self.config.limits.check_decoded(&img)?;
+                return Ok(img);
+            }
+            DetectedFormat::Psd => return layers::decode_psd(bytes, &self.config.limits),
+            _ => {}
+        }
+
+        let img = ImageReader::new(Cursor::new(bytes))
+            .with_guessed_format()
+            .map_err(ImageError::IoError)
+            .and_then(|reader| self.config.limits.decode(reader))
             .map_err(|e| format!("Gagal memuat gambar dari bytes: {}", e))?;
+        self.config.limits.check_decoded(&img)?;
+        Ok(img)
+    }
+
+    /// Mengkonversi gambar dari sumber `Read` apa pun menjadi ASCII art.
+    /// 
+    /// Seluruh isi reader dibaca ke buffer lalu didekode, sehingga cocok untuk
+    /// stdin (`cat img.png | program`), socket, atau stream lain tanpa file sementara.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `reader` - Sumber data gambar
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(String)` - ASCII art dalam bentuk string jika berhasil
+    /// * `Err(String)` - Pesan error jika gagal
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust,no_run
+    /// use aspix::AsciiConverter;
+    /// 
+    /// let converter = AsciiConverter::new(100, 50);
+    /// match converter.convert_from_reader(std::io::stdin().lock()) {
+    ///     Ok(ascii) => println!("{}", ascii),
+    ///     Err(e) => eprintln!("Error: {}", e),
+    /// }
+    /// ```
+    pub fn convert_from_reader(&self, reader: impl Read) -> Result<String, String> {
+        // Cukup satu byte melewati `max_bytes` untuk mendeteksi data yang terlalu besar
+        let max_read = self.config.limits.max_bytes.map_or(u64::MAX, |max| max.saturating_add(1));
+        let mut bytes = Vec::new();
+        reader
+            .take(max_read)
+            .read_to_end(&mut bytes)
+            .map_err(|e| format!("Gagal membaca data gambar: {}", e))?;
+
+        self.convert_from_bytes(&bytes)
+    }
+
+    /// Mengkonversi `DynamicImage` yang sudah didekode menjadi ASCII art.
+    /// 
+    /// Berguna ketika gambar sudah ada di memory, misalnya hasil pemrosesan
+    /// lain atau frame animasi.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `img` - Gambar sumber
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(String)` - ASCII art dalam bentuk string jika berhasil
+    /// * `Err(String)` - Pesan error jika gagal
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use aspix::AsciiConverter;
+    /// use image::DynamicImage;
+    /// 
+    /// let converter = AsciiConverter::new(4, 2);
+    /// let ascii = converter.convert_image(&DynamicImage::new_rgb8(8, 4)).unwrap();
+    /// assert_eq!(ascii, "@@@@\n@@@@\n");
+    /// ```
+    pub fn convert_image(&self, img: &DynamicImage) -> Result<String, String> {
+        self.process_image(img)
+    }
+
+    /// Mengkonversi gambar dari path file menjadi grid `AsciiArt` terstruktur.
+    /// 
+    /// Berbeda dengan `convert`, hasilnya menyimpan karakter, brightness, dan
+    /// warna setiap sel sehingga dapat dianalisis atau di-render ulang.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `image_path` - Path ke file gambar yang akan dikonversi
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(AsciiArt)` - Grid hasil konversi jika berhasil
+    /// * `Err(String)` - Pesan error jika gagal
+    /// 
+    /// # Examples
+    /// 
+    /// ```rust
+    /// use aspix::AsciiConverter;
+    /// 
+    /// let converter = AsciiConverter::new(100, 50);
+    /// if let Ok(art) = converter.convert_to_art("image.jpg") {
+    ///     println!("{}", art.to_text());
+    /// }
+    /// ```
+    pub fn convert_to_art(&self, image_path: &str) -> Result<AsciiArt, String> {
+        let started = Instant::now();
+        let img = self.open_image(image_path)?;
+        self.art_since(&img, started, None)
+    }
+
+    /// Mengkonversi `DynamicImage` menjadi grid `AsciiArt` terstruktur.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `img` - Gambar sumber
+    /// 
+    /// # Returns
+    /// 
+    /// * `Ok(AsciiArt)` - Grid hasil konversi jika berhasil
+    /// * `Err(String)` - Pesan error jika gagal
+    pub fn convert_image_to_art(&self, img: &DynamicImage) -> Result<AsciiArt, String> {
+        self.art_since(img, Instant::now(), None)
+    }
+
+    /// Mengkonversi gambar menjadi grid dengan memeriksa `limits.timeout` sejak `started`
+    /// di antara setiap tahap.
+    /// 
+    /// Jika `depth` diisi, grid dimodulasi dengan depth map sebelum langkah akhir;
+    /// `smart_crop` dinonaktifkan agar depth map tetap sejajar dengan gambar.
+    pub(crate) fn art_since(
+        &self,
+        img: &DynamicImage,
+        started: Instant,
+        depth: Option<(&DynamicImage, &DepthOptions)>,
+    ) -> Result<AsciiArt, String> {
+        self.art_traced(img, started, depth, None).map_err(String::from)
+    }
+
+    /// Seperti `art_since`, sekaligus mencatat waktu dan ukuran internal setiap tahap ke `probe`.
+    pub(crate) fn art_traced(
+        &self,
+        img: &DynamicImage,
+        started: Instant,
+        depth: Option<(&DynamicImage, &DepthOptions)>,
+        probe: Option<&mut PipelineProbe>,
+    ) -> Result<AsciiArt, ConversionError> {
+        self.art_adjusted(img, started, depth, probe, &mut |_, art| art)
+    }
+
+    /// Seperti `art_traced`, dengan `adjust` diterapkan pada grid mentah sebelum langkah akhir.
+    ///
+    /// `adjust` menerima converter yang benar-benar memetakan gambar, yaitu converter
+    /// turunan pada mode `pixel_art`, agar glyph dipilih ulang dengan ramp yang sama.
+    pub(crate) fn art_adjusted(
+        &self,
+        img: &DynamicImage,
+        started: Instant,
+        depth: Option<(&DynamicImage, &DepthOptions)>,
+        probe: Option<&mut PipelineProbe>,
+        adjust: &mut dyn FnMut(&AsciiConverter, AsciiArt) -> AsciiArt,
+    ) -> Result<AsciiArt, ConversionError> {
+        self.check_config()?;
+        if depth.is_some() && self.config.smart_crop {
+            let mut converter = AsciiConverter::with_config(AsciiConfig { smart_crop: false, ..self.config.clone() });
+            converter.on_cell = self.on_cell.clone();
+            return converter.art_adjusted(img, started, depth, probe, adjust);
+        }
+        if let Some((converter, downsampled)) = self.pixel_art_converter(img) {
+            return converter.art_adjusted(downsampled.as_ref().unwrap_or(img), started, depth, probe, adjust);
+        }
+        let limits = &self.config.limits;
+        limits.check_elapsed(started)?;
+        let stage = Instant::now();
+        let (processed, background) = self.prepare_image(img);
+        let prepared = stage.elapsed();
+        limits.check_elapsed(started)?;
+        let stage = Instant::now();
+        let mut art = self.image_to_art(&processed, background.as_ref());
+        if let Some((depth_map, options)) = depth {
+            depth::apply(self, &mut art, depth_map, options);
+        }
+        let art = adjust(self, art);
+        limits.check_elapsed(started)?;
+        let art = self.finish_art(art);
+        if let Some(probe) = probe {
+            *probe = PipelineProbe {
+                prepare: prepared,
+                mapping: stage.elapsed(),
+                internal_width: processed.width(),
+                internal_height: processed.height(),
+                block_size: self.block_size(),
+            };
+        }
+        Ok(art)
+    }
+
+    /// Converter turunan untuk mode `pixel_art` dengan grid yang mengikuti ukuran piksel sumber.
+    ///
+    /// Gambar yang lebih besar dari batas ikut dikembalikan setelah diperkecil (nearest-neighbor)
+    /// ke grid pikselnya. Mengembalikan `None` jika mode nonaktif atau konfigurasi saat ini
+    /// sudah sesuai.
+    fn pixel_art_converter(&self, img: &DynamicImage) -> Option<(AsciiConverter, Option<DynamicImage>)> {
+        if !self.config.pixel_art {
+            return None;
+        }
 
-        self.process_image(&img)
+        let (width, height) = match self.config.rotate {
+            Rotation::Rotate90 | Rotation::Rotate270 => (img.height(), img.width()),
+            _ => (img.width(), img.height()),
+        };
+        let (max_width, max_height) = (self.config.width.max(1), self.config.height.max(1));
+        // Gambar yang lebih besar dari batas diperkecil dengan faktor bulat agar grid tetap rapi
+        let factor = width.div_ceil(max_width).max(height.div_ceil(max_height)).max(1);
+        let (pixels_x, pixels_y) = ((width / factor).max(1), (height / factor).max(1));
+        let downsampled = (factor > 1).then(|| {
+            let (source_width, source_height) = ((img.width() / factor).max(1), (img.height() / factor).max(1));
+            img.resize_exact(source_width, source_height, FilterType::Nearest)
+        });
+        let cells_per_pixel = if pixels_x * 2 <= max_width { 2 } else { 1 };
+
+        let config = AsciiConfig {
+            width: pixels_x * cells_per_pixel,
+            height: pixels_y,
+            dither_noise: 0.0,
+            ordered_dither: None,
+            smart_crop: false,
+            ..self.config.clone()
+        };
+        let unchanged = config.width == self.config.width
+            && config.height == self.config.height
+            && self.config.dither_noise <= 0.0
+            && self.config.ordered_dither.is_none()
+            && !self.config.smart_crop;
+        if unchanged {
+            return None;
+        }
+
+        let mut converter = AsciiConverter::with_config(config);
+        converter.on_cell = self.on_cell.clone();
+        Some((converter, downsampled))
+    }
+
+    /// Langkah akhir grid: line art, orientasi output, kanvas, gradien warna, efek CRT, lalu stilisasi retro.
+    pub(crate) fn finish_art(&self, art: AsciiArt) -> AsciiArt {
+        let art = match &self.config.line_art {
+            Some(line_art) => contour::trace(&art, line_art, self.config.inverted()),
+            None => art,
+        };
+        self.lay_out_art(art)
+    }
+
+    /// Bagian `finish_art` setelah line art: orientasi, kanvas, gradien, CRT, dan retro.
+    ///
+    /// Dipisah agar grid yang disusun ulang (misalnya montage berlabel) tetap
+    /// mendapat tahap akhir yang sama tanpa di-trace ulang.
+    pub(crate) fn lay_out_art(&self, art: AsciiArt) -> AsciiArt {
+        let mut art = match self.config.orientation {
+            Orientation::Normal => art,
+            orientation => art.oriented(orientation),
+        };
+        if let Some(canvas) = &self.config.canvas {
+            art = art.on_canvas_colored(canvas, text_color(self.config.theme));
+        }
+        if let Some(gradient) = &self.config.gradient {
+            art.apply_gradient(gradient);
+        }
+        if let Some(crt) = &self.config.crt {
+            art.apply_crt(crt);
+        }
+        if self.config.retro.is_some() {
+            art.apply_retro();
+        }
+        art
     }
 
     /// Memproses gambar DynamicImage menjadi ASCII art.
     /// 
     /// Fungsi internal yang melakukan konversi utama.
-    fn process_image(&self, img: &DynamicImage) -> Result<String, String> {
-        let target_width = (self.config.width as f32 * self.config.scale) as u32;
-        let target_height = (self.config.height as f32 * self.config.scale) as u32;
-        
-        let mut processed = img.resize_exact(
-            target_width,
-            target_height,
-            FilterType::Lanczos3
-        );
+    pub(crate) fn process_image(&self, img: &DynamicImage) -> Result<String, String> {
+        let art = self.art_since(img, Instant::now(), None)?;
+        Ok(self.render_art(&art))
+    }
 
-        processed = self.apply_image_adjustments(&processed);
-        
+    /// Me-render grid sesuai konfigurasi: format berwarna jika `use_color`, teks biasa jika tidak.
+    pub(crate) fn render_art(&self, art: &AsciiArt) -> String {
+        let _span = stage_span!("render", format = ?self.config.output_format, color = self.config.use_color);
         if self.config.use_color {
-            Ok(self.image_to_colored_ascii(&processed))
+            let accessibility = self.config.html_accessibility.as_ref();
+            match (self.config.output_format, self.config.html_palette_size) {
+                (OutputFormat::Html, Some(palette_size)) => {
+                    themed_html(art.to_html_themed(palette_size, accessibility), self.config.theme)
+                }
+                (OutputFormat::Html, None) => themed_html(art.to_html_with(accessibility), self.config.theme),
+                (OutputFormat::HtmlTable, _) => {
+                    themed_html(art.to_html_table_document(&self.config.html_table), self.config.theme)
+                }
+                (OutputFormat::Emoji, _) => match &self.config.emoji_palette {
+                    Some(palette) => art.to_emoji_with(palette),
+                    None => art.to_emoji(),
+                },
+                (OutputFormat::Ansi, _) if self.config.retro.is_some() => art.to_ansi16(),
+                (OutputFormat::AnsiBackground, _) if self.config.retro.is_some() => art.to_ansi16_background(),
+                (format, _) => art.render(format),
+            }
         } else {
-            let grayscale = processed.into_luma8();
-            Ok(self.image_to_ascii(&grayscale))
+            art.to_text_with(self.config.line_ending, self.config.trim_trailing_whitespace)
         }
     }
 
-    /// Mengkonversi gambar grayscale menjadi string ASCII.
+    /// Menyiapkan gambar sebelum dipetakan ke karakter: rotasi/flip, smart crop, resize,
+    /// deteksi background, sharpening, CLAHE, lalu white balance, gain channel, contrast, dan brightness.
     /// 
-    /// Fungsi internal yang menghasilkan ASCII art dari gambar grayscale.
-    fn image_to_ascii(&self, image: &GrayImage) -> String {
-        let mut ascii_output = String::new();
-        
-        if self.config.use_high_density {
-            // Gunakan karakter densitas tinggi
-            for y in 0..self.config.height {
-                for x in 0..self.config.width {
-                    let scale_factor = self.config.scale as u32;
-                    let base_x = (x * scale_factor) as u32;
-                    let base_y = (y * scale_factor) as u32;
-                    
-                    // Hitung rata-rata brightness untuk blok piksel
-                    let mut total_brightness = 0.0;
-                    let mut count = 0.0;
-                    
-                    for dy in 0..scale_factor {
-                        for dx in 0..scale_factor {
-                            if base_x + dx < image.width() && base_y + dy < image.height() {
-                                let pixel = image.get_pixel(base_x + dx, base_y + dy);
-                                let mut brightness = pixel[0] as f32 / 255.0;
-                                
-                                if self.config.invert {
-                                    brightness = 1.0 - brightness;
-                                }
-                                
-                                total_brightness += brightness;
-                                count += 1.0;
-                            }
-                        }
-                    }
-                    
-                    let avg_brightness = if count > 0.0 { total_brightness / count } else { 0.0 };
-                    let index = (avg_brightness * (HIGH_DENSITY_CHARS.len() - 1) as f32) as usize;
-                    ascii_output.push_str(HIGH_DENSITY_CHARS[index]);
-                }
-                ascii_output.push('\n');
-            }
-        } else {
-            // Gunakan karakter ASCII normal atau detail
-            let chars = if self.config.use_detailed_chars {
-                DETAILED_ASCII_CHARS
+    /// Background dideteksi sebelum penyesuaian agar warna kunci tetap cocok.
+    pub(crate) fn prepare_image(&self, img: &DynamicImage) -> (DynamicImage, Option<BackgroundMask>) {
+        let (target_width, target_height) = self.target_dimensions();
+        let mut processed = {
+            let _span = stage_span!(
+                "resize",
+                source_width = img.width(),
+                source_height = img.height(),
+                target_width,
+                target_height
+            );
+            let oriented = self.apply_orientation(img);
+            let img = oriented.as_ref().unwrap_or(img);
+
+            let cropped = if self.config.smart_crop {
+                let aspect = self.config.width as f32 / (self.config.height as f32 * crop::CELL_ASPECT);
+                crop::salient_crop(img, aspect)
             } else {
-                ASCII_CHARS
+                None
             };
+            let img = cropped.as_ref().unwrap_or(img);
 
-            for y in 0..self.config.height {
-                for x in 0..self.config.width {
-                    let scale_factor = self.config.scale as u32;
-                    let base_x = (x * scale_factor) as u32;
-                    let base_y = (y * scale_factor) as u32;
-                    
-                    // Hitung rata-rata brightness untuk blok piksel
-                    let mut total_brightness = 0.0;
-                    let mut count = 0.0;
-                    
-                    for dy in 0..scale_factor {
-                        for dx in 0..scale_factor {
-                            if base_x + dx < image.width() && base_y + dy < image.height() {
-                                let pixel = image.get_pixel(base_x + dx, base_y + dy);
-                                let mut brightness = pixel[0] as f32 / 255.0;
-                                
-                                if self.config.invert {
-                                    brightness = 1.0 - brightness;
-                                }
-                                
-                                total_brightness += brightness;
-                                count += 1.0;
-                            }
-                        }
-                    }
-                        
-                    let avg_brightness = if count > 0.0 {
-                        total_brightness / count
-                    } else { 
-                        0.0 
-                    };
-                    
-                    let index = (avg_brightness * (chars.len() - 1) as f32) as usize;
-                    ascii_output.push(chars[index] as char);
+            match self.config.tile_height {
+                _ if self.config.pixel_art => img.resize_exact(target_width, target_height, FilterType::Nearest),
+                Some(tile_height) => {
+                    tiled::downsample_in_strips(img, target_width, target_height, tile_height, self.config.linear_light)
                 }
-                ascii_output.push('\n');
+                None if self.config.linear_light => linear::resize_linear(img, target_width, target_height, FilterType::Lanczos3),
+                None => img.resize_exact(
+                    target_width,
+                    target_height,
+                    FilterType::Lanczos3
+                ),
+            }
+        };
+
+        let _span = stage_span!("adjust");
+        let background = self
+            .config
+            .background_removal
+            .map(|mode| BackgroundMask::detect(&processed, mode));
+
+        if self.config.sharpen_amount > 0.0 {
+            processed = self.apply_sharpening(&processed);
+        }
+
+        if let Some(options) = self.config.clahe {
+            processed = clahe::apply(&processed, options);
+        }
+
+        (self.apply_image_adjustments(&processed), background)
+    }
+
+    /// Membuka dan mendekode gambar dari path.
+    /// 
+    /// Jika `tile_height` diisi, gambar JPEG, PNG, dan TIFF didekode langsung pada ukuran
+    /// yang diperkecil, lihat `tiled::open_downscaled`.
+    pub(crate) fn open_image(&self, image_path: &str) -> Result<DynamicImage, ConversionError> {
+        self.check_config()?;
+        let limits = &self.config.limits;
+        limits.check_file(Path::new(image_path))?;
+        let _span = stage_span!("decode", path = image_path);
+        match Self::sniff_file(image_path)? {
+            #[cfg(feature = "heif")]
+            Some(DetectedFormat::Heif | DetectedFormat::Avif) => {
+                let bytes = fs::read(image_path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+                return heif::decode(&bytes, limits);
+            }
+            Some(DetectedFormat::Psd) => {
+                let bytes = fs::read(image_path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+                return layers::decode_psd(&bytes, limits);
             }
+            _ => {}
         }
-        
-        ascii_output
+
+        #[cfg(feature = "svg")]
+        if svg::has_svg_extension(Path::new(image_path)) {
+            let bytes = fs::read(image_path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+            let (target_width, target_height) = self.target_dimensions();
+            let img = svg::rasterize(&bytes, target_width, target_height)?;
+            limits.check_decoded(&img)?;
+            return Ok(img);
+        }
+
+        if self.config.tile_height.is_some() {
+            let (target_width, target_height) = self.target_dimensions();
+            return tiled::open_downscaled(Path::new(image_path), target_width, target_height, self.config.linear_light, limits);
+        }
+
+        let reader = ImageReader::open(Path::new(image_path)).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+        let img = limits.decode(reader).map_err(|e| format!("Gagal mendekode gambar: {}", e))?;
+        limits.check_decoded(&img)?;
+        Ok(img)
     }
-    
-    /// Mengkonversi gambar berwarna menjadi ASCII art dengan warna.
-    /// 
-    /// Menghasilkan HTML dengan karakter ASCII yang berwarna.
-    fn image_to_colored_ascii(&self, image: &DynamicImage) -> String {
-        let mut html_output = String::from(
-            "<!DOCTYPE html>\n<html>\n<head>\n<style>\n\
-            body { background-color: #000; margin: 0; padding: 10px; }\n\
-            pre { font-family: monospace; font-size: 10px; line-height: 0.9; }\n\
-            </style>\n</head>\n<body>\n<pre>\n"
-        );
-        
-        let chars = if self.config.use_detailed_chars {
-            DETAILED_ASCII_CHARS
-        } else if self.config.use_high_density {
-            // Menggunakan blok karakter ASCII untuk densidade tinggi
-            b"@%#*+=-:. "
+
+    /// Seperti `open_image`, sekaligus mengembalikan bytes file yang dibaca.
+    /// 
+    /// File dibaca sekali ke memory lalu didekode dari bytes tersebut, sehingga
+    /// pemanggil dapat memakai bytes yang sama (misalnya untuk EXIF) tanpa membaca ulang.
+    pub(crate) fn open_image_bytes(&self, image_path: &str) -> Result<(DynamicImage, Vec<u8>), ConversionError> {
+        self.check_config()?;
+        let limits = &self.config.limits;
+        limits.check_file(Path::new(image_path))?;
+        let bytes = fs::read(image_path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+        // Hanya JPEG, PNG, dan TIFF yang memiliki decode diperkecil; format lain didekode penuh
+        let reducible = matches!(image::guess_format(&bytes), Ok(ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::Tiff));
+        let img = if self.config.tile_height.is_some() && reducible {
+            let (target_width, target_height) = self.target_dimensions();
+            tiled::decode_downscaled(&bytes, target_width, target_height, self.config.linear_light, limits)?
         } else {
-            ASCII_CHARS
+            self.decode_bytes(&bytes)?
         };
-        
+        Ok((img, bytes))
+    }
+
+    /// Mendeteksi format file dari header-nya (cukup untuk HEIF/AVIF dan PSD).
+    fn sniff_file(image_path: &str) -> Result<Option<DetectedFormat>, String> {
+        let mut header = Vec::with_capacity(FTYP_HEADER_LEN);
+        fs::File::open(image_path)
+            .and_then(|file| file.take(FTYP_HEADER_LEN as u64).read_to_end(&mut header))
+            .map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+        Ok(DetectedFormat::sniff(&header))
+    }
+
+    /// Menerapkan rotasi lalu flip pada gambar sumber.
+    /// 
+    /// Mengembalikan `None` jika tidak ada transformasi agar gambar tidak perlu disalin.
+    pub(crate) fn apply_orientation(&self, img: &DynamicImage) -> Option<DynamicImage> {
+        let mut oriented = match self.config.rotate {
+            Rotation::None => None,
+            Rotation::Rotate90 => Some(img.rotate90()),
+            Rotation::Rotate180 => Some(img.rotate180()),
+            Rotation::Rotate270 => Some(img.rotate270()),
+        };
+
+        if self.config.flip_horizontal {
+            oriented = Some(oriented.as_ref().unwrap_or(img).fliph());
+        }
+        if self.config.flip_vertical {
+            oriented = Some(oriented.as_ref().unwrap_or(img).flipv());
+        }
+
+        oriented
+    }
+
+    /// Menolak konversi jika konfigurasi tidak lolos `AsciiConfig::validate`.
+    pub(crate) fn check_config(&self) -> Result<(), String> {
+        if self.issues.is_empty() {
+            return Ok(());
+        }
+        let messages: Vec<String> = self.issues.iter().map(ConfigIssue::message).collect();
+        Err(format!("Konfigurasi tidak valid: {}", messages.join("; ")))
+    }
+
+    /// Menghitung dimensi gambar internal berdasarkan ukuran output dan skala.
+    /// 
+    /// Pada mode quadrant setiap sel selalu mencakup 2×2 piksel.
+    pub(crate) fn target_dimensions(&self) -> (u32, u32) {
+        if self.config.use_quadrants {
+            return (self.config.width * 2, self.config.height * 2);
+        }
+        (
+            (self.config.width as f32 * self.internal_scale()) as u32,
+            (self.config.height as f32 * self.internal_scale()) as u32,
+        )
+    }
+
+    /// Skala internal efektif, lihat `AsciiConfig::internal_scale`.
+    fn internal_scale(&self) -> f32 {
+        self.config.internal_scale()
+    }
+
+    /// Ukuran blok piksel internal (per sisi) yang diwakili satu sel.
+    pub(crate) fn block_size(&self) -> u32 {
+        if self.config.use_quadrants {
+            2
+        } else {
+            (self.internal_scale() as u32).max(1)
+        }
+    }
+
+    /// Memetakan gambar yang sudah disiapkan menjadi grid sel.
+    /// 
+    /// Untuk output teks, brightness diambil dari konversi grayscale bawaan
+    /// crate `image`; untuk output berwarna digunakan formula
+    /// (R*0.3 + G*0.59 + B*0.11) agar konsisten dengan warna yang ditampilkan.
+    /// 
+    /// Dengan `dominant_colors`, warna sel kemudian dibatasi pada warna dominan gambar.
+    pub(crate) fn image_to_art(&self, image: &DynamicImage, background: Option<&BackgroundMask>) -> AsciiArt {
+        let _span = stage_span!("map", columns = self.config.width, rows = self.config.height);
+        let mut art = self.map_cells(image, background);
+        if let Some(count) = self.config.dominant_colors {
+            let colors = palette::dominant_colors(image, count).into_iter().map(|dominant| dominant.color).collect();
+            art.recolor(&Recolor::Palette(colors));
+        }
+        art
+    }
+
+    /// Memetakan setiap blok piksel menjadi satu sel (atau pola quadrant).
+    fn map_cells(&self, image: &DynamicImage, background: Option<&BackgroundMask>) -> AsciiArt {
+        #[cfg(feature = "simd")]
+        if let Some(art) = self.simd_image_to_art(image, background) {
+            return art;
+        }
+
+        let grayscale = (!self.config.use_color).then(|| image.to_luma8());
+        let luma = |x: u32, y: u32| -> f32 {
+            let value = match &grayscale {
+                Some(gray) => gray.get_pixel(x, y)[0] as f32 / 255.0,
+                None => {
+                    let pixel = image.get_pixel(x, y);
+                    let r = pixel[0] as f32 / 255.0;
+                    let g = pixel[1] as f32 / 255.0;
+                    let b = pixel[2] as f32 / 255.0;
+                    r * 0.3 + g * 0.59 + b * 0.11
+                }
+            };
+            match &self.config.curve {
+                Some(curve) => curve.apply(value),
+                None => value,
+            }
+        };
+
+        let ramp = self.character_ramp();
+        let mixed = self
+            .config
+            .mixed_charset
+            .map(|mixed| (mixed, high_density_ramp(), detailed_ramp()));
+        let adaptive = self.config.adaptive_density.map(|adaptive| (adaptive.detail_threshold, detailed_ramp()));
+        let scale_factor = self.block_size();
+        let center = scale_factor / 2;
+        let mut rng = Rng::from_seed(self.config.seed);
+        let linear_table = self.config.linear_light.then(linear::linear_table);
+        let mut cells = Vec::with_capacity((self.config.width * self.config.height) as usize);
+        let mut samples: Vec<(f32, [f32; 3])> = Vec::with_capacity((scale_factor * scale_factor) as usize);
+        let bayer = self.config.ordered_dither.map(|matrix| {
+            // Amplitudo satu tingkat ramp agar pola hanya berpindah ke karakter tetangga
+            let levels = mixed.as_ref().map_or(ramp.len(), |(_, _, midtones)| midtones.len());
+            (matrix.size(), matrix.thresholds(), 1.0 / (levels.max(2) - 1) as f32)
+        });
+
         for y in 0..self.config.height {
             for x in 0..self.config.width {
-                let scale_factor = self.config.scale as u32;
-                let base_x = (x * scale_factor) as u32;
-                let base_y = (y * scale_factor) as u32;
+                let base_x = x * scale_factor;
+                let base_y = y * scale_factor;
                 
                 // Hitung rata-rata warna dan brightness untuk blok piksel
-                let mut total_r = 0.0;
-                let mut total_g = 0.0;
-                let mut total_b = 0.0;
+                let mut total_rgb = [0.0f32; 3];
                 let mut total_brightness = 0.0;
                 let mut count = 0.0;
+                let mut background_count = 0.0;
+                let mut center_sample = 0;
+                samples.clear();
                 
                 for dy in 0..scale_factor {
                     for dx in 0..scale_factor {
                         if base_x + dx < image.width() && base_y + dy < image.height() {
                             let pixel = image.get_pixel(base_x + dx, base_y + dy);
-                            let r = pixel[0] as f32 / 255.0;
-                            let g = pixel[1] as f32 / 255.0;
-                            let b = pixel[2] as f32 / 255.0;
-                            
-                            // Brightness menggunakan formula standar (R*0.3 + G*0.59 + B*0.11)
-                            let brightness = r * 0.3 + g * 0.59 + b * 0.11;
-                            
-                            total_r += r;
-                            total_g += g;
-                            total_b += b;
-                            total_brightness += brightness;
+                            let rgb = [0, 1, 2].map(|c| pixel[c] as f32 / 255.0);
+                            let pixel_luma = luma(base_x + dx, base_y + dy);
+                            for c in 0..3 {
+                                total_rgb[c] += match &linear_table {
+                                    Some(table) => table[pixel[c] as usize],
+                                    None => rgb[c],
+                                };
+                            }
+                            total_brightness += pixel_luma;
                             count += 1.0;
+                            if self.config.sampling != Sampling::Mean {
+                                if dx <= center && dy <= center {
+                                    center_sample = samples.len();
+                                }
+                                samples.push((pixel_luma, rgb));
+                            }
+                            if background.is_some_and(|mask| mask.is_background(base_x + dx, base_y + dy)) {
+                                background_count += 1.0;
+                            }
                         }
                     }
                 }
-                
-                if count > 0.0 {
-                    let avg_r = total_r / count;
-                    let avg_g = total_g / count;
-                    let avg_b = total_b / count;
-                    let avg_brightness = if self.config.invert {
-                        1.0 - (total_brightness / count)
-                    } else {
-                        total_brightness / count
-                    };
-                    
-                    // Hitung karakter berdasarkan brightness
-                    let char_index = (avg_brightness * (chars.len() - 1) as f32) as usize;
-                    let character = if self.config.use_high_density && char_index < HIGH_DENSITY_CHARS.len() {
-                        HIGH_DENSITY_CHARS[char_index].to_string()
+
+                let (avg_rgb, brightness) = if samples.is_empty() {
+                    if count > 0.0 {
+                        let avg_rgb = match linear_table {
+                            Some(_) => total_rgb.map(|c| linear::linear_to_srgb(c / count)),
+                            None => total_rgb.map(|c| c / count),
+                        };
+                        (avg_rgb, total_brightness / count)
                     } else {
-                        let char_bytes = &[chars[char_index]];
-                        std::str::from_utf8(char_bytes)
-                            .map(|s| s.to_string())
-                            .unwrap_or_else(|_| " ".to_string())
-                    };
-                    
-                    // Terapkan saturasi warna
-                    let sat = self.config.color_saturation;
-                    let r = ((avg_r * sat + (1.0 - sat) * 0.5) * 255.0) as u8;
-                    let g = ((avg_g * sat + (1.0 - sat) * 0.5) * 255.0) as u8;
-                    let b = ((avg_b * sat + (1.0 - sat) * 0.5) * 255.0) as u8;
-                    
-                    // Tambahkan karakter dengan warna ke output HTML
-                    html_output.push_str(&format!("<span style=\"color:rgb({},{},{})\">{}</span>", r, g, b, character));
+                        ([0.0; 3], 0.0)
+                    }
+                } else {
+                    let (selected_luma, selected_rgb) = select_sample(&mut samples, self.config.sampling, center_sample);
+                    (selected_rgb, selected_luma)
+                };
+
+                // Beri kesempatan hook untuk menggantikan sel ini
+                if let Some(hook) = &self.on_cell {
+                    let source = avg_rgb.map(|c| (c * 255.0).round() as u8);
+                    if let Some(cell) = hook(x, y, brightness, source) {
+                        cells.push(cell);
+                        continue;
+                    }
+                }
+
+                // Sel yang didominasi background dikosongkan
+                if count > 0.0 && background_count * 2.0 > count {
+                    let color = self.config.color_mode.apply(avg_rgb, brightness, self.config.color_saturation);
+                    cells.push(Cell { ch: ' ', brightness, color });
+                    continue;
+                }
+
+                let mut mapped = if self.config.inverted() { 1.0 - brightness } else { brightness };
+                if self.config.dither_noise > 0.0 {
+                    mapped = (mapped + (rng.next_f32() - 0.5) * self.config.dither_noise).clamp(0.0, 1.0);
                 }
+                if let Some((size, thresholds, step)) = &bayer {
+                    // Indeks ramp dibulatkan ke bawah, jadi threshold 0..1 menaikkan sel
+                    // ke tingkat berikutnya dengan peluang sebesar sisa pecahannya
+                    let threshold = thresholds[((y % size) * size + x % size) as usize];
+                    mapped = (mapped + threshold * step).clamp(0.0, 1.0);
+                }
+                
+                // Hitung karakter berdasarkan brightness (atau pola 2×2 pada mode quadrant)
+                let ch = if self.config.use_quadrants {
+                    let sample = |dx: u32, dy: u32| {
+                        let px = (base_x + dx).min(image.width().saturating_sub(1));
+                        let py = (base_y + dy).min(image.height().saturating_sub(1));
+                        luma(px, py)
+                    };
+                    quadrant_glyph([sample(0, 0), sample(1, 0), sample(0, 1), sample(1, 1)], self.config.inverted())
+                } else {
+                    match &adaptive {
+                        Some((threshold, detailed)) if local_detail(image, base_x, base_y, scale_factor, &luma) > *threshold => {
+                            ramp_glyph(detailed, None, mapped)
+                        }
+                        _ => ramp_glyph(&ramp, mixed.as_ref(), mapped),
+                    }
+                };
+
+                // Tentukan warna sesuai color mode (saturasi untuk warna asli)
+                let color = self.config.color_mode.apply(avg_rgb, brightness, self.config.color_saturation);
+
+                cells.push(Cell { ch, brightness, color });
             }
-            html_output.push_str("<br/>\n");
         }
-        
-        html_output.push_str("</pre>\n</body>\n</html>");
-        html_output
+
+        AsciiArt::new(self.config.width, self.config.height, cells)
+            .expect("jumlah sel selalu sesuai dimensi konfigurasi")
+    }
+
+    /// Pemetaan brightness sel (sebelum `invert`) ke glyph ramp, tanpa dithering.
+    /// 
+    /// Mengembalikan `None` pada mode quadrant karena glyph-nya ditentukan pola blok.
+    pub(crate) fn glyph_mapper(&self) -> Option<impl Fn(f32) -> char> {
+        if self.config.use_quadrants {
+            return None;
+        }
+        let ramp = self.character_ramp();
+        let mixed = self
+            .config
+            .mixed_charset
+            .map(|mixed| (mixed, high_density_ramp(), detailed_ramp()));
+        let inverted = self.config.inverted();
+        Some(move |brightness: f32| {
+            let mapped = if inverted { 1.0 - brightness } else { brightness };
+            ramp_glyph(&ramp, mixed.as_ref(), mapped.clamp(0.0, 1.0))
+        })
     }
 
+    /// Jalur cepat SIMD untuk kasus satu sel tepat satu piksel.
+    /// 
+    /// Mengembalikan `None` jika `use_simd` nonaktif atau konfigurasi membutuhkan
+    /// jalur umum (scale > 1, quadrant, dithering, mixed charset, kepadatan adaptif,
+    /// hook sel, atau background removal).
+    #[cfg(feature = "simd")]
+    fn simd_image_to_art(&self, image: &DynamicImage, background: Option<&BackgroundMask>) -> Option<AsciiArt> {
+        let direct = self.config.use_simd
+            && self.block_size() == 1
+            && !self.config.use_quadrants
+            && self.config.dither_noise <= 0.0
+            && self.config.ordered_dither.is_none()
+            && self.config.mixed_charset.is_none()
+            && self.config.adaptive_density.is_none()
+            && self.on_cell.is_none()
+            && background.is_none();
+        if !direct {
+            return None;
+        }
+
+        let converted;
+        let rgba = match image.as_rgba8() {
+            Some(rgba) => rgba,
+            None => {
+                converted = image.to_rgba8();
+                &converted
+            }
+        };
+
+        let ramp = self.character_ramp();
+        let mut luma = simd::luminance(rgba.as_raw(), !self.config.use_color);
+        if let Some(curve) = &self.config.curve {
+            luma.iter_mut().for_each(|value| *value = curve.apply(*value));
+        }
+        let indices = simd::ramp_indices(&luma, self.config.inverted(), ramp.len());
+
+        let cells = rgba
+            .pixels()
+            .zip(luma.iter().zip(indices.iter()))
+            .map(|(pixel, (&brightness, &index))| {
+                let source = [pixel[0], pixel[1], pixel[2]].map(|c| c as f32 / 255.0);
+                let color = self.config.color_mode.apply(source, brightness, self.config.color_saturation);
+                Cell { ch: ramp[index], brightness, color }
+            })
+            .collect();
+
+        AsciiArt::new(rgba.width(), rgba.height(), cells).ok()
+    }
+
+    /// Menentukan ramp karakter (gelap → terang) sesuai konfigurasi.
+    /// 
+    /// Urutan prioritas: `custom_chars`, lalu `use_high_density`, lalu
+    /// `use_detailed_chars`, dan terakhir set ASCII dasar. Untuk output berwarna,
+    /// `use_detailed_chars` tetap didahulukan dari `use_high_density`.
+    pub(crate) fn character_ramp(&self) -> Vec<char> {
+        match &self.config.custom_chars {
+            Some(custom) if !custom.is_empty() => custom.clone(),
+            _ if self.config.use_color && self.config.use_detailed_chars => detailed_ramp(),
+            _ if self.config.use_high_density => high_density_ramp(),
+            _ if self.config.use_detailed_chars => detailed_ramp(),
+            _ => ASCII_CHARS.iter().map(|&b| b as char).collect(),
+        }
+    }
+    
     /// Menyimpan hasil ASCII art ke file.
     /// 
     /// # Arguments
@@ -488,11 +1647,20 @@ impl AsciiConverter {
             .map_err(|e| format!("Gagal menyimpan file: {}", e))
     }
 
-    /// Menerapkan penyesuaian contrast dan brightness pada gambar.
+    /// Menerapkan gain per channel (white balance dan `channel_gains`), contrast, brightness,
+    /// saturasi, dan hue pada gambar.
     /// 
     /// Fungsi internal untuk memodifikasi gambar sebelum konversi ke ASCII.
     fn apply_image_adjustments(&self, img: &DynamicImage) -> DynamicImage {
+        let mut gains = self.config.channel_gains;
+        if let Some(white_balance) = self.config.white_balance {
+            let balance = white_balance.gains(img);
+            for c in 0..3 {
+                gains[c] *= balance[c];
+            }
+        }
         let mut adjusted = img.to_rgba8();
+        let adjust_color = self.config.saturation != 1.0 || self.config.hue_rotation % 360.0 != 0.0;
         
         // Iterasi melalui setiap pixel
         for pixel in adjusted.pixels_mut() {
@@ -500,6 +1668,9 @@ impl AsciiConverter {
             for c in 0..3 {
                 // Normalisasi nilai warna ke range 0.0 - 1.0
                 let mut color = pixel[c] as f32 / 255.0;
+
+                // Terapkan gain channel untuk mengoreksi color cast
+                color = (color * gains[c]).clamp(0.0, 1.0);
                 
                 // Terapkan penyesuaian contrast
                 // Formula: (color - 0.5) * contrast + 0.5
@@ -516,9 +1687,173 @@ impl AsciiConverter {
                 // Konversi kembali ke range 0-255
                 pixel[c] = (color * 255.0) as u8;
             }
+
+            // Terapkan saturasi dan rotasi hue dalam ruang HSV
+            if adjust_color {
+                let rgb = [pixel[0], pixel[1], pixel[2]].map(|c| c as f32 / 255.0);
+                let (hue, sat, value) = rgb_to_hsv(rgb);
+                let hue = (hue + self.config.hue_rotation).rem_euclid(360.0);
+                let sat = (sat * self.config.saturation).clamp(0.0, 1.0);
+                let [r, g, b] = hsv_to_rgb(hue, sat, value);
+                pixel[0] = (r * 255.0).round() as u8;
+                pixel[1] = (g * 255.0).round() as u8;
+                pixel[2] = (b * 255.0).round() as u8;
+            }
         }
 
         // Kembalikan gambar yang telah disesuaikan
         DynamicImage::ImageRgba8(adjusted)
     }
+
+    /// Menerapkan unsharp mask untuk mempertahankan detail halus setelah resize.
+    /// 
+    /// Formula: hasil = asli + amount * (asli - blur(asli, radius)).
+    /// Dijalankan pada gambar yang sudah diperkecil sehingga biayanya kecil.
+    fn apply_sharpening(&self, img: &DynamicImage) -> DynamicImage {
+        let original = img.to_rgba8();
+        let blurred = image::imageops::blur(&original, self.config.sharpen_radius.max(0.1));
+        let amount = self.config.sharpen_amount;
+
+        let mut sharpened = original.clone();
+        for (pixel, (orig, blur)) in sharpened
+            .pixels_mut()
+            .zip(original.pixels().zip(blurred.pixels()))
+        {
+            // Alpha dibiarkan, hanya channel warna yang dipertajam
+            for c in 0..3 {
+                let value = orig[c] as f32 + amount * (orig[c] as f32 - blur[c] as f32);
+                pixel[c] = value.clamp(0.0, 255.0) as u8;
+            }
+        }
+
+        DynamicImage::ImageRgba8(sharpened)
+    }
+}
+
+/// Memilih glyph ramp untuk brightness yang sudah dipetakan (setelah `invert` dan dithering).
+/// 
+/// Dengan mixed charset, nilai di luar ambang memakai ramp ekstrem dan sisanya ramp midtone.
+fn ramp_glyph(ramp: &[char], mixed: Option<&(MixedCharset, Vec<char>, Vec<char>)>, mapped: f32) -> char {
+    let ramp = match mixed {
+        Some((mixed, extremes, _)) if mapped < mixed.dark_threshold || mapped > mixed.light_threshold => extremes,
+        Some((_, _, midtones)) => midtones,
+        None => ramp,
+    };
+    let index = (mapped * (ramp.len() - 1) as f32) as usize;
+    ramp[index.min(ramp.len() - 1)]
+}
+
+/// Simpangan baku luminance pada blok sel di `(base_x, base_y)` selebar `size`
+/// piksel, diperluas satu piksel ke setiap sisi agar sel satu piksel pun memiliki tetangga.
+fn local_detail(image: &DynamicImage, base_x: u32, base_y: u32, size: u32, luma: &impl Fn(u32, u32) -> f32) -> f32 {
+    let x_range = base_x.saturating_sub(1)..(base_x + size + 1).min(image.width());
+    let y_range = base_y.saturating_sub(1)..(base_y + size + 1).min(image.height());
+
+    let (mut sum, mut sum_squares, mut count) = (0.0f32, 0.0f32, 0.0f32);
+    for y in y_range {
+        for x in x_range.clone() {
+            let value = luma(x, y);
+            sum += value;
+            sum_squares += value * value;
+            count += 1.0;
+        }
+    }
+    if count == 0.0 {
+        return 0.0;
+    }
+    let mean = sum / count;
+    (sum_squares / count - mean * mean).max(0.0).sqrt()
+}
+
+/// Memilih karakter quadrant untuk empat sampel brightness (kiri-atas, kanan-atas,
+/// kiri-bawah, kanan-bawah).
+/// 
+/// Sub-piksel yang lebih gelap dari rata-rata blok diisi, mengikuti konvensi ramp
+/// (gelap = karakter padat). Blok yang hampir seragam diisi penuh atau dikosongkan
+/// berdasarkan rata-ratanya agar area datar tidak menjadi noise.
+fn quadrant_glyph(samples: [f32; 4], invert: bool) -> char {
+    let samples = if invert { samples.map(|s| 1.0 - s) } else { samples };
+    let mean = samples.iter().sum::<f32>() / 4.0;
+    let min = samples.iter().cloned().fold(f32::MAX, f32::min);
+    let max = samples.iter().cloned().fold(f32::MIN, f32::max);
+
+    if max - min < 0.1 {
+        return if mean < 0.5 { QUADRANT_CHARS[15] } else { QUADRANT_CHARS[0] };
+    }
+
+    let mask = samples
+        .iter()
+        .enumerate()
+        .filter(|(_, &s)| s < mean)
+        .fold(0, |mask, (i, _)| mask | (1 << i));
+    QUADRANT_CHARS[mask]
+}
+
+/// Ramp densitas tinggi sebagai daftar karakter.
+fn high_density_ramp() -> Vec<char> {
+    HIGH_DENSITY_CHARS.iter().filter_map(|s| s.chars().next()).collect()
+}
+
+/// Ramp ASCII detail sebagai daftar karakter.
+fn detailed_ramp() -> Vec<char> {
+    DETAILED_ASCII_CHARS.iter().map(|&b| b as char).collect()
+}
+
+/// Mengubah RGB (0.0 - 1.0) ke HSV: hue dalam derajat, saturasi dan value 0.0 - 1.0.
+fn rgb_to_hsv([r, g, b]: [f32; 3]) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+    (hue, saturation, max)
+}
+
+/// Mengubah HSV kembali ke RGB (0.0 - 1.0).
+fn hsv_to_rgb(hue: f32, saturation: f32, value: f32) -> [f32; 3] {
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let m = value - chroma;
+
+    let (r, g, b) = match (hue / 60.0) as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    [r + m, g + m, b + m]
+}
+
+/// Memilih sampel yang mewakili blok sesuai strategi sampling.
+/// 
+/// `samples` tidak boleh kosong; urutannya dapat berubah untuk `Sampling::Median`.
+fn select_sample(samples: &mut [(f32, [f32; 3])], sampling: Sampling, center: usize) -> (f32, [f32; 3]) {
+    match sampling {
+        Sampling::Mean => {
+            let count = samples.len() as f32;
+            let luma = samples.iter().map(|(luma, _)| luma).sum::<f32>() / count;
+            let rgb = [0, 1, 2].map(|c| samples.iter().map(|(_, rgb)| rgb[c]).sum::<f32>() / count);
+            (luma, rgb)
+        }
+        Sampling::Median => {
+            let middle = samples.len() / 2;
+            *samples.select_nth_unstable_by(middle, |a, b| a.0.total_cmp(&b.0)).1
+        }
+        Sampling::Max => *samples.iter().max_by(|a, b| a.0.total_cmp(&b.0)).expect("blok tidak kosong"),
+        Sampling::Min => *samples.iter().min_by(|a, b| a.0.total_cmp(&b.0)).expect("blok tidak kosong"),
+        Sampling::Center => samples[center.min(samples.len() - 1)],
+    }
 }
@@ -0,0 +1,83 @@
+//! Smart crop: memilih area paling "menarik" sebelum konversi.
+//!
+//! Kepadatan tepi (gradien luminance) dihitung pada salinan kecil gambar,
+//! lalu jendela terbesar dengan rasio aspek target digeser sepanjang sumbu
+//! yang berlebih untuk mencari posisi dengan energi tepi tertinggi. Dengan
+//! begitu wajah atau subjek tidak hilang saat foto lebar dimasukkan ke grid.
+
+use image::{imageops::FilterType, DynamicImage, GenericImageView};
+
+/// Perbandingan tinggi terhadap lebar satu sel karakter pada font monospace umum.
+pub(crate) const CELL_ASPECT: f32 = 2.0;
+
+/// Sisi terpanjang salinan gambar untuk analisis saliency.
+const ANALYSIS_SIZE: u32 = 256;
+
+/// Crop tidak dilakukan jika rasio aspek sudah hampir sama (selisih relatif).
+const ASPECT_TOLERANCE: f32 = 0.02;
+
+/// Memotong gambar ke area paling menarik dengan rasio aspek `aspect` (lebar / tinggi).
+///
+/// Mengembalikan `None` jika gambar sudah memiliki rasio aspek target.
+pub(crate) fn salient_crop(img: &DynamicImage, aspect: f32) -> Option<DynamicImage> {
+    let (width, height) = img.dimensions();
+    if width == 0 || height == 0 || !aspect.is_finite() || aspect <= 0.0 {
+        return None;
+    }
+    let source_aspect = width as f32 / height as f32;
+    if (source_aspect / aspect - 1.0).abs() <= ASPECT_TOLERANCE {
+        return None;
+    }
+
+    // Analisis pada salinan kecil agar biaya tidak bergantung pada ukuran sumber
+    let small = img
+        .resize(ANALYSIS_SIZE, ANALYSIS_SIZE, FilterType::Triangle)
+        .into_luma8();
+    let (small_width, small_height) = small.dimensions();
+    let energy = |x: u32, y: u32| -> u64 {
+        let center = small.get_pixel(x, y)[0] as i32;
+        let right = small.get_pixel((x + 1).min(small_width - 1), y)[0] as i32;
+        let below = small.get_pixel(x, (y + 1).min(small_height - 1))[0] as i32;
+        ((right - center).abs() + (below - center).abs()) as u64
+    };
+
+    // Energi per kolom (jika gambar terlalu lebar) atau per baris (jika terlalu tinggi)
+    let crop_horizontally = source_aspect > aspect;
+    let profile: Vec<u64> = if crop_horizontally {
+        (0..small_width).map(|x| (0..small_height).map(|y| energy(x, y)).sum()).collect()
+    } else {
+        (0..small_height).map(|y| (0..small_width).map(|x| energy(x, y)).sum()).collect()
+    };
+
+    let (full, window) = if crop_horizontally {
+        (width, ((height as f32 * aspect).round() as u32).clamp(1, width))
+    } else {
+        (height, ((width as f32 / aspect).round() as u32).clamp(1, height))
+    };
+    let start = best_window(&profile, window as f32 / full as f32);
+    let offset = ((start as f32 / profile.len() as f32) * full as f32).round() as u32;
+    let offset = offset.min(full - window);
+
+    Some(if crop_horizontally {
+        img.crop_imm(offset, 0, window, height)
+    } else {
+        img.crop_imm(0, offset, width, window)
+    })
+}
+
+/// Posisi awal jendela dengan jumlah energi terbesar.
+///
+/// `fraction` adalah panjang jendela relatif terhadap panjang profil.
+fn best_window(profile: &[u64], fraction: f32) -> usize {
+    let len = ((profile.len() as f32 * fraction).round() as usize).clamp(1, profile.len());
+    let mut sum: u64 = profile[..len].iter().sum();
+    let (mut best, mut best_sum) = (0, sum);
+    for start in 1..=profile.len() - len {
+        sum = sum + profile[start + len - 1] - profile[start - 1];
+        if sum > best_sum {
+            best = start;
+            best_sum = sum;
+        }
+    }
+    best
+}
@@ -0,0 +1,96 @@
+//! Stilisasi layar CRT: scanline, color bleed horizontal, dan vignette.
+//!
+//! Efek bekerja pada level sel dengan hanya mengubah warna, sehingga terlihat
+//! pada output berwarna (ANSI, HTML) dan tidak mengubah teks biasa.
+
+use crate::crop::CELL_ASPECT;
+use crate::AsciiArt;
+
+/// Opsi efek CRT untuk splash screen bertema retro.
+///
+/// * `scanline` - Seberapa gelap baris ganjil (0.0 - 1.0, 0.0 = tanpa scanline)
+/// * `bleed` - Porsi warna yang "luber" dari sel tetangga kiri-kanan (0.0 - 1.0)
+/// * `vignette` - Seberapa gelap pojok layar dibanding tengahnya (0.0 - 1.0)
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiArt, Cell, CrtEffect};
+///
+/// let cell = Cell { ch: '#', brightness: 0.5, color: [200, 200, 200] };
+/// let mut art = AsciiArt::new(1, 2, vec![cell; 2]).unwrap();
+/// art.apply_crt(&CrtEffect { scanline: 0.5, bleed: 0.0, vignette: 0.0 });
+/// assert_eq!(art.get(0, 0).unwrap().color, [200, 200, 200]);
+/// assert_eq!(art.get(0, 1).unwrap().color, [100, 100, 100]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrtEffect {
+    pub scanline: f32,
+    pub bleed: f32,
+    pub vignette: f32,
+}
+
+impl Default for CrtEffect {
+    /// Membuat opsi default:
+    /// * scanline: 0.35
+    /// * bleed: 0.25
+    /// * vignette: 0.4
+    fn default() -> Self {
+        Self {
+            scanline: 0.35,
+            bleed: 0.25,
+            vignette: 0.4,
+        }
+    }
+}
+
+impl AsciiArt {
+    /// Menerapkan efek CRT pada warna sel; karakter dan brightness tidak berubah.
+    ///
+    /// Color bleed dihitung dari warna asli tetangga, lalu vignette dan scanline
+    /// meredupkan hasilnya. Jarak vertikal vignette dikalikan `CELL_ASPECT` (sel
+    /// dua kali lebih tinggi dari lebarnya) sehingga gelapnya melingkar pada layar,
+    /// dengan kegelapan penuh tepat di pojok.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, CrtEffect};
+    ///
+    /// // Grid 6x3 sel tampil persegi; sel (0, 1) dan (1, 0) sama-sama berjarak 2.5
+    /// // lebar sel dari pusat layar sehingga sama gelapnya
+    /// let cell = Cell { ch: '#', brightness: 0.5, color: [200, 200, 200] };
+    /// let mut art = AsciiArt::new(6, 3, vec![cell; 18]).unwrap();
+    /// art.apply_crt(&CrtEffect { scanline: 0.0, bleed: 0.0, vignette: 1.0 });
+    /// let color = |x, y| art.get(x, y).unwrap().color;
+    /// assert_eq!(color(0, 1), color(1, 0));
+    /// assert!(color(0, 0)[0] < color(0, 1)[0]);
+    /// ```
+    pub fn apply_crt(&mut self, effect: &CrtEffect) {
+        let (width, height) = (self.width(), self.height());
+        let bleed = effect.bleed.clamp(0.0, 1.0);
+        let original: Vec<[u8; 3]> = self.cells().iter().map(|cell| cell.color).collect();
+        let color_at = |x: u32, y: u32| original[(y * width + x) as usize].map(|c| c as f32);
+        // Jarak dari pusat dalam satuan lebar sel, dibandingkan dengan jarak pusat ke pojok
+        let offset = |value: u32, size: u32| value as f32 + 0.5 - size as f32 / 2.0;
+        let corner = (width as f32 / 2.0).powi(2) + (height as f32 * CELL_ASPECT / 2.0).powi(2);
+
+        for y in 0..height {
+            for x in 0..width {
+                let own = color_at(x, y);
+                let left = if x > 0 { color_at(x - 1, y) } else { own };
+                let right = if x + 1 < width { color_at(x + 1, y) } else { own };
+                let mixed = [0, 1, 2].map(|c| own[c] * (1.0 - bleed) + (left[c] + right[c]) / 2.0 * bleed);
+
+                let (dx, dy) = (offset(x, width), offset(y, height) * CELL_ASPECT);
+                let mut factor = 1.0 - effect.vignette.clamp(0.0, 1.0) * (dx * dx + dy * dy) / corner;
+                if y % 2 == 1 {
+                    factor *= 1.0 - effect.scanline.clamp(0.0, 1.0);
+                }
+
+                let cell = self.get_mut(x, y).expect("koordinat di dalam grid");
+                cell.color = mixed.map(|c| (c * factor).round().clamp(0.0, 255.0) as u8);
+            }
+        }
+    }
+}
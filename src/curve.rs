@@ -0,0 +1,146 @@
+//! Kurva tone untuk membentuk luminance sebelum pemetaan karakter.
+
+/// Jumlah entri tabel lookup kurva.
+pub const TONE_CURVE_SIZE: usize = 256;
+
+/// Kurva tone yang memetakan luminance input (0.0 - 1.0) ke luminance output.
+///
+/// Kurva disimpan sebagai tabel 256 entri dan dievaluasi dengan interpolasi
+/// linear, sehingga murah diterapkan pada setiap piksel.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, ToneCurve};
+///
+/// // Angkat bayangan, pertahankan highlight
+/// let curve = ToneCurve::from_points(&[(0.0, 0.0), (0.25, 0.4), (1.0, 1.0)]).unwrap();
+/// assert!((curve.apply(0.25) - 0.4).abs() < 0.01);
+/// assert!(curve.apply(0.1) > 0.1);
+///
+/// let config = AsciiConfig { curve: Some(curve), ..Default::default() };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToneCurve {
+    lut: Vec<f32>,
+}
+
+impl ToneCurve {
+    /// Membuat kurva halus dari titik kontrol `(input, output)` dalam rentang 0.0 - 1.0.
+    ///
+    /// Titik diinterpolasi dengan spline kubik monoton (Fritsch-Carlson) sehingga
+    /// kurva melewati setiap titik tanpa overshoot. Di luar titik pertama dan
+    /// terakhir, output bernilai konstan.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ToneCurve)` - Kurva hasil interpolasi
+    /// * `Err(String)` - Pesan error jika titik kurang dari dua, di luar rentang, atau input ganda
+    pub fn from_points(points: &[(f32, f32)]) -> Result<Self, String> {
+        if points.len() < 2 {
+            return Err("Kurva membutuhkan minimal dua titik kontrol".to_string());
+        }
+        if points
+            .iter()
+            .any(|&(x, y)| !(0.0..=1.0).contains(&x) || !(0.0..=1.0).contains(&y))
+        {
+            return Err("Titik kontrol kurva harus berada dalam rentang 0.0 - 1.0".to_string());
+        }
+
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if points.windows(2).any(|pair| pair[1].0 - pair[0].0 <= f32::EPSILON) {
+            return Err("Input titik kontrol kurva tidak boleh sama".to_string());
+        }
+
+        let tangents = monotone_tangents(&points);
+        let lut = (0..TONE_CURVE_SIZE)
+            .map(|i| {
+                let x = i as f32 / (TONE_CURVE_SIZE - 1) as f32;
+                evaluate(&points, &tangents, x).clamp(0.0, 1.0)
+            })
+            .collect();
+        Ok(Self { lut })
+    }
+
+    /// Membuat kurva dari tabel lookup 256 entri (indeks = input 0-255, nilai = output 0-255).
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ToneCurve)` - Kurva dari tabel
+    /// * `Err(String)` - Pesan error jika panjang tabel bukan 256
+    pub fn from_lut(lut: &[u8]) -> Result<Self, String> {
+        if lut.len() != TONE_CURVE_SIZE {
+            return Err(format!(
+                "Tabel kurva harus berisi {} entri, bukan {}",
+                TONE_CURVE_SIZE,
+                lut.len()
+            ));
+        }
+        Ok(Self {
+            lut: lut.iter().map(|&v| v as f32 / 255.0).collect(),
+        })
+    }
+
+    /// Memetakan luminance `value` (0.0 - 1.0) melalui kurva.
+    pub fn apply(&self, value: f32) -> f32 {
+        let position = value.clamp(0.0, 1.0) * (TONE_CURVE_SIZE - 1) as f32;
+        let index = (position as usize).min(TONE_CURVE_SIZE - 2);
+        let t = position - index as f32;
+        self.lut[index] + (self.lut[index + 1] - self.lut[index]) * t
+    }
+}
+
+/// Menghitung tangen Fritsch-Carlson agar spline tetap monoton per segmen.
+fn monotone_tangents(points: &[(f32, f32)]) -> Vec<f32> {
+    let slopes: Vec<f32> = points
+        .windows(2)
+        .map(|pair| (pair[1].1 - pair[0].1) / (pair[1].0 - pair[0].0))
+        .collect();
+
+    let mut tangents = Vec::with_capacity(points.len());
+    tangents.push(slopes[0]);
+    for pair in slopes.windows(2) {
+        tangents.push(if pair[0] * pair[1] <= 0.0 { 0.0 } else { (pair[0] + pair[1]) / 2.0 });
+    }
+    tangents.push(slopes[slopes.len() - 1]);
+
+    for (i, &slope) in slopes.iter().enumerate() {
+        if slope == 0.0 {
+            tangents[i] = 0.0;
+            tangents[i + 1] = 0.0;
+            continue;
+        }
+        let alpha = tangents[i] / slope;
+        let beta = tangents[i + 1] / slope;
+        let magnitude = alpha * alpha + beta * beta;
+        if magnitude > 9.0 {
+            let tau = 3.0 / magnitude.sqrt();
+            tangents[i] = tau * alpha * slope;
+            tangents[i + 1] = tau * beta * slope;
+        }
+    }
+    tangents
+}
+
+/// Mengevaluasi spline Hermite kubik pada `x`.
+fn evaluate(points: &[(f32, f32)], tangents: &[f32], x: f32) -> f32 {
+    let last = points.len() - 1;
+    if x <= points[0].0 {
+        return points[0].1;
+    }
+    if x >= points[last].0 {
+        return points[last].1;
+    }
+
+    let i = points.windows(2).position(|pair| x <= pair[1].0).unwrap_or(last - 1);
+    let ((x0, y0), (x1, y1)) = (points[i], points[i + 1]);
+    let h = x1 - x0;
+    let t = (x - x0) / h;
+    let (t2, t3) = (t * t, t * t * t);
+
+    (2.0 * t3 - 3.0 * t2 + 1.0) * y0
+        + (t3 - 2.0 * t2 + t) * h * tangents[i]
+        + (-2.0 * t3 + 3.0 * t2) * y1
+        + (t3 - t2) * h * tangents[i + 1]
+}
@@ -0,0 +1,153 @@
+//! Konversi berbantuan depth map untuk efek pseudo-3D.
+//!
+//! Depth map (misalnya dari mode potret kamera ponsel) diperkecil ke ukuran grid,
+//! lalu setiap sel dimodulasi sesuai jaraknya: objek dekat tetap padat dan terang,
+//! objek jauh menjadi renggang dan redup.
+
+use crate::{AsciiArt, AsciiConverter};
+use image::imageops::FilterType;
+use image::DynamicImage;
+use std::time::Instant;
+
+/// Aspek sel yang dimodulasi oleh depth map.
+///
+/// * `Density` - Sel jauh memakai karakter yang lebih renggang
+/// * `Color` - Warna sel jauh diredupkan
+/// * `Both` - Keduanya (default)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DepthEffect {
+    Density,
+    Color,
+    #[default]
+    Both,
+}
+
+/// Opsi konversi berbantuan depth map.
+///
+/// * `effect` - Aspek sel yang dimodulasi
+/// * `strength` - Kekuatan efek (0.0 - 1.0); 1.0 membuat sel terjauh kosong dan hitam
+/// * `invert` - Depth map memakai konvensi terang = jauh (default: terang = dekat)
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConverter, DepthEffect, DepthOptions};
+/// use image::{DynamicImage, GrayImage, Luma};
+///
+/// let photo = DynamicImage::ImageLuma8(GrayImage::from_pixel(8, 4, Luma([90])));
+/// // Sisi kiri dekat (putih), sisi kanan jauh (hitam)
+/// let depth = DynamicImage::ImageLuma8(GrayImage::from_fn(8, 4, |x, _| Luma([if x < 4 { 255 } else { 0 }])));
+///
+/// let converter = AsciiConverter::new(8, 4);
+/// let options = DepthOptions { effect: DepthEffect::Density, ..Default::default() };
+/// let art = converter.convert_image_with_depth(&photo, &depth, &options).unwrap();
+/// assert_eq!(art.to_text().lines().next(), Some("****    "));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DepthOptions {
+    pub effect: DepthEffect,
+    pub strength: f32,
+    pub invert: bool,
+}
+
+impl Default for DepthOptions {
+    /// Membuat opsi default:
+    /// * effect: DepthEffect::Both
+    /// * strength: 1.0
+    /// * invert: false
+    fn default() -> Self {
+        Self {
+            effect: DepthEffect::Both,
+            strength: 1.0,
+            invert: false,
+        }
+    }
+}
+
+impl AsciiConverter {
+    /// Mengkonversi foto beserta depth map-nya menjadi ASCII art pseudo-3D.
+    ///
+    /// Depth map mengalami rotasi dan flip yang sama dengan foto lalu diperkecil ke
+    /// ukuran grid, jadi resolusinya tidak harus sama dengan foto. `smart_crop`
+    /// diabaikan agar keduanya tetap sejajar.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_path` - Path ke foto
+    /// * `depth_path` - Path ke depth map (grayscale)
+    /// * `options` - Opsi modulasi depth
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - ASCII art sesuai format output jika berhasil
+    /// * `Err(String)` - Pesan error jika salah satu gambar gagal dimuat atau opsi tidak valid
+    pub fn convert_with_depth(&self, image_path: &str, depth_path: &str, options: &DepthOptions) -> Result<String, String> {
+        let started = Instant::now();
+        check_options(options)?;
+        let img = self.open_image(image_path)?;
+        let depth = self
+            .open_image(depth_path)
+            .map_err(|e| format!("Gagal memuat depth map: {}", e))?;
+        let art = self.art_since(&img, started, Some((&depth, options)))?;
+        Ok(self.render_art(&art))
+    }
+
+    /// Seperti `convert_with_depth`, untuk gambar yang sudah didekode; menghasilkan grid terstruktur.
+    pub fn convert_image_with_depth(
+        &self,
+        img: &DynamicImage,
+        depth: &DynamicImage,
+        options: &DepthOptions,
+    ) -> Result<AsciiArt, String> {
+        check_options(options)?;
+        self.art_since(img, Instant::now(), Some((depth, options)))
+    }
+}
+
+/// Memastikan `strength` berada di rentang 0.0 - 1.0.
+fn check_options(options: &DepthOptions) -> Result<(), String> {
+    if !(0.0..=1.0).contains(&options.strength) {
+        return Err(format!("Kekuatan depth harus di antara 0.0 dan 1.0, bukan {}", options.strength));
+    }
+    Ok(())
+}
+
+/// Memodulasi grid (sebelum orientasi output dan stilisasi) dengan depth map.
+///
+/// Hanya sel yang karakternya berasal dari ramp yang diganti, sehingga sel dari
+/// hook, background removal, atau mode quadrant tetap utuh.
+pub(crate) fn apply(converter: &AsciiConverter, art: &mut AsciiArt, depth: &DynamicImage, options: &DepthOptions) {
+    let oriented = converter.apply_orientation(depth);
+    let depth = oriented
+        .as_ref()
+        .unwrap_or(depth)
+        .resize_exact(art.width(), art.height(), FilterType::Triangle)
+        .into_luma8();
+    let glyph = match options.effect {
+        DepthEffect::Density | DepthEffect::Both => converter.glyph_mapper(),
+        DepthEffect::Color => None,
+    };
+    let dim = options.effect != DepthEffect::Density;
+    let inverted = converter.config().inverted();
+
+    for (x, y, value) in depth.enumerate_pixels() {
+        let near = value[0] as f32 / 255.0;
+        let near = if options.invert { 1.0 - near } else { near };
+        let fade = options.strength * (1.0 - near);
+        let Some(cell) = art.get_mut(x, y) else {
+            continue;
+        };
+
+        if let Some(glyph) = &glyph {
+            if glyph(cell.brightness) == cell.ch {
+                // Ujung terang ramp adalah karakter paling renggang
+                let mapped = if inverted { 1.0 - cell.brightness } else { cell.brightness };
+                let mapped = mapped + (1.0 - mapped) * fade;
+                cell.ch = glyph(if inverted { 1.0 - mapped } else { mapped });
+            }
+        }
+        if dim {
+            cell.color = cell.color.map(|c| (c as f32 * (1.0 - fade)).round() as u8);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Visualisasi perbedaan dua gambar sebagai ASCII art.
+//!
+//! Berguna untuk visual regression testing di terminal: sel yang berubah
+//! ditandai dengan karakter blok berwarna, sedangkan sel yang sama tetap
+//! ditampilkan redup sebagai konteks.
+
+use crate::converter::color_luma;
+use crate::{AsciiArt, AsciiConverter, Cell};
+use image::{DynamicImage, GenericImageView, Rgb, RgbImage};
+
+/// Karakter penanda perubahan, dari perubahan kecil hingga besar.
+const DIFF_CHARS: [char; 4] = ['░', '▒', '▓', '█'];
+
+/// Faktor peredupan warna sel konteks yang tidak berubah.
+const CONTEXT_DIM: f32 = 0.4;
+
+/// Opsi visualisasi diff.
+///
+/// * `threshold` - Selisih minimum (0.0 - 1.0) agar sel dianggap berubah
+/// * `changed_color` - Warna sel yang berubah
+/// * `show_context` - Tampilkan gambar kedua secara redup pada sel yang tidak berubah;
+///   jika `false`, sel tersebut berisi spasi
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffOptions {
+    pub threshold: f32,
+    pub changed_color: [u8; 3],
+    pub show_context: bool,
+}
+
+impl Default for DiffOptions {
+    /// Membuat opsi default:
+    /// * threshold: 0.1
+    /// * changed_color: merah (255, 64, 64)
+    /// * show_context: true
+    fn default() -> Self {
+        Self {
+            threshold: 0.1,
+            changed_color: [255, 64, 64],
+            show_context: true,
+        }
+    }
+}
+
+impl AsciiConverter {
+    /// Membuat grid yang menyorot bagian yang berbeda antara dua gambar.
+    ///
+    /// Grid berukuran `width` × `height` dari konfigurasi. Selisih dihitung per
+    /// piksel pada resolusi penuh lalu dirata-rata per sel, sehingga perubahan
+    /// kecil tetap terlihat. Pengaturan geometri (rotasi, flip, orientasi,
+    /// smart crop) tidak diterapkan agar sel tetap sejajar dengan gambar asli.
+    ///
+    /// # Arguments
+    ///
+    /// * `img_a` - Gambar acuan
+    /// * `img_b` - Gambar pembanding, harus berukuran sama dengan `img_a`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AsciiArt)` - Grid diff
+    /// * `Err(String)` - Pesan error jika ukuran kedua gambar berbeda atau kosong
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiConverter;
+    /// use image::{DynamicImage, Rgb, RgbImage};
+    ///
+    /// let before = RgbImage::new(8, 8);
+    /// let after = RgbImage::from_fn(8, 8, |x, y| if x >= 4 && y >= 4 { Rgb([255, 255, 255]) } else { Rgb([0, 0, 0]) });
+    ///
+    /// let converter = AsciiConverter::new(2, 2);
+    /// let diff = converter
+    ///     .diff(&DynamicImage::ImageRgb8(before), &DynamicImage::ImageRgb8(after))
+    ///     .unwrap();
+    /// assert_eq!(diff.get(1, 1).unwrap().ch, '█');
+    /// assert_ne!(diff.get(0, 0).unwrap().ch, '█');
+    /// ```
+    pub fn diff(&self, img_a: &DynamicImage, img_b: &DynamicImage) -> Result<AsciiArt, String> {
+        self.diff_with(img_a, img_b, &DiffOptions::default())
+    }
+
+    /// Seperti `diff`, dengan opsi visualisasi kustom.
+    pub fn diff_with(&self, img_a: &DynamicImage, img_b: &DynamicImage, options: &DiffOptions) -> Result<AsciiArt, String> {
+        if img_a.dimensions() != img_b.dimensions() {
+            return Err(format!(
+                "Ukuran gambar berbeda: {}x{} dan {}x{}",
+                img_a.width(),
+                img_a.height(),
+                img_b.width(),
+                img_b.height()
+            ));
+        }
+
+        if img_a.width() == 0 || img_a.height() == 0 {
+            return Err("Gambar kosong tidak dapat dibandingkan".to_string());
+        }
+
+        let (width, height) = (self.config().width, self.config().height);
+        let rgb_a = img_a.to_rgb8();
+        let rgb_b = img_b.to_rgb8();
+        let difference = RgbImage::from_fn(rgb_a.width(), rgb_a.height(), |x, y| {
+            let (a, b) = (rgb_a.get_pixel(x, y), rgb_b.get_pixel(x, y));
+            Rgb([0, 1, 2].map(|c| a[c].abs_diff(b[c])))
+        });
+
+        let difference = cell_means(&difference, width, height);
+        let context = cell_means(&rgb_b, width, height);
+
+        let ramp = self.character_ramp();
+        let threshold = options.threshold.clamp(0.0, 1.0);
+        let cells = difference
+            .iter()
+            .zip(context.iter())
+            .map(|(delta, source)| {
+                let magnitude = delta.iter().copied().fold(0.0, f32::max) / 255.0;
+                if magnitude > threshold {
+                    let level = (magnitude - threshold) / (1.0 - threshold).max(f32::EPSILON);
+                    let index = ((level * DIFF_CHARS.len() as f32) as usize).min(DIFF_CHARS.len() - 1);
+                    return Cell { ch: DIFF_CHARS[index], brightness: magnitude, color: options.changed_color };
+                }
+
+                let brightness = color_luma(source.map(|c| c.round() as u8));
+                if !options.show_context {
+                    return Cell { ch: ' ', brightness, color: [0, 0, 0] };
+                }
+                let mapped = if self.config().inverted() { 1.0 - brightness } else { brightness };
+                let index = ((mapped * (ramp.len() - 1) as f32) as usize).min(ramp.len() - 1);
+                let color = source.map(|c| (c * CONTEXT_DIM) as u8);
+                Cell { ch: ramp[index], brightness, color }
+            })
+            .collect();
+
+        AsciiArt::new(width, height, cells)
+    }
+}
+
+/// Rata-rata warna setiap blok piksel yang menjadi satu sel (box filter).
+fn cell_means(image: &RgbImage, columns: u32, rows: u32) -> Vec<[f32; 3]> {
+    let span = |cell: u32, cells: u32, pixels: u32| {
+        let start = (cell as u64 * pixels as u64 / cells as u64) as u32;
+        let end = ((cell as u64 + 1) * pixels as u64 / cells as u64) as u32;
+        (start.min(pixels - 1), end.max(start + 1).min(pixels))
+    };
+
+    let mut means = Vec::with_capacity((columns * rows) as usize);
+    for row in 0..rows {
+        let (y0, y1) = span(row, rows, image.height());
+        for column in 0..columns {
+            let (x0, x1) = span(column, columns, image.width());
+            let mut sum = [0.0f32; 3];
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let pixel = image.get_pixel(x, y);
+                    for channel in 0..3 {
+                        sum[channel] += pixel[channel] as f32;
+                    }
+                }
+            }
+            let count = ((x1 - x0) * (y1 - y0)) as f32;
+            means.push(sum.map(|total| total / count));
+        }
+    }
+    means
+}
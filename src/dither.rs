@@ -0,0 +1,65 @@
+//! Dithering berurutan (ordered) dengan matriks Bayer.
+//!
+//! Berbeda dengan noise acak, pola Bayer bergantung hanya pada posisi sel,
+//! sehingga frame animasi yang mirip menghasilkan pola yang stabil (tanpa
+//! "crawling") dan setiap sel dapat dihitung secara independen.
+
+/// Ukuran matriks Bayer untuk ordered dithering.
+///
+/// Matriks lebih besar memberi lebih banyak tingkat gradasi semu, dengan
+/// pola yang lebih halus tetapi kurang tajam pada grid kecil.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, BayerMatrix};
+///
+/// let config = AsciiConfig { ordered_dither: Some(BayerMatrix::Bayer4), ..Default::default() };
+/// assert_eq!(BayerMatrix::Bayer8.size(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BayerMatrix {
+    Bayer2,
+    Bayer4,
+    Bayer8,
+}
+
+impl BayerMatrix {
+    /// Panjang sisi matriks.
+    pub fn size(self) -> u32 {
+        match self {
+            BayerMatrix::Bayer2 => 2,
+            BayerMatrix::Bayer4 => 4,
+            BayerMatrix::Bayer8 => 8,
+        }
+    }
+
+    /// Threshold (0.0 - 1.0, eksklusif) untuk setiap posisi, baris demi baris.
+    ///
+    /// Dibangun secara rekursif: `M(2n) = [[4M, 4M+2], [4M+3, 4M+1]]`.
+    pub(crate) fn thresholds(self) -> Vec<f32> {
+        let mut matrix = vec![0u32];
+        let mut size = 1;
+        while size < self.size() as usize {
+            let next_size = size * 2;
+            let mut next = vec![0u32; next_size * next_size];
+            for y in 0..size {
+                for x in 0..size {
+                    let value = matrix[y * size + x] * 4;
+                    next[y * next_size + x] = value;
+                    next[y * next_size + x + size] = value + 2;
+                    next[(y + size) * next_size + x] = value + 3;
+                    next[(y + size) * next_size + x + size] = value + 1;
+                }
+            }
+            matrix = next;
+            size = next_size;
+        }
+
+        let levels = (size * size) as f32;
+        matrix
+            .into_iter()
+            .map(|value| (value as f32 + 0.5) / levels)
+            .collect()
+    }
+}
@@ -0,0 +1,109 @@
+//! Output mosaik emoji untuk platform chat yang membuang ANSI dan HTML.
+
+use crate::AsciiArt;
+
+/// Palet emoji bawaan: kotak berwarna beserta perkiraan warna tampilannya.
+const BUILTIN_EMOJI: [(&str, [u8; 3]); 9] = [
+    ("🟥", [221, 46, 68]),
+    ("🟧", [244, 144, 12]),
+    ("🟨", [253, 203, 88]),
+    ("🟩", [120, 177, 89]),
+    ("🟦", [85, 172, 238]),
+    ("🟪", [170, 142, 214]),
+    ("🟫", [193, 105, 79]),
+    ("⬛", [41, 47, 51]),
+    ("⬜", [230, 231, 232]),
+];
+
+/// Pemetaan emoji ke warna yang diwakilinya.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::EmojiPalette;
+///
+/// let hearts = EmojiPalette::new(vec![
+///     ("❤️".to_string(), [221, 46, 68]),
+///     ("💛".to_string(), [253, 203, 88]),
+///     ("🖤".to_string(), [49, 55, 61]),
+/// ]);
+/// assert_eq!(hearts.nearest([250, 200, 90]), Some("💛"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmojiPalette {
+    entries: Vec<(String, [u8; 3])>,
+}
+
+impl EmojiPalette {
+    /// Membuat palet dari pasangan emoji dan warnanya.
+    pub fn new(entries: Vec<(String, [u8; 3])>) -> Self {
+        Self { entries }
+    }
+
+    /// Palet bawaan berisi sembilan kotak berwarna (`🟥🟧🟨🟩🟦🟪🟫⬛⬜`).
+    pub fn builtin() -> Self {
+        Self::new(
+            BUILTIN_EMOJI
+                .iter()
+                .map(|&(emoji, color)| (emoji.to_string(), color))
+                .collect(),
+        )
+    }
+
+    /// Emoji dengan warna terdekat (jarak RGB berbobot persepsi), atau `None` jika palet kosong.
+    pub fn nearest(&self, color: [u8; 3]) -> Option<&str> {
+        self.entries
+            .iter()
+            .min_by_key(|(_, candidate)| weighted_distance(color, *candidate))
+            .map(|(emoji, _)| emoji.as_str())
+    }
+}
+
+impl Default for EmojiPalette {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl AsciiArt {
+    /// Me-render grid sebagai mosaik emoji dengan palet bawaan.
+    ///
+    /// Emoji umumnya selebar dua kolom dan berbentuk persegi, jadi gunakan
+    /// `height` sesuai rasio aspek gambar (tidak dibagi dua seperti teks).
+    /// Warna diambil dari warna sel; `color_saturation: 1.0` memberi hasil
+    /// paling mirip dengan sumber.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell};
+    ///
+    /// let red = Cell { ch: '#', brightness: 0.3, color: [230, 40, 50] };
+    /// let white = Cell { ch: ' ', brightness: 1.0, color: [250, 250, 250] };
+    /// let art = AsciiArt::new(2, 1, vec![red, white]).unwrap();
+    /// assert_eq!(art.to_emoji(), "🟥⬜\n");
+    /// ```
+    pub fn to_emoji(&self) -> String {
+        self.to_emoji_with(&EmojiPalette::builtin())
+    }
+
+    /// Me-render grid sebagai mosaik emoji dengan palet kustom.
+    ///
+    /// Jika palet kosong, setiap sel di-render sebagai spasi.
+    pub fn to_emoji_with(&self, palette: &EmojiPalette) -> String {
+        let mut output = String::new();
+        for row in self.rows() {
+            for cell in row {
+                output.push_str(palette.nearest(cell.color).unwrap_or(" "));
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Jarak kuadrat RGB dengan bobot "redmean" sederhana (2, 4, 3).
+pub(crate) fn weighted_distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    let delta = |i: usize| (a[i] as i32 - b[i] as i32).unsigned_abs();
+    2 * delta(0).pow(2) + 4 * delta(1).pow(2) + 3 * delta(2).pow(2)
+}
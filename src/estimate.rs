@@ -0,0 +1,136 @@
+//! Estimasi ukuran output dan kebutuhan memori sebelum konversi.
+
+use crate::art::HTML_HEAD;
+use crate::html_table::html_table_document_bytes;
+use crate::{AsciiConfig, AsciiConverter, Cell, Orientation, OutputFormat};
+
+/// Panjang maksimum escape warna ANSI per sel: `ESC[38;2;255;255;255m`.
+const ANSI_COLOR_BYTES: u64 = 19;
+
+/// Panjang reset ANSI di akhir baris ditambah newline: `ESC[0m\n`.
+const ANSI_LINE_END_BYTES: u64 = 5;
+
+/// Panjang maksimum span HTML per sel tanpa karakternya.
+const HTML_SPAN_BYTES: u64 = "<span style=\"color:rgb(255,255,255)\"></span>".len() as u64;
+
+/// Panjang penutup baris dan dokumen HTML.
+const HTML_LINE_END_BYTES: u64 = "<br/>\n".len() as u64;
+const HTML_TAIL_BYTES: u64 = "<pre>\n</pre>\n</body>\n</html>".len() as u64;
+
+/// Panjang maksimum satu aturan class palet HTML.
+const HTML_CLASS_RULE_BYTES: u64 = ".c65535 { color: #ffffff; }\n".len() as u64;
+
+/// Perkiraan ukuran output dan kebutuhan memori untuk sebuah konfigurasi.
+///
+/// Ukuran bytes adalah batas atas (warna terpanjang, glyph terlebar pada ramp).
+///
+/// * `columns` - Jumlah kolom karakter
+/// * `rows` - Jumlah baris
+/// * `plain_bytes` - Ukuran output teks biasa
+/// * `ansi_bytes` - Ukuran output ANSI berwarna
+/// * `ansi_background_bytes` - Ukuran output ANSI background ("photo mode")
+/// * `html_bytes` - Ukuran output HTML; dengan `OutputFormat::HtmlTable`, ukuran dokumen tabel
+/// * `internal_pixels` - Jumlah piksel gambar internal setelah resize
+/// * `working_memory_bytes` - Perkiraan memori kerja pipeline, tidak termasuk gambar sumber
+///   yang didekode (lebar × tinggi × 4 bytes untuk RGBA)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutputEstimate {
+    pub columns: u32,
+    pub rows: u32,
+    pub plain_bytes: u64,
+    pub ansi_bytes: u64,
+    pub ansi_background_bytes: u64,
+    pub html_bytes: u64,
+    pub internal_pixels: u64,
+    pub working_memory_bytes: u64,
+}
+
+impl AsciiConverter {
+    /// Memperkirakan dimensi, ukuran output per format, dan kebutuhan memori tanpa melakukan konversi.
+    ///
+    /// Berguna bagi layanan untuk menolak permintaan yang terlalu besar sejak awal.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Konfigurasi yang akan diperkirakan
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, AsciiConfig};
+    ///
+    /// let estimate = AsciiConverter::estimate_output(&AsciiConfig::default());
+    /// assert_eq!((estimate.columns, estimate.rows), (100, 50));
+    /// assert_eq!(estimate.plain_bytes, 50 * 101);
+    /// if estimate.html_bytes > 10_000_000 {
+    ///     eprintln!("Permintaan terlalu besar");
+    /// }
+    ///
+    /// // Ukuran tabel HTML dihitung dari markup per sel, bukan `<span>`
+    /// use aspix::{AsciiArt, Cell, OutputFormat};
+    ///
+    /// let config = AsciiConfig { width: 4, height: 2, use_color: true, output_format: OutputFormat::HtmlTable, ..Default::default() };
+    /// let table = AsciiConverter::estimate_output(&config);
+    /// let art = AsciiArt::new(4, 2, vec![Cell { ch: '@', brightness: 0.0, color: [255, 255, 255] }; 8]).unwrap();
+    /// assert!(table.html_bytes >= art.to_html_table_document(&config.html_table).len() as u64);
+    /// ```
+    pub fn estimate_output(config: &AsciiConfig) -> OutputEstimate {
+        let converter = AsciiConverter::with_config(config.clone());
+        // Konfigurasi converter sudah memperhitungkan batas ukuran mode retro
+        let config = converter.config();
+        // Grid yang diputar menukar jumlah kolom dan baris; kanvas menentukan ukuran akhir
+        let (output_columns, output_rows) = match (config.canvas, config.orientation) {
+            (Some(canvas), _) => (canvas.width, canvas.height),
+            (None, Orientation::Normal) => (config.width, config.height),
+            (None, Orientation::Rotate90 | Orientation::Rotate270) => (config.height, config.width),
+        };
+        let columns = output_columns as u64;
+        let rows = output_rows as u64;
+        let cells = columns * rows;
+
+        let glyph_bytes = converter
+            .character_ramp()
+            .iter()
+            .map(|ch| ch.len_utf8())
+            .max()
+            .unwrap_or(1)
+            .max(if config.use_quadrants || config.mixed_charset.is_some() || config.line_art.is_some() { 3 } else { 1 })
+            .max(config.canvas.map_or(1, |canvas| canvas.fill_char.len_utf8())) as u64;
+        // Karakter khusus HTML dapat menjadi entity hingga 6 bytes (`&quot;`)
+        let html_glyph_bytes = glyph_bytes.max(6);
+
+        let (target_width, target_height) = converter.target_dimensions();
+        let internal_pixels = target_width as u64 * target_height as u64;
+
+        let plain_bytes = rows * (columns * glyph_bytes + config.line_ending.as_str().len() as u64);
+        let ansi_bytes = rows * (columns * (ANSI_COLOR_BYTES + glyph_bytes) + ANSI_LINE_END_BYTES);
+        let ansi_background_bytes = rows * (columns * (ANSI_COLOR_BYTES + 1) + ANSI_LINE_END_BYTES);
+        let html_bytes = match config.output_format {
+            OutputFormat::HtmlTable => html_table_document_bytes(columns, rows, html_glyph_bytes, &config.html_table),
+            _ => {
+                HTML_HEAD.len() as u64
+                    + rows * (columns * (HTML_SPAN_BYTES + html_glyph_bytes) + HTML_LINE_END_BYTES)
+                    + HTML_TAIL_BYTES
+                    + config.html_palette_size.map_or(0, |size| size as u64 * HTML_CLASS_RULE_BYTES)
+            }
+        };
+
+        // Buffer hasil resize, hasil penyesuaian (RGBA), dan grayscale, ditambah grid sel
+        // serta output terbesar yang mungkin dibuat
+        let largest_output = if config.use_color { html_bytes.max(ansi_bytes) } else { plain_bytes };
+        let working_memory_bytes = internal_pixels * (4 + 4 + 1)
+            + cells * std::mem::size_of::<Cell>() as u64
+            + largest_output;
+
+        OutputEstimate {
+            columns: output_columns,
+            rows: output_rows,
+            plain_bytes,
+            ansi_bytes,
+            ansi_background_bytes,
+            html_bytes,
+            internal_pixels,
+            working_memory_bytes,
+        }
+    }
+}
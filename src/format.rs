@@ -0,0 +1,258 @@
+//! Deteksi format gambar dari magic bytes dan diagnosis format yang tidak didukung.
+
+use crate::AsciiConverter;
+use std::fmt;
+
+/// Jumlah bytes awal yang diperiksa untuk mendeteksi SVG.
+const SVG_SNIFF_LEN: usize = 4096;
+
+/// Jumlah bytes awal yang dibutuhkan untuk mengenali box `ftyp` HEIF/AVIF.
+pub(crate) const FTYP_HEADER_LEN: usize = 12;
+
+/// Brand `ftyp` HEIF (HEIC) dan AVIF.
+const HEIF_BRANDS: [&[u8; 4]; 8] = [b"heic", b"heix", b"hevc", b"hevx", b"heim", b"heis", b"mif1", b"msf1"];
+const AVIF_BRANDS: [&[u8; 4]; 2] = [b"avif", b"avis"];
+
+/// Mendeteksi HEIF atau AVIF dari box `ftyp` di awal data.
+fn sniff_ftyp(header: &[u8]) -> Option<DetectedFormat> {
+    if header.len() < FTYP_HEADER_LEN || &header[4..8] != b"ftyp" {
+        return None;
+    }
+    let brand = &header[8..12];
+    if AVIF_BRANDS.iter().any(|candidate| brand == candidate.as_slice()) {
+        Some(DetectedFormat::Avif)
+    } else if HEIF_BRANDS.iter().any(|candidate| brand == candidate.as_slice()) {
+        Some(DetectedFormat::Heif)
+    } else {
+        None
+    }
+}
+
+/// Mendeteksi dokumen SVG dari isinya (teks XML dengan elemen `<svg`).
+fn looks_like_svg(bytes: &[u8]) -> bool {
+    let head = &bytes[..bytes.len().min(SVG_SNIFF_LEN)];
+    let start = head.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(head.len());
+    head[start..].starts_with(b"<") && head.windows(4).any(|window| window == b"<svg")
+}
+
+/// Format gambar yang dikenali dari magic bytes.
+///
+/// Selain format yang didekode crate `image`, deteksi juga mengenali format yang
+/// membutuhkan feature (`Heif`, `Avif`, `Svg`), PSD (gambar gabungannya didekode
+/// oleh dekoder layer, lihat `list_layers`), dan format yang tidak didukung sama
+/// sekali (`JpegXl`, `Pdf`) agar pesan error bisa spesifik.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConverter, DetectedFormat};
+///
+/// assert_eq!(DetectedFormat::sniff(b"\x89PNG\r\n\x1a\n...."), Some(DetectedFormat::Png));
+/// assert_eq!(DetectedFormat::sniff(b"\0\0\0\x1cftypavif"), Some(DetectedFormat::Avif));
+/// assert_eq!(DetectedFormat::Avif.required_feature(), Some("heif"));
+/// assert_eq!(DetectedFormat::sniff(b"halo"), None);
+///
+/// // PSD grayscale 2x1 tanpa layer: dikonversi dari gambar gabungannya
+/// let mut psd = b"8BPS\0\x01\0\0\0\0\0\0\0\x01\0\0\0\x01\0\0\0\x02\0\x08\0\x01".to_vec();
+/// psd.extend_from_slice(&[0; 12]); // color mode data, image resources, layer & mask kosong
+/// psd.extend_from_slice(&[0, 0, 0, 255]); // image data raw: hitam, putih
+/// assert!(DetectedFormat::Psd.is_supported());
+/// assert_eq!(AsciiConverter::new(2, 1).convert_from_bytes(&psd).unwrap(), "@ \n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DetectedFormat {
+    Png,
+    Jpeg,
+    Gif,
+    WebP,
+    Bmp,
+    Tiff,
+    Ico,
+    Qoi,
+    Hdr,
+    OpenExr,
+    Pnm,
+    Farbfeld,
+    Dds,
+    Heif,
+    Avif,
+    Svg,
+    JpegXl,
+    Psd,
+    Pdf,
+}
+
+impl DetectedFormat {
+    /// Mendeteksi format dari bytes awal data, atau `None` jika tidak dikenali.
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        // Box `ftyp` diperiksa lebih dulu karena ukurannya dapat menyerupai magic ICO
+        if let Some(format) = sniff_ftyp(bytes) {
+            return Some(format);
+        }
+
+        let magic: [(&[u8], DetectedFormat); 18] = [
+            (b"\x89PNG\r\n\x1a\n", DetectedFormat::Png),
+            (b"\xff\xd8\xff", DetectedFormat::Jpeg),
+            (b"GIF87a", DetectedFormat::Gif),
+            (b"GIF89a", DetectedFormat::Gif),
+            (b"BM", DetectedFormat::Bmp),
+            (b"II*\0", DetectedFormat::Tiff),
+            (b"MM\0*", DetectedFormat::Tiff),
+            (b"\0\0\x01\0", DetectedFormat::Ico),
+            (b"qoif", DetectedFormat::Qoi),
+            (b"#?RADIANCE", DetectedFormat::Hdr),
+            (b"#?RGBE", DetectedFormat::Hdr),
+            (b"v/1\x01", DetectedFormat::OpenExr),
+            (b"farbfeld", DetectedFormat::Farbfeld),
+            (b"DDS ", DetectedFormat::Dds),
+            (b"\xff\x0a", DetectedFormat::JpegXl),
+            (b"\0\0\0\x0cJXL \r\n\x87\n", DetectedFormat::JpegXl),
+            (b"8BPS", DetectedFormat::Psd),
+            (b"%PDF", DetectedFormat::Pdf),
+        ];
+        if let Some(&(_, format)) = magic.iter().find(|(prefix, _)| bytes.starts_with(prefix)) {
+            return Some(format);
+        }
+
+        if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+            return Some(DetectedFormat::WebP);
+        }
+        if bytes.len() >= 2 && bytes[0] == b'P' && (b'1'..=b'7').contains(&bytes[1]) {
+            return Some(DetectedFormat::Pnm);
+        }
+
+        looks_like_svg(bytes).then_some(DetectedFormat::Svg)
+    }
+
+    /// Nama format yang mudah dibaca.
+    pub fn name(&self) -> &'static str {
+        match self {
+            DetectedFormat::Png => "PNG",
+            DetectedFormat::Jpeg => "JPEG",
+            DetectedFormat::Gif => "GIF",
+            DetectedFormat::WebP => "WebP",
+            DetectedFormat::Bmp => "BMP",
+            DetectedFormat::Tiff => "TIFF",
+            DetectedFormat::Ico => "ICO",
+            DetectedFormat::Qoi => "QOI",
+            DetectedFormat::Hdr => "Radiance HDR",
+            DetectedFormat::OpenExr => "OpenEXR",
+            DetectedFormat::Pnm => "PNM",
+            DetectedFormat::Farbfeld => "Farbfeld",
+            DetectedFormat::Dds => "DDS",
+            DetectedFormat::Heif => "HEIF/HEIC",
+            DetectedFormat::Avif => "AVIF",
+            DetectedFormat::Svg => "SVG",
+            DetectedFormat::JpegXl => "JPEG XL",
+            DetectedFormat::Psd => "PSD",
+            DetectedFormat::Pdf => "PDF",
+        }
+    }
+
+    /// Feature cargo aspix yang menyediakan dekoder format ini, jika ada.
+    pub fn required_feature(&self) -> Option<&'static str> {
+        match self {
+            DetectedFormat::Heif | DetectedFormat::Avif => Some("heif"),
+            DetectedFormat::Svg => Some("svg"),
+            _ => None,
+        }
+    }
+
+    /// Apakah format ini dapat didekode oleh build saat ini.
+    pub fn is_supported(&self) -> bool {
+        match self.required_feature() {
+            Some(feature) => enabled_features().contains(&feature),
+            None => !matches!(self, DetectedFormat::JpegXl | DetectedFormat::Pdf),
+        }
+    }
+}
+
+impl fmt::Display for DetectedFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Data gambar dengan format yang tidak dapat didekode oleh build saat ini.
+///
+/// Converter mengembalikan pesan error dari `message`; gunakan
+/// `AsciiConverter::check_format` untuk memperoleh nilai bertipe ini
+/// (misalnya untuk membalas HTTP 415).
+///
+/// * `detected` - Format yang terdeteksi, atau `None` jika magic bytes tidak dikenali
+/// * `enabled_features` - Feature dekoder aspix yang aktif pada build ini
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedFormat {
+    pub detected: Option<DetectedFormat>,
+    pub enabled_features: Vec<&'static str>,
+}
+
+impl UnsupportedFormat {
+    /// Pesan yang menjelaskan format terdeteksi dan feature yang perlu diaktifkan.
+    pub fn message(&self) -> String {
+        let enabled = if self.enabled_features.is_empty() {
+            "tidak ada".to_string()
+        } else {
+            self.enabled_features.join(", ")
+        };
+        match self.detected {
+            Some(format) => match format.required_feature() {
+                Some(feature) => format!(
+                    "format {} terdeteksi, tetapi feature `{}` tidak aktif (feature dekoder aktif: {}); \
+                     aktifkan dengan `aspix = {{ version = \"*\", features = [\"{}\"] }}` di Cargo.toml",
+                    format, feature, enabled, feature
+                ),
+                None => format!("format {} terdeteksi, tetapi tidak dapat didekode oleh aspix", format),
+            },
+            None => format!("format gambar tidak dikenali dari magic bytes (feature dekoder aktif: {})", enabled),
+        }
+    }
+}
+
+impl From<UnsupportedFormat> for String {
+    fn from(error: UnsupportedFormat) -> Self {
+        format!("Format gambar tidak didukung: {}", error.message())
+    }
+}
+
+/// Feature aspix yang menambah dekoder input dan aktif pada build ini.
+fn enabled_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "heif") {
+        features.push("heif");
+    }
+    if cfg!(feature = "svg") {
+        features.push("svg");
+    }
+    features
+}
+
+impl AsciiConverter {
+    /// Mendeteksi format data gambar dan memastikan build ini dapat mendekodenya.
+    ///
+    /// `convert_from_bytes` sudah menjalankan pemeriksaan yang sama; fungsi ini
+    /// berguna ketika pemanggil membutuhkan error bertipe.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, DetectedFormat};
+    ///
+    /// let converter = AsciiConverter::new(80, 40);
+    /// let error = converter.check_format(b"%PDF-1.7 ...").unwrap_err();
+    /// assert_eq!(error.detected, Some(DetectedFormat::Pdf));
+    ///
+    /// // Tanpa feature `heif`, pesan error menyebut format dan feature yang perlu diaktifkan
+    /// let heic = b"\0\0\0\x1cftypheic";
+    /// if converter.check_format(heic).is_err() {
+    ///     let message = converter.convert_from_bytes(heic).unwrap_err();
+    ///     assert!(message.contains("HEIF/HEIC") && message.contains("features = [\"heif\"]"));
+    /// }
+    /// ```
+    pub fn check_format(&self, bytes: &[u8]) -> Result<DetectedFormat, UnsupportedFormat> {
+        match DetectedFormat::sniff(bytes) {
+            Some(format) if format.is_supported() => Ok(format),
+            detected => Err(UnsupportedFormat { detected, enabled_features: enabled_features() }),
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Ekspor animasi menjadi file per frame beserta manifest JSON.
+//!
+//! Manifest mencatat urutan file, durasi setiap frame, dimensi grid, dan sidik
+//! jari konfigurasi (`AsciiConfig::fingerprint`) sehingga player atau pipeline
+//! lain dapat memutar urutan frame tanpa menebak dari nama file.
+
+use crate::{AnimationConverter, AsciiConfig, FrameSource, OutputFormat};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Nama file manifest di dalam direktori ekspor.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Opsi penamaan file frame.
+///
+/// File diberi nama `{prefix}_{nomor}.{ekstensi}` dengan nomor dimulai dari 1 dan
+/// diisi nol hingga `min_digits` digit. Ekstensi mengikuti output: `html` untuk
+/// HTML, `ans` untuk ANSI, dan `txt` untuk teks biasa atau emoji.
+///
+/// * `prefix` - Awalan nama file
+/// * `min_digits` - Jumlah digit minimum nomor frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameExportOptions {
+    pub prefix: String,
+    pub min_digits: usize,
+}
+
+impl Default for FrameExportOptions {
+    /// Nama `frame_0001.txt`, `frame_0002.txt`, dan seterusnya.
+    fn default() -> Self {
+        Self {
+            prefix: "frame".to_string(),
+            min_digits: 4,
+        }
+    }
+}
+
+/// Satu frame pada manifest.
+///
+/// * `file` - Nama file frame, relatif terhadap direktori ekspor
+/// * `delay` - Durasi tampil frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestFrame {
+    pub file: String,
+    pub delay: Duration,
+}
+
+/// Manifest hasil `AnimationConverter::export_frames`.
+///
+/// * `columns` - Lebar grid setiap frame dalam karakter
+/// * `rows` - Tinggi grid setiap frame dalam baris
+/// * `config_fingerprint` - Sidik jari konfigurasi yang dipakai (`AsciiConfig::fingerprint`)
+/// * `frames` - Daftar frame berurutan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameManifest {
+    pub columns: u32,
+    pub rows: u32,
+    pub config_fingerprint: u64,
+    pub frames: Vec<ManifestFrame>,
+}
+
+impl FrameManifest {
+    /// Jumlah frame.
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Total durasi seluruh frame.
+    pub fn total_duration(&self) -> Duration {
+        self.frames.iter().map(|frame| frame.delay).sum()
+    }
+
+    /// Menyusun manifest sebagai JSON.
+    ///
+    /// Durasi ditulis dalam milidetik, dan sidik jari sebagai string heksadesimal
+    /// 16 digit agar tidak kehilangan presisi di parser JSON berbasis `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{FrameManifest, ManifestFrame};
+    /// use std::time::Duration;
+    ///
+    /// let manifest = FrameManifest {
+    ///     columns: 8,
+    ///     rows: 4,
+    ///     config_fingerprint: 0xabc,
+    ///     frames: vec![ManifestFrame { file: "frame_0001.txt".to_string(), delay: Duration::from_millis(40) }],
+    /// };
+    /// assert_eq!(
+    ///     manifest.to_json(),
+    ///     "{\n  \"frame_count\": 1,\n  \"columns\": 8,\n  \"rows\": 4,\n  \"total_duration_ms\": 40,\n  \
+    ///      \"config_fingerprint\": \"0000000000000abc\",\n  \"frames\": [\n    \
+    ///      {\"file\": \"frame_0001.txt\", \"delay_ms\": 40}\n  ]\n}\n"
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let frames: Vec<String> = self
+            .frames
+            .iter()
+            .map(|frame| {
+                format!("    {{\"file\": {}, \"delay_ms\": {}}}", json_string(&frame.file), frame.delay.as_millis())
+            })
+            .collect();
+        format!(
+            "{{\n  \"frame_count\": {},\n  \"columns\": {},\n  \"rows\": {},\n  \"total_duration_ms\": {},\n  \
+             \"config_fingerprint\": \"{:016x}\",\n  \"frames\": [\n{}\n  ]\n}}\n",
+            self.frame_count(),
+            self.columns,
+            self.rows,
+            self.total_duration().as_millis(),
+            self.config_fingerprint,
+            frames.join(",\n")
+        )
+    }
+}
+
+impl AnimationConverter {
+    /// Mengkonversi semua frame dari sumber dan menulisnya sebagai file bernomor beserta manifest.
+    ///
+    /// Frame ditulis satu per satu saat dikonversi sehingga animasi panjang tidak
+    /// perlu ditampung di memory. Seperti `convert_all`, waktu animasi dimulai dari
+    /// awal (`rewind`). Direktori dibuat jika belum ada; file dengan nama
+    /// sama ditimpa. Jika jumlah frame melebihi `min_digits` digit, nama file tidak
+    /// lagi terurut secara leksikografis, jadi gunakan urutan pada manifest.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Sumber frame
+    /// * `dir` - Direktori tujuan
+    /// * `options` - Opsi penamaan file
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FrameManifest)` - Manifest yang juga ditulis ke `MANIFEST_FILE`
+    /// * `Err(String)` - Pesan error jika konversi atau penulisan file gagal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AnimationConverter, AsciiConfig, FrameExportOptions, ImageSequence, MANIFEST_FILE};
+    /// use image::DynamicImage;
+    /// use std::time::Duration;
+    ///
+    /// let dir = std::env::temp_dir().join("aspix-frame-export-doc");
+    /// let converter = AnimationConverter::new(AsciiConfig { width: 8, height: 4, ..Default::default() });
+    /// let mut source = ImageSequence::new(vec![DynamicImage::new_rgb8(16, 8); 3], Duration::from_millis(100));
+    ///
+    /// let manifest = converter.export_frames(&mut source, &dir, &FrameExportOptions::default()).unwrap();
+    /// assert_eq!(manifest.frame_count(), 3);
+    /// assert_eq!(manifest.frames[2].file, "frame_0003.txt");
+    /// assert_eq!(manifest.total_duration(), Duration::from_millis(300));
+    /// assert_eq!(std::fs::read_to_string(dir.join("frame_0001.txt")).unwrap().lines().count(), 4);
+    /// assert_eq!(std::fs::read_to_string(dir.join(MANIFEST_FILE)).unwrap(), manifest.to_json());
+    /// ```
+    pub fn export_frames<S: FrameSource + ?Sized>(
+        &self,
+        source: &mut S,
+        dir: impl AsRef<Path>,
+        options: &FrameExportOptions,
+    ) -> Result<FrameManifest, String> {
+        self.rewind();
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(|e| format!("Gagal membuat direktori ekspor: {}", e))?;
+        let extension = frame_extension(self.config());
+
+        let mut manifest = FrameManifest {
+            columns: 0,
+            rows: 0,
+            config_fingerprint: self.config().fingerprint(),
+            frames: Vec::new(),
+        };
+        while let Some(frame) = self.convert_next(source) {
+            let frame = frame?;
+            let file = format!(
+                "{}_{:0width$}.{}",
+                options.prefix,
+                manifest.frames.len() + 1,
+                extension,
+                width = options.min_digits
+            );
+            fs::write(dir.join(&file), &frame.content).map_err(|e| format!("Gagal menulis frame {}: {}", file, e))?;
+            manifest.columns = frame.art.width();
+            manifest.rows = frame.art.height();
+            manifest.frames.push(ManifestFrame { file, delay: frame.delay });
+        }
+
+        fs::write(dir.join(MANIFEST_FILE), manifest.to_json()).map_err(|e| format!("Gagal menulis manifest: {}", e))?;
+        Ok(manifest)
+    }
+}
+
+/// Ekstensi file frame sesuai format output konfigurasi.
+fn frame_extension(config: &AsciiConfig) -> &'static str {
+    if !config.use_color {
+        return "txt";
+    }
+    match config.output_format {
+        OutputFormat::Html | OutputFormat::HtmlTable => "html",
+        OutputFormat::Ansi | OutputFormat::AnsiBackground => "ans",
+        OutputFormat::Emoji => "txt",
+    }
+}
+
+/// Menulis string sebagai literal string JSON.
+fn json_string(text: &str) -> String {
+    let mut output = String::with_capacity(text.len() + 2);
+    output.push('"');
+    for ch in text.chars() {
+        match ch {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            ch if (ch as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => output.push(ch),
+        }
+    }
+    output.push('"');
+    output
+}
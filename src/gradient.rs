@@ -0,0 +1,115 @@
+//! Stilisasi warna gradien di seluruh grid.
+//!
+//! Gradien mengabaikan warna sumber dan mewarnai sel berdasarkan posisinya,
+//! gaya yang populer untuk signature ANSI dan banner MOTD.
+
+use crate::AsciiArt;
+
+/// Arah gradien pada grid.
+///
+/// * `Vertical` - Atas ke bawah (warna sama dalam satu baris)
+/// * `Horizontal` - Kiri ke kanan (warna sama dalam satu kolom)
+/// * `Diagonal` - Pojok kiri atas ke pojok kanan bawah
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GradientDirection {
+    #[default]
+    Vertical,
+    Horizontal,
+    Diagonal,
+}
+
+/// Gradien warna dengan beberapa titik henti (stop).
+///
+/// * `direction` - Arah gradien
+/// * `stops` - Pasangan posisi (0.0 - 1.0) dan warna; posisi tidak harus urut
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, ColorGradient, GradientDirection};
+///
+/// let sunset = ColorGradient::even(GradientDirection::Vertical, &[[255, 94, 77], [255, 195, 0], [88, 24, 69]]);
+/// assert_eq!(sunset.color_at(0.5), [255, 195, 0]);
+///
+/// let config = AsciiConfig { use_color: true, gradient: Some(sunset), ..Default::default() };
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorGradient {
+    pub direction: GradientDirection,
+    pub stops: Vec<(f32, [u8; 3])>,
+}
+
+impl ColorGradient {
+    /// Membuat gradien dengan stop yang tersebar rata dari 0.0 sampai 1.0.
+    pub fn even(direction: GradientDirection, colors: &[[u8; 3]]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self {
+            direction,
+            stops: colors
+                .iter()
+                .enumerate()
+                .map(|(i, &color)| (i as f32 / last, color))
+                .collect(),
+        }
+    }
+
+    /// Warna gradien pada posisi `t` (0.0 - 1.0), diinterpolasi linear antar stop.
+    ///
+    /// Gradien tanpa stop menghasilkan putih.
+    pub fn color_at(&self, t: f32) -> [u8; 3] {
+        let mut stops = self.stops.clone();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let t = t.clamp(0.0, 1.0);
+        let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+            return [255, 255, 255];
+        };
+        if t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+
+        let segment = stops
+            .windows(2)
+            .find(|pair| t <= pair[1].0)
+            .expect("t berada di antara stop pertama dan terakhir");
+        let ((start, from), (end, to)) = (segment[0], segment[1]);
+        let local = if end > start { (t - start) / (end - start) } else { 1.0 };
+        [0, 1, 2].map(|i| (from[i] as f32 + (to[i] as f32 - from[i] as f32) * local).round() as u8)
+    }
+}
+
+impl AsciiArt {
+    /// Mewarnai semua sel dengan gradien, menggantikan warna sebelumnya.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, ColorGradient, GradientDirection};
+    ///
+    /// let cell = Cell { ch: '#', brightness: 0.5, color: [0, 0, 0] };
+    /// let mut art = AsciiArt::new(3, 3, vec![cell; 9]).unwrap();
+    /// art.apply_gradient(&ColorGradient::even(GradientDirection::Vertical, &[[255, 0, 0], [0, 0, 255]]));
+    /// assert_eq!(art.get(2, 0).unwrap().color, [255, 0, 0]);
+    /// assert_eq!(art.get(0, 2).unwrap().color, [0, 0, 255]);
+    /// ```
+    pub fn apply_gradient(&mut self, gradient: &ColorGradient) {
+        let (width, height) = (self.width(), self.height());
+        let span = |value: u32, size: u32| if size > 1 { value as f32 / (size - 1) as f32 } else { 0.0 };
+
+        for y in 0..height {
+            for x in 0..width {
+                let t = match gradient.direction {
+                    GradientDirection::Vertical => span(y, height),
+                    GradientDirection::Horizontal => span(x, width),
+                    GradientDirection::Diagonal => (span(x, width) + span(y, height)) / 2.0,
+                };
+                if let Some(cell) = self.get_mut(x, y) {
+                    cell.color = gradient.color_at(t);
+                }
+            }
+        }
+    }
+}
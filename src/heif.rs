@@ -0,0 +1,49 @@
+//! Dekoder HEIC/HEIF dan AVIF (feature `heif`).
+//!
+//! Foto ponsel modern umumnya disimpan sebagai HEIC atau AVIF yang tidak
+//! didukung crate `image`. Keduanya memakai container ISO-BMFF yang sama,
+//! sehingga didekode melalui libheif (membutuhkan `libheif` >= 1.18 terpasang
+//! di sistem, dengan plugin dekoder HEVC dan AV1).
+
+use crate::{ConversionError, ResourceLimits};
+use image::{DynamicImage, RgbImage, RgbaImage};
+use libheif_rs::{ColorSpace, HeifContext, LibHeif, RgbChroma};
+
+/// Mendekode gambar utama dari file HEIF/AVIF di memory.
+///
+/// Dimensi gambar utama diperiksa terhadap `limits` sebelum data piksel didekode.
+pub(crate) fn decode(bytes: &[u8], limits: &ResourceLimits) -> Result<DynamicImage, ConversionError> {
+    let context = HeifContext::read_from_bytes(bytes)
+        .map_err(|e| format!("Gagal membaca HEIF/AVIF: {}", e))?;
+    let handle = context
+        .primary_image_handle()
+        .map_err(|e| format!("Gagal membaca HEIF/AVIF: {}", e))?;
+    limits.check_dimensions(handle.width(), handle.height())?;
+
+    let has_alpha = handle.has_alpha_channel();
+    let chroma = if has_alpha { RgbChroma::Rgba } else { RgbChroma::Rgb };
+    let decoded = LibHeif::new()
+        .decode(&handle, ColorSpace::Rgb(chroma), None)
+        .map_err(|e| format!("Gagal mendekode HEIF/AVIF: {}", e))?;
+
+    let planes = decoded.planes();
+    let plane = planes
+        .interleaved
+        .ok_or_else(|| "Gagal mendekode HEIF/AVIF: data piksel tidak tersedia".to_string())?;
+
+    // Salin baris demi baris karena stride dapat lebih besar dari lebar gambar
+    let channels = if has_alpha { 4 } else { 3 };
+    let row_len = plane.width as usize * channels;
+    let mut pixels = Vec::with_capacity(row_len * plane.height as usize);
+    for row in plane.data.chunks(plane.stride).take(plane.height as usize) {
+        pixels.extend_from_slice(&row[..row_len]);
+    }
+
+    let invalid = || "Gagal mendekode HEIF/AVIF: ukuran data piksel tidak sesuai".to_string();
+    let image = if has_alpha {
+        RgbaImage::from_raw(plane.width, plane.height, pixels).map(DynamicImage::ImageRgba8)
+    } else {
+        RgbImage::from_raw(plane.width, plane.height, pixels).map(DynamicImage::ImageRgb8)
+    };
+    Ok(image.ok_or_else(invalid)?)
+}
@@ -0,0 +1,130 @@
+//! Renderer HTML berupa tabel sel berwarna berukuran tetap.
+//!
+//! Output `<pre>` biasa bergantung pada font monospace, yang sering diganti atau
+//! diabaikan oleh klien email dan CMS. Tabel dengan lebar dan tinggi sel eksplisit
+//! (style inline ditambah atribut `bgcolor` untuk klien lama) tetap tampil sebagai
+//! grid piksel yang benar di tempat tersebut.
+
+use crate::art::{escape_html, HTML_HEAD};
+use crate::converter::color_luma;
+use crate::{AsciiArt, Cell};
+
+/// Opsi renderer tabel HTML.
+///
+/// * `cell_size` - Lebar dan tinggi setiap sel dalam piksel CSS
+/// * `show_characters` - Menampilkan karakter di dalam sel (warna teks hitam atau putih
+///   mengikuti warna latar sel); jika `false`, sel hanya berupa blok warna
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, HtmlTableOptions, OutputFormat};
+///
+/// let config = AsciiConfig {
+///     use_color: true,
+///     output_format: OutputFormat::HtmlTable,
+///     html_table: HtmlTableOptions { cell_size: 4, show_characters: false },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HtmlTableOptions {
+    pub cell_size: u32,
+    pub show_characters: bool,
+}
+
+impl Default for HtmlTableOptions {
+    /// Sel 8×8 piksel tanpa karakter.
+    fn default() -> Self {
+        Self {
+            cell_size: 8,
+            show_characters: false,
+        }
+    }
+}
+
+/// Pembuka tabel, termasuk newline setelahnya.
+const TABLE_OPEN: &str = "<table role=\"presentation\" cellpadding=\"0\" cellspacing=\"0\" border=\"0\" \
+                          style=\"border-collapse:collapse;table-layout:fixed\">\n";
+
+/// Penutup tabel dan dokumen.
+const TABLE_CLOSE: &str = "</table>\n";
+const DOCUMENT_CLOSE: &str = "</body>\n</html>";
+
+impl AsciiArt {
+    /// Me-render grid sebagai fragmen `<table>` yang siap disisipkan ke email atau halaman.
+    ///
+    /// Fragmen tidak bergantung pada stylesheet maupun font monospace; semua ukuran
+    /// dan warna ditulis inline pada setiap sel.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, HtmlTableOptions};
+    ///
+    /// let red = Cell { ch: '#', brightness: 0.3, color: [255, 0, 0] };
+    /// let blue = Cell { color: [0, 0, 255], ..red };
+    /// let art = AsciiArt::new(3, 1, vec![red, red, blue]).unwrap();
+    ///
+    /// let table = art.to_html_table(&HtmlTableOptions::default());
+    /// assert!(table.contains("<td width=\"8\" height=\"8\" bgcolor=\"#ff0000\""));
+    /// assert_eq!(table.matches("<td").count(), 3);
+    ///
+    /// let with_text = art.to_html_table(&HtmlTableOptions { show_characters: true, ..Default::default() });
+    /// assert_eq!(with_text.matches(">#</td>").count(), 3);
+    /// ```
+    pub fn to_html_table(&self, options: &HtmlTableOptions) -> String {
+        let mut output = String::from(TABLE_OPEN);
+        for row in self.rows() {
+            output.push_str("<tr>");
+            for cell in row {
+                push_cell(&mut output, cell, options);
+            }
+            output.push_str("</tr>\n");
+        }
+        output.push_str(TABLE_CLOSE);
+        output
+    }
+
+    /// Me-render grid sebagai dokumen HTML lengkap berisi `to_html_table`.
+    pub fn to_html_table_document(&self, options: &HtmlTableOptions) -> String {
+        format!("{}{}{}", HTML_HEAD, self.to_html_table(options), DOCUMENT_CLOSE)
+    }
+}
+
+/// Menulis satu sel `<td>` dengan ukuran dan warna inline.
+fn push_cell(output: &mut String, cell: &Cell, options: &HtmlTableOptions) {
+    let size = options.cell_size.max(1);
+    let [r, g, b] = cell.color;
+    let background = format!("#{:02x}{:02x}{:02x}", r, g, b);
+    output.push_str(&format!(
+        "<td width=\"{size}\" height=\"{size}\" bgcolor=\"{bg}\" \
+         style=\"width:{size}px;height:{size}px;padding:0;background-color:{bg};",
+        size = size,
+        bg = background
+    ));
+    if options.show_characters {
+        // Teks hitam di atas latar terang, putih di atas latar gelap
+        let text = if color_luma(cell.color) > 0.5 { "#000" } else { "#fff" };
+        output.push_str(&format!(
+            "color:{};font:{size}px/{size}px monospace;text-align:center;overflow:hidden\">{}</td>",
+            text,
+            escape_html(&cell.ch.to_string()),
+            size = size
+        ));
+    } else {
+        output.push_str("font-size:0;line-height:0\"></td>");
+    }
+}
+
+/// Ukuran maksimum dokumen `to_html_table_document` untuk grid `columns × rows`.
+///
+/// Panjang setiap sel tetap kecuali karakternya, yang paling panjang `glyph_bytes`
+/// setelah di-escape.
+pub(crate) fn html_table_document_bytes(columns: u64, rows: u64, glyph_bytes: u64, options: &HtmlTableOptions) -> u64 {
+    let mut cell = String::new();
+    push_cell(&mut cell, &Cell { ch: ' ', brightness: 0.0, color: [0, 0, 0] }, options);
+    let cell_bytes = cell.len() as u64 + if options.show_characters { glyph_bytes - 1 } else { 0 };
+    let row_bytes = "<tr>".len() as u64 + columns * cell_bytes + "</tr>\n".len() as u64;
+    (HTML_HEAD.len() + TABLE_OPEN.len() + TABLE_CLOSE.len() + DOCUMENT_CLOSE.len()) as u64 + rows * row_bytes
+}
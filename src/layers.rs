@@ -0,0 +1,607 @@
+//! Input berlapis: layer PSD, halaman TIFF, dan ukuran ikon ICO.
+//!
+//! `list_layers` menampilkan layer/halaman yang tersedia, lalu
+//! `AsciiConverter::convert_layer` mengkonversi salah satunya. Format lain
+//! diperlakukan sebagai gambar dengan satu layer. Konversi biasa (`convert`,
+//! `convert_from_bytes`) pada file PSD memakai gambar gabungannya.
+//!
+//! Dukungan PSD terbatas pada kedalaman 8-bit dengan mode warna grayscale
+//! atau RGB, kompresi raw maupun RLE (PackBits). Area transparan layer
+//! diisi putih agar subjek tetap terbaca.
+
+use crate::{AsciiConverter, ConversionError, ResourceLimits};
+use image::io::Reader as ImageReader;
+use image::{DynamicImage, GrayImage, ImageFormat, RgbImage, RgbaImage};
+use std::fs;
+use std::io::Cursor;
+use std::ops::Range;
+use tiff::decoder::{Decoder as TiffDecoder, DecodingResult};
+use tiff::ColorType as TiffColorType;
+
+/// Informasi satu layer, halaman, atau ukuran ikon.
+///
+/// * `index` - Posisi layer, dipakai oleh `LayerSelector::Index`
+/// * `name` - Nama layer PSD, atau label halaman/ukuran untuk format lain
+/// * `width` - Lebar layer dalam piksel
+/// * `height` - Tinggi layer dalam piksel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerInfo {
+    pub index: usize,
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Cara memilih layer yang akan dikonversi.
+///
+/// * `Index` - Berdasarkan posisi pada `list_layers`
+/// * `Name` - Berdasarkan nama persis (layer PSD, `"Halaman 2"`, `"32x32"`)
+/// * `Largest` - Layer dengan jumlah piksel terbanyak
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayerSelector {
+    Index(usize),
+    Name(String),
+    Largest,
+}
+
+/// Menampilkan daftar layer, halaman, atau ukuran ikon dalam sebuah file.
+///
+/// # Arguments
+///
+/// * `path` - Path ke file gambar (PSD, TIFF, ICO, atau format lain)
+///
+/// # Returns
+///
+/// * `Ok(Vec<LayerInfo>)` - Daftar layer; format biasa menghasilkan satu layer
+/// * `Err(String)` - Pesan error jika file gagal dibaca
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use aspix::{list_layers, AsciiConverter, LayerSelector};
+///
+/// for layer in list_layers("design.psd").unwrap() {
+///     println!("{}: {} ({}x{})", layer.index, layer.name, layer.width, layer.height);
+/// }
+/// let ascii = AsciiConverter::new(80, 40)
+///     .convert_layer("design.psd", &LayerSelector::Name("Logo".to_string()))
+///     .unwrap();
+/// ```
+pub fn list_layers(path: &str) -> Result<Vec<LayerInfo>, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+    Container::parse(&bytes)?.layers(&bytes)
+}
+
+/// Membuka satu layer dari file berlapis sebagai `DynamicImage`.
+///
+/// # Returns
+///
+/// * `Ok(DynamicImage)` - Gambar layer yang dipilih
+/// * `Err(String)` - Pesan error jika file gagal dibaca atau layer tidak ditemukan
+pub fn open_layer(path: &str, selector: &LayerSelector) -> Result<DynamicImage, String> {
+    open_layer_with_limits(path, selector, &ResourceLimits::default())
+}
+
+/// Seperti `open_layer`, dengan batas sumber daya.
+///
+/// Ukuran file diperiksa sebelum dibaca dan dimensi layer yang dipilih
+/// diperiksa sebelum data pikselnya didekode.
+///
+/// # Returns
+///
+/// * `Ok(DynamicImage)` - Gambar layer yang dipilih
+/// * `Err(String)` - Pesan error jika file gagal dibaca, layer tidak ditemukan, atau melampaui `limits`
+pub fn open_layer_with_limits(path: &str, selector: &LayerSelector, limits: &ResourceLimits) -> Result<DynamicImage, String> {
+    let size = fs::metadata(path).map_err(|e| format!("Gagal membuka gambar: {}", e))?.len();
+    limits.check_bytes(size)?;
+    let bytes = fs::read(path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+    let container = Container::parse(&bytes)?;
+    let layers = container.layers(&bytes)?;
+
+    let index = match selector {
+        LayerSelector::Index(index) => layers.iter().position(|layer| layer.index == *index),
+        LayerSelector::Name(name) => layers.iter().position(|layer| &layer.name == name),
+        LayerSelector::Largest => layers
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, layer)| layer.width as u64 * layer.height as u64)
+            .map(|(position, _)| position),
+    }
+    .ok_or_else(|| format!("Layer {:?} tidak ditemukan", selector))?;
+
+    limits.check_dimensions(layers[index].width, layers[index].height)?;
+    let img = container.decode(&bytes, index, limits)?;
+    limits.check_decoded(&img)?;
+    Ok(img)
+}
+
+/// Mendekode gambar gabungan dokumen PSD untuk konversi biasa.
+///
+/// Dimensi dokumen diperiksa terhadap `limits` sebelum data piksel didekode.
+pub(crate) fn decode_psd(bytes: &[u8], limits: &ResourceLimits) -> Result<DynamicImage, ConversionError> {
+    let psd = Psd::parse(bytes)?;
+    limits.check_dimensions(psd.width, psd.height)?;
+    Ok(psd.decode_composite(bytes)?)
+}
+
+impl AsciiConverter {
+    /// Mengkonversi satu layer, halaman, atau ukuran ikon dari file berlapis.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_path` - Path ke file gambar
+    /// * `selector` - Layer yang dipilih
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - ASCII art dari layer yang dipilih
+    /// * `Err(String)` - Pesan error jika gagal
+    pub fn convert_layer(&self, image_path: &str, selector: &LayerSelector) -> Result<String, String> {
+        let img = open_layer_with_limits(image_path, selector, &self.config().limits)?;
+        self.process_image(&img)
+    }
+}
+
+/// Jenis container yang dikenali beserta hasil parsing strukturnya.
+enum Container {
+    Psd(Psd),
+    Tiff,
+    Ico(Vec<IcoEntry>),
+    Single,
+}
+
+impl Container {
+    /// Mengenali container dari magic bytes.
+    fn parse(bytes: &[u8]) -> Result<Self, String> {
+        if bytes.starts_with(b"8BPS") {
+            Psd::parse(bytes).map(Container::Psd)
+        } else if bytes.starts_with(b"II*\0") || bytes.starts_with(b"MM\0*") {
+            Ok(Container::Tiff)
+        } else if bytes.starts_with(&[0, 0, 1, 0]) {
+            parse_ico(bytes).map(Container::Ico)
+        } else {
+            Ok(Container::Single)
+        }
+    }
+
+    /// Daftar layer di dalam container.
+    fn layers(&self, bytes: &[u8]) -> Result<Vec<LayerInfo>, String> {
+        match self {
+            Container::Psd(psd) => Ok(psd.layer_infos()),
+            Container::Tiff => tiff_pages(bytes),
+            Container::Ico(entries) => Ok(entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| LayerInfo {
+                    index,
+                    name: format!("{}x{}", entry.width, entry.height),
+                    width: entry.width,
+                    height: entry.height,
+                })
+                .collect()),
+            Container::Single => {
+                let (width, height) = ImageReader::new(Cursor::new(bytes))
+                    .with_guessed_format()
+                    .map_err(|e| format!("Gagal membuka gambar: {}", e))?
+                    .into_dimensions()
+                    .map_err(|e| format!("Gagal mendekode gambar: {}", e))?;
+                Ok(vec![LayerInfo { index: 0, name: String::new(), width, height }])
+            }
+        }
+    }
+
+    /// Mendekode layer pada posisi `index`.
+    fn decode(&self, bytes: &[u8], index: usize, limits: &ResourceLimits) -> Result<DynamicImage, String> {
+        match self {
+            Container::Psd(psd) => psd.decode(bytes, index),
+            Container::Tiff => decode_tiff_page(bytes, index),
+            Container::Ico(entries) => decode_ico_entry(bytes, &entries[index]),
+            Container::Single => ImageReader::new(Cursor::new(bytes))
+                .with_guessed_format()
+                .map_err(image::ImageError::IoError)
+                .and_then(|reader| limits.decode(reader))
+                .map_err(|e| format!("Gagal mendekode gambar: {}", e)),
+        }
+    }
+}
+
+/// Pembaca big-endian sederhana untuk struktur biner.
+struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    fn new(data: &'a [u8], pos: usize) -> Self {
+        Self { data, pos }
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self.pos.checked_add(len).filter(|&end| end <= self.data.len())
+            .ok_or_else(|| "Data gambar terpotong".to_string())?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        let b = self.take(2)?;
+        Ok(u16::from_be_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn i16(&mut self) -> Result<i16, String> {
+        self.u16().map(|v| v as i16)
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        self.u32().map(|v| v as i32)
+    }
+
+    /// Membaca blok dengan awalan panjang `u32` dan mengembalikan rentangnya.
+    fn section(&mut self) -> Result<Range<usize>, String> {
+        let len = self.u32()? as usize;
+        let start = self.pos;
+        self.take(len)?;
+        Ok(start..self.pos)
+    }
+}
+
+/// Dokumen PSD yang sudah di-parse (tanpa mendekode piksel).
+struct Psd {
+    width: u32,
+    height: u32,
+    channels: u16,
+    grayscale: bool,
+    layers: Vec<PsdLayer>,
+    /// Awal bagian image data (gambar gabungan seluruh layer).
+    composite: usize,
+}
+
+/// Satu layer PSD: nama, ukuran, dan lokasi data setiap channel.
+struct PsdLayer {
+    name: String,
+    width: u32,
+    height: u32,
+    /// Pasangan ID channel (0..2 warna, -1 alpha) dan rentang datanya.
+    channels: Vec<(i16, Range<usize>)>,
+}
+
+impl Psd {
+    fn parse(bytes: &[u8]) -> Result<Self, String> {
+        let mut reader = ByteReader::new(bytes, 4);
+        if reader.u16()? != 1 {
+            return Err("Versi PSD tidak didukung (hanya PSD, bukan PSB)".to_string());
+        }
+        reader.take(6)?;
+        let channels = reader.u16()?;
+        let height = reader.u32()?;
+        let width = reader.u32()?;
+        let depth = reader.u16()?;
+        let mode = reader.u16()?;
+        if depth != 8 || !(mode == 1 || mode == 3) {
+            return Err(format!(
+                "PSD dengan kedalaman {} bit dan mode warna {} tidak didukung",
+                depth, mode
+            ));
+        }
+        let color_channels = if mode == 1 { 1 } else { 3 };
+        if channels < color_channels {
+            return Err(format!(
+                "Header PSD tidak valid: mode warna {} membutuhkan {} channel, hanya ada {}",
+                mode, color_channels, channels
+            ));
+        }
+
+        reader.section()?; // color mode data
+        reader.section()?; // image resources
+        let layer_and_mask = reader.section()?;
+        let layers = parse_psd_layers(bytes, layer_and_mask.clone())?;
+
+        Ok(Self {
+            width,
+            height,
+            channels,
+            grayscale: mode == 1,
+            layers,
+            composite: layer_and_mask.end,
+        })
+    }
+
+    /// Daftar layer; jika dokumen tidak memiliki layer, gambar gabungan menjadi satu-satunya layer.
+    fn layer_infos(&self) -> Vec<LayerInfo> {
+        if self.layers.is_empty() {
+            return vec![LayerInfo { index: 0, name: String::new(), width: self.width, height: self.height }];
+        }
+        self.layers
+            .iter()
+            .enumerate()
+            .map(|(index, layer)| LayerInfo {
+                index,
+                name: layer.name.clone(),
+                width: layer.width,
+                height: layer.height,
+            })
+            .collect()
+    }
+
+    fn decode(&self, bytes: &[u8], index: usize) -> Result<DynamicImage, String> {
+        let Some(layer) = self.layers.get(index) else {
+            return self.decode_composite(bytes);
+        };
+
+        let pixels = layer.width as usize * layer.height as usize;
+        let mut planes: [Option<Vec<u8>>; 4] = Default::default();
+        for (id, range) in &layer.channels {
+            let slot = match id {
+                0..=2 => *id as usize,
+                -1 => 3,
+                _ => continue, // mask pengguna memiliki ukuran sendiri
+            };
+            let mut reader = ByteReader::new(&bytes[range.clone()], 0);
+            let compression = reader.u16()?;
+            planes[slot] = Some(read_channel(&mut reader, compression, layer.width, layer.height, &mut None)?);
+        }
+
+        let gray = planes[0].clone().unwrap_or_else(|| vec![0; pixels]);
+        let color = |slot: usize| if self.grayscale { &gray } else { planes[slot].as_ref().unwrap_or(&gray) };
+        let (r, g, b) = (color(0), color(1), color(2));
+        let alpha = planes[3].as_ref();
+
+        // Komposit ke latar putih
+        let mut data = Vec::with_capacity(pixels * 3);
+        for i in 0..pixels {
+            let a = alpha.map_or(255, |alpha| alpha[i]) as u32;
+            for channel in [r[i], g[i], b[i]] {
+                data.push(((channel as u32 * a + 255 * (255 - a)) / 255) as u8);
+            }
+        }
+        RgbImage::from_raw(layer.width, layer.height, data)
+            .map(DynamicImage::ImageRgb8)
+            .ok_or_else(|| "Gagal mendekode layer PSD".to_string())
+    }
+
+    /// Mendekode gambar gabungan (bagian image data di akhir file).
+    fn decode_composite(&self, bytes: &[u8]) -> Result<DynamicImage, String> {
+        let mut reader = ByteReader::new(bytes, self.composite);
+        let compression = reader.u16()?;
+        let color_channels = if self.grayscale { 1 } else { 3 };
+
+        // Pada RLE, jumlah bytes setiap baris seluruh channel disimpan di depan
+        let mut row_counts = if compression == 1 {
+            let rows = self.height as usize * self.channels as usize;
+            let mut counts = Vec::with_capacity(rows);
+            for _ in 0..rows {
+                counts.push(reader.u16()? as usize);
+            }
+            Some(counts.into_iter())
+        } else {
+            None
+        };
+
+        let mut planes = Vec::with_capacity(color_channels);
+        for _ in 0..color_channels.min(self.channels as usize) {
+            planes.push(read_channel(&mut reader, compression, self.width, self.height, &mut row_counts)?);
+        }
+
+        if self.grayscale {
+            GrayImage::from_raw(self.width, self.height, planes.remove(0))
+                .map(DynamicImage::ImageLuma8)
+                .ok_or_else(|| "Gagal mendekode gambar PSD".to_string())
+        } else {
+            let data = (0..planes[0].len())
+                .flat_map(|i| [planes[0][i], planes[1][i], planes[2][i]])
+                .collect();
+            RgbImage::from_raw(self.width, self.height, data)
+                .map(DynamicImage::ImageRgb8)
+                .ok_or_else(|| "Gagal mendekode gambar PSD".to_string())
+        }
+    }
+}
+
+/// Mem-parse layer record dan lokasi data channel di bagian layer & mask.
+///
+/// Layer kosong (misalnya penanda grup) dilewati.
+fn parse_psd_layers(bytes: &[u8], section: Range<usize>) -> Result<Vec<PsdLayer>, String> {
+    if section.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut reader = ByteReader::new(&bytes[..section.end], section.start);
+    let layer_info = reader.section()?;
+    if layer_info.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut reader = ByteReader::new(&bytes[..layer_info.end], layer_info.start);
+    let count = reader.i16()?.unsigned_abs();
+
+    let mut records = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let top = reader.i32()?;
+        let left = reader.i32()?;
+        let bottom = reader.i32()?;
+        let right = reader.i32()?;
+        let channel_count = reader.u16()?;
+        let mut channels = Vec::with_capacity(channel_count as usize);
+        for _ in 0..channel_count {
+            channels.push((reader.i16()?, reader.u32()? as usize));
+        }
+        reader.take(12)?; // signature, blend mode, opacity, clipping, flags, filler
+
+        let extra = reader.section()?;
+        let mut extra_reader = ByteReader::new(&bytes[..extra.end], extra.start);
+        extra_reader.section()?; // layer mask
+        extra_reader.section()?; // blending ranges
+        let name_len = extra_reader.u8()? as usize;
+        let name = String::from_utf8_lossy(extra_reader.take(name_len)?).into_owned();
+
+        let (Some(width), Some(height)) = (right.checked_sub(left), bottom.checked_sub(top)) else {
+            return Err("Batas layer PSD tidak valid".to_string());
+        };
+        let (width, height) = (width.max(0) as u32, height.max(0) as u32);
+        records.push((name, width, height, channels));
+    }
+
+    // Data channel tersimpan berurutan setelah semua record
+    let mut layers = Vec::with_capacity(records.len());
+    for (name, width, height, channel_lengths) in records {
+        let mut channels = Vec::with_capacity(channel_lengths.len());
+        for (id, len) in channel_lengths {
+            let start = reader.pos;
+            reader.take(len)?;
+            channels.push((id, start..reader.pos));
+        }
+        if width > 0 && height > 0 {
+            layers.push(PsdLayer { name, width, height, channels });
+        }
+    }
+    Ok(layers)
+}
+
+/// Membaca satu channel planar (raw atau RLE).
+///
+/// Untuk gambar gabungan, jumlah bytes per baris RLE sudah dibaca di depan
+/// dan diteruskan lewat `row_counts`; untuk layer, dibaca di awal channel.
+fn read_channel(
+    reader: &mut ByteReader,
+    compression: u16,
+    width: u32,
+    height: u32,
+    row_counts: &mut Option<std::vec::IntoIter<usize>>,
+) -> Result<Vec<u8>, String> {
+    let (width, height) = (width as usize, height as usize);
+    match compression {
+        0 => Ok(reader.take(width * height)?.to_vec()),
+        1 => {
+            let counts: Vec<usize> = match row_counts {
+                Some(counts) => counts.by_ref().take(height).collect(),
+                None => (0..height).map(|_| reader.u16().map(|c| c as usize)).collect::<Result<_, _>>()?,
+            };
+            let mut output = Vec::with_capacity(width * height);
+            for count in counts {
+                let row_start = output.len();
+                unpack_bits(reader.take(count)?, &mut output);
+                output.resize(row_start + width, 0);
+            }
+            Ok(output)
+        }
+        other => Err(format!("Kompresi PSD {} tidak didukung", other)),
+    }
+}
+
+/// Dekompresi PackBits.
+fn unpack_bits(mut input: &[u8], output: &mut Vec<u8>) {
+    while let Some((&header, rest)) = input.split_first() {
+        let header = header as i8;
+        input = rest;
+        if header >= 0 {
+            let len = (header as usize + 1).min(input.len());
+            output.extend_from_slice(&input[..len]);
+            input = &input[len..];
+        } else if header != -128 {
+            if let Some((&value, rest)) = input.split_first() {
+                output.extend(std::iter::repeat_n(value, (1 - header as isize) as usize));
+                input = rest;
+            }
+        }
+    }
+}
+
+/// Daftar halaman (IFD) pada file TIFF.
+fn tiff_pages(bytes: &[u8]) -> Result<Vec<LayerInfo>, String> {
+    let mut decoder = TiffDecoder::new(Cursor::new(bytes)).map_err(|e| format!("Gagal membaca TIFF: {}", e))?;
+    let mut pages = Vec::new();
+    loop {
+        let (width, height) = decoder.dimensions().map_err(|e| format!("Gagal membaca TIFF: {}", e))?;
+        let index = pages.len();
+        pages.push(LayerInfo { index, name: format!("Halaman {}", index + 1), width, height });
+        if !decoder.more_images() {
+            return Ok(pages);
+        }
+        decoder.next_image().map_err(|e| format!("Gagal membaca TIFF: {}", e))?;
+    }
+}
+
+/// Mendekode satu halaman TIFF (8 atau 16 bit, grayscale/RGB dengan atau tanpa alpha).
+fn decode_tiff_page(bytes: &[u8], index: usize) -> Result<DynamicImage, String> {
+    let error = |e: tiff::TiffError| format!("Gagal mendekode TIFF: {}", e);
+    let mut decoder = TiffDecoder::new(Cursor::new(bytes)).map_err(error)?;
+    decoder.seek_to_image(index).map_err(error)?;
+    let (width, height) = decoder.dimensions().map_err(error)?;
+    let color_type = decoder.colortype().map_err(error)?;
+
+    let data = match decoder.read_image().map_err(error)? {
+        DecodingResult::U8(data) => data,
+        DecodingResult::U16(data) => data.into_iter().map(|v| (v >> 8) as u8).collect(),
+        _ => return Err("Format sampel TIFF tidak didukung".to_string()),
+    };
+
+    let invalid = || "Gagal mendekode TIFF: ukuran data tidak sesuai".to_string();
+    match color_type {
+        TiffColorType::Gray(_) => GrayImage::from_raw(width, height, data).map(DynamicImage::ImageLuma8).ok_or_else(invalid),
+        TiffColorType::GrayA(_) => image::GrayAlphaImage::from_raw(width, height, data)
+            .map(DynamicImage::ImageLumaA8)
+            .ok_or_else(invalid),
+        TiffColorType::RGB(_) => RgbImage::from_raw(width, height, data).map(DynamicImage::ImageRgb8).ok_or_else(invalid),
+        TiffColorType::RGBA(_) => RgbaImage::from_raw(width, height, data).map(DynamicImage::ImageRgba8).ok_or_else(invalid),
+        other => Err(format!("Tipe warna TIFF {:?} tidak didukung", other)),
+    }
+}
+
+/// Satu entri direktori ICO.
+struct IcoEntry {
+    width: u32,
+    height: u32,
+    /// 16 bytes entri direktori asli.
+    directory: [u8; 16],
+    data: Range<usize>,
+}
+
+/// Mem-parse direktori ICO (nilai little-endian).
+fn parse_ico(bytes: &[u8]) -> Result<Vec<IcoEntry>, String> {
+    let truncated = || "Data ICO terpotong".to_string();
+    let count = u16::from_le_bytes([*bytes.get(4).ok_or_else(truncated)?, *bytes.get(5).ok_or_else(truncated)?]);
+
+    (0..count as usize)
+        .map(|i| {
+            let start = 6 + i * 16;
+            let directory: [u8; 16] = bytes
+                .get(start..start + 16)
+                .and_then(|slice| slice.try_into().ok())
+                .ok_or_else(truncated)?;
+            let size = u32::from_le_bytes([directory[8], directory[9], directory[10], directory[11]]) as usize;
+            let offset = u32::from_le_bytes([directory[12], directory[13], directory[14], directory[15]]) as usize;
+            if offset.checked_add(size).is_none_or(|end| end > bytes.len()) {
+                return Err(truncated());
+            }
+            // Nilai 0 pada direktori berarti 256 piksel
+            let dimension = |value: u8| if value == 0 { 256 } else { value as u32 };
+            Ok(IcoEntry {
+                width: dimension(directory[0]),
+                height: dimension(directory[1]),
+                directory,
+                data: offset..offset + size,
+            })
+        })
+        .collect()
+}
+
+/// Mendekode satu ukuran ikon dengan membungkusnya sebagai ICO satu entri.
+fn decode_ico_entry(bytes: &[u8], entry: &IcoEntry) -> Result<DynamicImage, String> {
+    let mut single = Vec::with_capacity(22 + entry.data.len());
+    single.extend_from_slice(&[0, 0, 1, 0, 1, 0]);
+    single.extend_from_slice(&entry.directory[..12]);
+    single.extend_from_slice(&22u32.to_le_bytes());
+    single.extend_from_slice(&bytes[entry.data.clone()]);
+
+    image::load_from_memory_with_format(&single, ImageFormat::Ico)
+        .map_err(|e| format!("Gagal mendekode ikon: {}", e))
+}
@@ -1,3 +1,121 @@
+mod animation;
+mod ans;
+mod art;
+mod ascii_to_image;
+#[cfg(feature = "tokio")]
+mod async_api;
+mod background;
+mod batch;
+mod cache;
+mod calibration;
+mod canvas;
+mod charset;
+mod chunk;
+mod clahe;
+mod contour;
 mod converter;
+mod crop;
+mod crt;
+mod curve;
+mod depth;
+mod diff;
+mod dither;
+mod emoji;
+mod estimate;
+mod format;
+mod frame_export;
+mod gradient;
+#[cfg(feature = "heif")]
+mod heif;
+mod html_table;
+mod layers;
+mod limits;
+mod linear;
+mod metadata;
+mod montage;
+mod motd;
+mod orientation;
+pub mod palette;
+mod parse;
+#[cfg(feature = "pdf")]
+mod pdf;
+#[cfg(feature = "player")]
+mod player;
+#[cfg(feature = "preview")]
+pub mod preview;
+pub mod quality;
+mod recolor;
+mod report;
+mod resample;
+mod retro;
+mod rng;
+mod rust_source;
+#[cfg(feature = "server")]
+mod server;
+#[cfg(feature = "simd")]
+mod simd;
+mod sprite;
+mod subtitle;
+#[cfg(feature = "svg")]
+mod svg;
+mod temporal;
+pub mod testing;
+mod template;
+mod theme;
+mod tiled;
+mod trace;
+mod tune;
+mod validate;
+#[cfg(feature = "watch")]
+mod watch;
+mod white_balance;
 
+pub use animation::*;
+pub use ans::*;
+pub use art::*;
+pub use ascii_to_image::*;
+pub use background::*;
+pub use batch::*;
+pub use cache::*;
+pub use calibration::*;
+pub use canvas::*;
+pub use charset::*;
+pub use chunk::*;
+pub use clahe::*;
+pub use contour::*;
 pub use converter::*;
+pub use crt::*;
+pub use curve::*;
+pub use depth::*;
+pub use diff::*;
+pub use dither::*;
+pub use emoji::*;
+pub use estimate::*;
+pub use format::*;
+pub use frame_export::*;
+pub use gradient::*;
+pub use html_table::*;
+pub use layers::*;
+pub use limits::*;
+pub use metadata::*;
+pub use montage::*;
+pub use motd::*;
+pub use orientation::*;
+#[cfg(feature = "pdf")]
+pub use pdf::*;
+#[cfg(feature = "player")]
+pub use player::*;
+pub use recolor::*;
+pub use report::*;
+pub use resample::*;
+pub use retro::*;
+pub use rust_source::*;
+#[cfg(feature = "server")]
+pub use server::*;
+pub use subtitle::*;
+pub use temporal::*;
+pub use template::*;
+pub use theme::*;
+pub use tune::*;
+pub use validate::*;
+pub use white_balance::*;
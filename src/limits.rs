@@ -0,0 +1,259 @@
+//! Batas sumber daya untuk melindungi layanan dari gambar berbahaya atau terlalu besar.
+
+use image::io::{Limits, Reader as ImageReader};
+use image::{DynamicImage, ImageResult};
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Cursor, Seek};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::AsciiConverter;
+
+/// Byte maksimum per piksel hasil decode (RGBA `f32`), dasar batas alokasi decoder.
+const MAX_BYTES_PER_PIXEL: u64 = 16;
+
+/// Batas sumber daya yang diterapkan saat konversi.
+///
+/// Ukuran data dan dimensi diperiksa sebelum decode (dimensi dibaca dari
+/// header), sedangkan `timeout` diperiksa di antara tahap pipeline sehingga
+/// tahap yang sedang berjalan tidak dihentikan di tengah jalan. Format yang
+/// header-nya tidak dapat dibaca tanpa decode (misalnya HEIF/AVIF dan SVG)
+/// diperiksa dimensinya setelah didekode.
+///
+/// * `max_pixels` - Jumlah piksel maksimum gambar sumber (lebar × tinggi)
+/// * `max_bytes` - Ukuran maksimum data gambar terkompresi
+/// * `timeout` - Durasi maksimum konversi, dihitung sejak decode dimulai
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, AsciiConverter, ResourceLimits};
+/// use std::time::Duration;
+///
+/// let converter = AsciiConverter::with_config(AsciiConfig {
+///     limits: ResourceLimits {
+///         max_pixels: Some(40_000_000),
+///         max_bytes: Some(20 * 1024 * 1024),
+///         timeout: Some(Duration::from_secs(5)),
+///     },
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceLimits {
+    pub max_pixels: Option<u64>,
+    pub max_bytes: Option<u64>,
+    pub timeout: Option<Duration>,
+}
+
+/// Batas sumber daya yang terlampaui.
+///
+/// Dikembalikan oleh `AsciiConverter::check_limits`, dan sebagai
+/// `ConversionError::Limit` oleh `try_convert` dan `try_convert_from_bytes`
+/// (misalnya untuk membalas HTTP 413). Method lain mengembalikan pesan dari `message`.
+///
+/// * `Bytes` - Data gambar melebihi `max_bytes`
+/// * `Pixels` - Dimensi gambar melebihi `max_pixels`
+/// * `Timeout` - Konversi berjalan lebih lama dari `timeout`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitExceeded {
+    Bytes { size: u64, max_bytes: u64 },
+    Pixels { width: u32, height: u32, max_pixels: u64 },
+    Timeout { elapsed: Duration, timeout: Duration },
+}
+
+impl LimitExceeded {
+    /// Pesan yang menjelaskan batas yang terlampaui.
+    pub fn message(&self) -> String {
+        match self {
+            LimitExceeded::Bytes { size, max_bytes } => {
+                format!("data gambar {} bytes melebihi batas {} bytes", size, max_bytes)
+            }
+            LimitExceeded::Pixels { width, height, max_pixels } => {
+                format!("gambar {}x{} melebihi batas {} piksel", width, height, max_pixels)
+            }
+            LimitExceeded::Timeout { elapsed, timeout } => {
+                format!("konversi berjalan {:?}, melebihi batas waktu {:?}", elapsed, timeout)
+            }
+        }
+    }
+}
+
+impl From<LimitExceeded> for String {
+    fn from(limit: LimitExceeded) -> Self {
+        format!("Batas sumber daya terlampaui: {}", limit.message())
+    }
+}
+
+/// Error konversi yang memisahkan batas sumber daya dari kegagalan lain.
+///
+/// * `Limit` - Salah satu `ResourceLimits` terlampaui
+/// * `Failed` - Kegagalan lain (file tidak ada, format rusak, konfigurasi tidak valid)
+///
+/// Dapat diubah menjadi `String` dengan pesan yang sama seperti method konversi biasa.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionError {
+    Limit(LimitExceeded),
+    Failed(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConversionError::Limit(limit) => write!(f, "Batas sumber daya terlampaui: {}", limit.message()),
+            ConversionError::Failed(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<LimitExceeded> for ConversionError {
+    fn from(limit: LimitExceeded) -> Self {
+        ConversionError::Limit(limit)
+    }
+}
+
+impl From<String> for ConversionError {
+    fn from(message: String) -> Self {
+        ConversionError::Failed(message)
+    }
+}
+
+impl From<ConversionError> for String {
+    fn from(error: ConversionError) -> Self {
+        error.to_string()
+    }
+}
+
+impl ResourceLimits {
+    /// Memeriksa ukuran data gambar terhadap `max_bytes`.
+    pub fn check_bytes(&self, size: u64) -> Result<(), LimitExceeded> {
+        match self.max_bytes {
+            Some(max_bytes) if size > max_bytes => Err(LimitExceeded::Bytes { size, max_bytes }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Memeriksa dimensi gambar terhadap `max_pixels`.
+    pub fn check_dimensions(&self, width: u32, height: u32) -> Result<(), LimitExceeded> {
+        match self.max_pixels {
+            Some(max_pixels) if width as u64 * height as u64 > max_pixels => {
+                Err(LimitExceeded::Pixels { width, height, max_pixels })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Memeriksa waktu yang telah berlalu sejak `started` terhadap `timeout`.
+    pub fn check_elapsed(&self, started: Instant) -> Result<(), LimitExceeded> {
+        let elapsed = started.elapsed();
+        match self.timeout {
+            Some(timeout) if elapsed > timeout => Err(LimitExceeded::Timeout { elapsed, timeout }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Memeriksa dimensi gambar yang sudah didekode, untuk format tanpa dimensi di header.
+    pub(crate) fn check_decoded(&self, img: &DynamicImage) -> Result<(), LimitExceeded> {
+        self.check_dimensions(img.width(), img.height())
+    }
+
+    /// Memeriksa ukuran dan dimensi (dari header) data gambar di memori.
+    ///
+    /// Data yang header-nya tidak dikenali (misalnya SVG) hanya diperiksa ukurannya;
+    /// dimensinya diperiksa dengan `check_decoded` setelah decode.
+    pub(crate) fn check_data(&self, bytes: &[u8]) -> Result<(), LimitExceeded> {
+        self.check_bytes(bytes.len() as u64)?;
+        if self.max_pixels.is_some() {
+            self.check_header(ImageReader::new(Cursor::new(bytes)).with_guessed_format())?;
+        }
+        Ok(())
+    }
+
+    /// Memeriksa ukuran dan dimensi (dari header) file gambar sebelum dibuka.
+    pub(crate) fn check_file(&self, path: &Path) -> Result<(), ConversionError> {
+        if self.max_bytes.is_some() {
+            let size = fs::metadata(path).map_err(|e| format!("Gagal membuka gambar: {}", e))?.len();
+            self.check_bytes(size)?;
+        }
+        if self.max_pixels.is_some() {
+            self.check_header(ImageReader::open(path).and_then(ImageReader::with_guessed_format))?;
+        }
+        Ok(())
+    }
+
+    /// Membaca dimensi dari header tanpa men-decode data piksel lalu memeriksanya.
+    ///
+    /// Header yang tidak dikenali lolos di sini; pemanggil memeriksa hasil decode-nya.
+    fn check_header<R: BufRead + Seek>(&self, reader: io::Result<ImageReader<R>>) -> Result<(), LimitExceeded> {
+        match reader.ok().and_then(|reader| reader.into_dimensions().ok()) {
+            Some((width, height)) => self.check_dimensions(width, height),
+            None => Ok(()),
+        }
+    }
+
+    /// Batas alokasi decoder crate `image` sesuai `max_pixels`.
+    pub(crate) fn decoder_limits(&self) -> Limits {
+        let mut limits = Limits::default();
+        if let Some(max_pixels) = self.max_pixels {
+            limits.max_alloc = Some(max_pixels.saturating_mul(MAX_BYTES_PER_PIXEL));
+        }
+        limits
+    }
+
+    /// Men-decode gambar dengan batas alokasi decoder sesuai `max_pixels`.
+    pub(crate) fn decode<R: BufRead + Seek>(&self, mut reader: ImageReader<R>) -> ImageResult<DynamicImage> {
+        if self.max_pixels.is_some() {
+            reader.limits(self.decoder_limits());
+        }
+        reader.decode()
+    }
+
+    /// Membuka dan men-decode file gambar dengan semua pemeriksaan batas.
+    ///
+    /// Dipakai oleh jalur yang tidak melewati pipeline converter (sprite sheet,
+    /// banner MOTD, preview interaktif).
+    pub(crate) fn open(&self, path: &Path) -> Result<DynamicImage, ConversionError> {
+        self.check_file(path)?;
+        let reader = ImageReader::open(path)
+            .and_then(ImageReader::with_guessed_format)
+            .map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+        let img = self.decode(reader).map_err(|e| format!("Gagal mendekode gambar: {}", e))?;
+        self.check_decoded(&img)?;
+        Ok(img)
+    }
+}
+
+impl AsciiConverter {
+    /// Memeriksa data gambar terhadap `AsciiConfig::limits` tanpa men-decode-nya.
+    ///
+    /// Konversi sudah menjalankan pemeriksaan yang sama; fungsi ini berguna
+    /// ketika pemanggil membutuhkan error bertipe, misalnya untuk membalas
+    /// HTTP 413 sebelum konversi dimulai.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConfig, AsciiConverter, LimitExceeded, ResourceLimits};
+    /// use image::{DynamicImage, ImageOutputFormat};
+    /// use std::io::Cursor;
+    ///
+    /// let mut png = Vec::new();
+    /// DynamicImage::new_rgb8(300, 200)
+    ///     .write_to(&mut Cursor::new(&mut png), ImageOutputFormat::Png)
+    ///     .unwrap();
+    ///
+    /// let converter = AsciiConverter::with_config(AsciiConfig {
+    ///     limits: ResourceLimits { max_pixels: Some(10_000), ..Default::default() },
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(
+    ///     converter.check_limits(&png),
+    ///     Err(LimitExceeded::Pixels { width: 300, height: 200, max_pixels: 10_000 })
+    /// );
+    /// assert!(converter.convert_from_bytes(&png).unwrap_err().starts_with("Batas sumber daya terlampaui"));
+    /// ```
+    pub fn check_limits(&self, bytes: &[u8]) -> Result<(), LimitExceeded> {
+        self.config().limits.check_data(bytes)
+    }
+}
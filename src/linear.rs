@@ -0,0 +1,55 @@
+//! Konversi sRGB ↔ linear-light agar rata-rata warna sesuai intensitas cahaya sebenarnya.
+//!
+//! Merata-rata nilai sRGB (yang sudah di-gamma-encode) secara langsung membuat
+//! sel berisi campuran warna terlihat lebih gelap dari aslinya; misalnya sel
+//! setengah hitam setengah putih menjadi abu-abu 128, padahal intensitas
+//! cahayanya setara abu-abu 188.
+
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, Rgba, RgbaImage};
+
+/// Mengubah komponen sRGB (0.0 - 1.0) menjadi linear-light.
+pub(crate) fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Mengubah komponen linear-light (0.0 - 1.0) kembali menjadi sRGB.
+pub(crate) fn linear_to_srgb(value: f32) -> f32 {
+    let value = value.clamp(0.0, 1.0);
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Tabel linear-light untuk setiap nilai komponen 8-bit.
+pub(crate) fn linear_table() -> [f32; 256] {
+    std::array::from_fn(|i| srgb_to_linear(i as f32 / 255.0))
+}
+
+/// Mengubah komponen linear-light menjadi nilai 8-bit sRGB.
+pub(crate) fn linear_to_u8(value: f32) -> u8 {
+    (linear_to_srgb(value) * 255.0).round() as u8
+}
+
+/// Resize dengan interpolasi di ruang linear-light; alpha diinterpolasi apa adanya.
+pub(crate) fn resize_linear(img: &DynamicImage, width: u32, height: u32, filter: FilterType) -> DynamicImage {
+    let mut linear = img.to_rgba32f();
+    for pixel in linear.pixels_mut() {
+        for c in 0..3 {
+            pixel[c] = srgb_to_linear(pixel[c]);
+        }
+    }
+
+    let resized = imageops::resize(&linear, width, height, filter);
+    let output = RgbaImage::from_fn(width, height, |x, y| {
+        let [r, g, b, a] = resized.get_pixel(x, y).0;
+        Rgba([linear_to_u8(r), linear_to_u8(g), linear_to_u8(b), (a.clamp(0.0, 1.0) * 255.0).round() as u8])
+    });
+    DynamicImage::ImageRgba8(output)
+}
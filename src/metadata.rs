@@ -0,0 +1,419 @@
+//! Metadata sumber (nama file, dimensi, tanggal pengambilan EXIF, ringkasan dan sidik jari
+//! konfigurasi) yang dapat disematkan ke output agar art tetap dapat dilacak ke sumber dan
+//! pengaturannya.
+//!
+//! Teks biasa dan ANSI mendapat header komentar `#`, HTML mendapat tag `<meta>`, dan
+//! ekspor `.ANS`/`.XB` mengisi record SAUCE. Tanggal dibaca dari EXIF JPEG, PNG
+//! (`eXIf`), WebP, dan TIFF tanpa dependensi tambahan.
+
+use crate::ans::SAUCE_COMMENT_WIDTH;
+use crate::art::escape_html;
+use crate::{AsciiConfig, AsciiConverter, ColorMode, ConversionError, LineEnding, OutputFormat, SauceRecord};
+use image::DynamicImage;
+use std::fs;
+use std::path::Path;
+
+/// Tag TIFF yang dibaca dari EXIF.
+const TAG_DATE_TIME: u16 = 0x0132;
+const TAG_EXIF_IFD: u16 = 0x8769;
+const TAG_DATE_TIME_ORIGINAL: u16 = 0x9003;
+const TAG_DATE_TIME_DIGITIZED: u16 = 0x9004;
+
+/// Metadata sumber sebuah konversi.
+///
+/// * `file_name` - Nama file sumber tanpa direktori, jika diketahui
+/// * `width` - Lebar gambar sumber dalam piksel (setelah didekode)
+/// * `height` - Tinggi gambar sumber dalam piksel
+/// * `captured` - Tanggal pengambilan dari EXIF (`YYYY-MM-DD HH:MM:SS`), jika ada
+/// * `settings` - Ringkasan pengaturan utama yang dapat dibaca (ukuran, charset, format, warna)
+/// * `config_fingerprint` - Sidik jari konfigurasi (`AsciiConfig::fingerprint`)
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, SourceMetadata};
+/// use image::DynamicImage;
+///
+/// let config = AsciiConfig::default();
+/// let metadata = SourceMetadata::from_image(&DynamicImage::new_rgb8(640, 480), None, Some("foto/pantai.png"), &config);
+/// assert_eq!(metadata.file_name.as_deref(), Some("pantai.png"));
+/// assert_eq!(metadata.settings, "size=100x50 charset=standard format=text color=off");
+/// assert!(metadata.text_header().starts_with(
+///     "# source: pantai.png\n# dimensions: 640x480\n# settings: size=100x50 charset=standard format=text color=off\n# config: "
+/// ));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceMetadata {
+    pub file_name: Option<String>,
+    pub width: u32,
+    pub height: u32,
+    pub captured: Option<String>,
+    pub settings: String,
+    pub config_fingerprint: u64,
+}
+
+impl SourceMetadata {
+    /// Menyusun metadata dari gambar yang sudah didekode.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Gambar sumber
+    /// * `encoded` - Bytes file asli untuk membaca tanggal EXIF (`None` jika tidak tersedia)
+    /// * `path` - Path atau nama file sumber; hanya nama filenya yang disimpan
+    /// * `config` - Konfigurasi yang dipakai untuk konversi
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConfig, AsciiConverter, SourceMetadata};
+    /// use image::{DynamicImage, ImageOutputFormat};
+    /// use std::io::Cursor;
+    ///
+    /// // JPEG dengan segmen EXIF berisi DateTime
+    /// let image = DynamicImage::new_rgb8(16, 8);
+    /// let mut encoded = Vec::new();
+    /// image.write_to(&mut Cursor::new(&mut encoded), ImageOutputFormat::Jpeg(90)).unwrap();
+    /// let mut tiff = b"II*\0\x08\0\0\0\x01\0\x32\x01\x02\0\x14\0\0\0\x1a\0\0\0\0\0\0\0".to_vec();
+    /// tiff.extend_from_slice(b"2023:08:17 07:30:00\0");
+    /// let mut app1 = vec![0xFF, 0xE1, 0, (2 + 6 + tiff.len()) as u8];
+    /// app1.extend_from_slice(b"Exif\0\0");
+    /// app1.extend_from_slice(&tiff);
+    /// encoded.splice(2..2, app1);
+    ///
+    /// let config = AsciiConfig { width: 4, height: 2, embed_metadata: true, ..Default::default() };
+    /// let metadata = SourceMetadata::from_image(&image, Some(&encoded), None, &config);
+    /// assert_eq!(metadata.captured.as_deref(), Some("2023-08-17 07:30:00"));
+    ///
+    /// let ascii = AsciiConverter::with_config(config).convert_from_bytes(&encoded).unwrap();
+    /// assert!(ascii.starts_with("# dimensions: 16x8\n# captured: 2023-08-17 07:30:00\n# settings: size=4x2 "));
+    /// assert!(ascii.ends_with("@@@@\n@@@@\n"));
+    /// ```
+    pub fn from_image(image: &DynamicImage, encoded: Option<&[u8]>, path: Option<&str>, config: &AsciiConfig) -> Self {
+        Self {
+            file_name: path
+                .and_then(|path| Path::new(path).file_name())
+                .map(|name| name.to_string_lossy().into_owned()),
+            width: image.width(),
+            height: image.height(),
+            captured: encoded.and_then(exif_capture_date),
+            settings: settings_summary(config),
+            config_fingerprint: config.fingerprint(),
+        }
+    }
+
+    /// Pasangan nama dan nilai metadata yang tersedia, dalam urutan tampil.
+    fn fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = Vec::new();
+        if let Some(name) = &self.file_name {
+            fields.push(("source", name.clone()));
+        }
+        fields.push(("dimensions", format!("{}x{}", self.width, self.height)));
+        if let Some(captured) = &self.captured {
+            fields.push(("captured", captured.clone()));
+        }
+        fields.push(("settings", self.settings.clone()));
+        fields.push(("config", format!("{:016x}", self.config_fingerprint)));
+        fields
+    }
+
+    /// Header komentar untuk output teks, satu baris `# nama: nilai` per field.
+    pub fn text_header(&self) -> String {
+        self.text_header_with(LineEnding::Lf)
+    }
+
+    /// Seperti `text_header`, dengan pemisah baris tertentu.
+    pub fn text_header_with(&self, line_ending: LineEnding) -> String {
+        self.fields()
+            .into_iter()
+            .map(|(name, value)| format!("# {}: {}{}", name, value, line_ending.as_str()))
+            .collect()
+    }
+
+    /// Tag `<meta name="aspix:...">` untuk disisipkan di dalam `<head>`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::SourceMetadata;
+    ///
+    /// let metadata = SourceMetadata {
+    ///     file_name: Some("a&b.jpg".to_string()),
+    ///     width: 8,
+    ///     height: 4,
+    ///     captured: None,
+    ///     settings: "size=8x4 charset=standard format=html color=source".to_string(),
+    ///     config_fingerprint: 1,
+    /// };
+    /// assert!(metadata.html_meta_tags().starts_with("<meta name=\"aspix:source\" content=\"a&amp;b.jpg\">\n"));
+    /// ```
+    pub fn html_meta_tags(&self) -> String {
+        self.fields()
+            .into_iter()
+            .map(|(name, value)| format!("<meta name=\"aspix:{}\" content=\"{}\">\n", name, escape_html(&value)))
+            .collect()
+    }
+
+    /// Melengkapi record SAUCE: judul dari nama file dan tanggal dari EXIF jika
+    /// belum diisi, serta metadata sebagai baris komentar. Field yang melebihi
+    /// lebar komentar dilanjutkan ke baris berikutnya dengan indentasi dua spasi.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, SauceRecord, SourceMetadata};
+    ///
+    /// let metadata = SourceMetadata {
+    ///     file_name: Some("kota.jpg".to_string()),
+    ///     width: 640,
+    ///     height: 480,
+    ///     captured: Some("2023-08-17 07:30:00".to_string()),
+    ///     settings: "size=80x25 charset=detailed format=ansi color=source".to_string(),
+    ///     config_fingerprint: 0xff,
+    /// };
+    /// let sauce = metadata.sauce(&SauceRecord { author: "fanny".to_string(), ..Default::default() });
+    /// assert_eq!((sauce.title.as_str(), sauce.author.as_str()), ("kota.jpg", "fanny"));
+    /// assert_eq!(sauce.date.as_deref(), Some("20230817"));
+    /// assert_eq!(sauce.comments[1], "dimensions: 640x480");
+    /// assert_eq!(sauce.comments[3], "settings: size=80x25 charset=detailed format=ansi color=source");
+    ///
+    /// // Komentar ditulis sebagai blok COMNT 64 byte per baris sebelum record SAUCE
+    /// let cell = Cell { ch: '#', brightness: 0.5, color: [170, 170, 170] };
+    /// let ans = AsciiArt::new(1, 1, vec![cell]).unwrap().to_ans(&sauce);
+    /// let record = ans.len() - 128;
+    /// assert_eq!(ans[record + 104], 5);
+    /// assert_eq!(&ans[record - 5 - 5 * 64..][..5], b"COMNT");
+    /// ```
+    pub fn sauce(&self, base: &SauceRecord) -> SauceRecord {
+        let mut sauce = base.clone();
+        if sauce.title.is_empty() {
+            sauce.title = self.file_name.clone().unwrap_or_default();
+        }
+        if sauce.date.is_none() {
+            sauce.date = self.captured.as_ref().and_then(|captured| captured.get(..10)).map(|date| date.replace('-', ""));
+        }
+        for (name, value) in self.fields() {
+            let mut line = format!("{}:", name);
+            for word in value.split(' ') {
+                if line.len() + 1 + word.len() > SAUCE_COMMENT_WIDTH && line.len() > name.len() + 1 {
+                    sauce.comments.push(std::mem::replace(&mut line, " ".to_string()));
+                }
+                line.push(' ');
+                line.push_str(word);
+            }
+            sauce.comments.push(line);
+        }
+        sauce
+    }
+
+    /// Menyematkan metadata ke output sesuai format: tag `<meta>` untuk HTML, header
+    /// komentar untuk format teks lainnya.
+    pub(crate) fn embed(&self, output: String, config: &AsciiConfig) -> String {
+        let html = config.use_color && matches!(config.output_format, OutputFormat::Html | OutputFormat::HtmlTable);
+        if html {
+            output.replacen("<head>\n", &format!("<head>\n{}", self.html_meta_tags()), 1)
+        } else {
+            self.text_header_with(config.line_ending) + &output
+        }
+    }
+}
+
+impl AsciiConverter {
+    /// Membaca metadata sumber dari file gambar tanpa mengkonversinya.
+    ///
+    /// Dimensi dibaca dari header gambar; format tanpa header dimensi yang
+    /// dikenali crate `image` (SVG, HEIF) menghasilkan error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiConverter;
+    ///
+    /// let converter = AsciiConverter::new(80, 40);
+    /// if let Ok(metadata) = converter.source_metadata("liburan.jpg") {
+    ///     println!("Diambil pada {:?}", metadata.captured);
+    /// }
+    /// ```
+    pub fn source_metadata(&self, image_path: &str) -> Result<SourceMetadata, String> {
+        let bytes = fs::read(image_path).map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+        let (width, height) = image::io::Reader::new(std::io::Cursor::new(&bytes))
+            .with_guessed_format()
+            .map_err(|e| format!("Gagal membaca header gambar: {}", e))?
+            .into_dimensions()
+            .map_err(|e| format!("Gagal membaca dimensi gambar: {}", e))?;
+        Ok(SourceMetadata {
+            file_name: Path::new(image_path)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            width,
+            height,
+            captured: exif_capture_date(&bytes),
+            settings: settings_summary(self.config()),
+            config_fingerprint: self.config().fingerprint(),
+        })
+    }
+
+    /// Membuka gambar beserta metadata sumbernya jika `embed_metadata` aktif.
+    ///
+    /// File hanya dibaca sekali: bytes yang sama dipakai untuk decode dan untuk EXIF.
+    pub(crate) fn open_with_metadata(&self, image_path: &str) -> Result<(DynamicImage, Option<SourceMetadata>), ConversionError> {
+        if !self.config().embed_metadata {
+            return Ok((self.open_image(image_path)?, None));
+        }
+        let (image, encoded) = self.open_image_bytes(image_path)?;
+        let metadata = SourceMetadata::from_image(&image, Some(&encoded), Some(image_path), self.config());
+        Ok((image, Some(metadata)))
+    }
+}
+
+/// Ringkasan pengaturan utama: `size=WxH charset=... format=... color=...`.
+///
+/// Charset mengikuti urutan prioritas pemilihan ramp karakter; format `text`
+/// dan warna `off` berarti output teks biasa tanpa warna.
+fn settings_summary(config: &AsciiConfig) -> String {
+    let charset = match &config.custom_chars {
+        Some(custom) if !custom.is_empty() => "custom",
+        _ if config.use_color && config.use_detailed_chars => "detailed",
+        _ if config.use_high_density => "high-density",
+        _ if config.use_detailed_chars => "detailed",
+        _ => "standard",
+    };
+    let (format, color) = if config.use_color {
+        let format = match config.output_format {
+            OutputFormat::Html => "html",
+            OutputFormat::HtmlTable => "html-table",
+            OutputFormat::Ansi => "ansi",
+            OutputFormat::AnsiBackground => "ansi-background",
+            OutputFormat::Emoji => "emoji",
+        };
+        let color = match config.color_mode {
+            ColorMode::Source => "source",
+            ColorMode::Duotone { .. } => "duotone",
+            ColorMode::Tint(_) => "tint",
+        };
+        (format, color)
+    } else {
+        ("text", "off")
+    };
+    format!("size={}x{} charset={} format={} color={}", config.width, config.height, charset, format, color)
+}
+
+/// Tanggal pengambilan dari EXIF: `DateTimeOriginal`, `DateTimeDigitized`, lalu `DateTime`.
+///
+/// Mengembalikan `YYYY-MM-DD HH:MM:SS`, atau `None` jika tidak ada EXIF atau tanggal tidak valid.
+pub(crate) fn exif_capture_date(bytes: &[u8]) -> Option<String> {
+    let tiff = exif_payload(bytes)?;
+    let big_endian = match tiff.get(..4)? {
+        b"II*\0" => false,
+        b"MM\0*" => true,
+        _ => return None,
+    };
+    let reader = TiffReader { data: tiff, big_endian };
+
+    let ifd0 = reader.u32(4)? as usize;
+    let exif_ifd = reader.find_entry(ifd0, TAG_EXIF_IFD).and_then(|entry| reader.u32(entry + 8));
+    [TAG_DATE_TIME_ORIGINAL, TAG_DATE_TIME_DIGITIZED]
+        .iter()
+        .filter_map(|&tag| reader.find_entry(exif_ifd? as usize, tag))
+        .chain(reader.find_entry(ifd0, TAG_DATE_TIME))
+        .find_map(|entry| reader.ascii_date(entry))
+}
+
+/// Mencari data EXIF (dimulai header TIFF) di dalam file JPEG, PNG, WebP, atau TIFF.
+fn exif_payload(bytes: &[u8]) -> Option<&[u8]> {
+    if bytes.starts_with(b"II*\0") || bytes.starts_with(b"MM\0*") {
+        return Some(bytes);
+    }
+
+    if bytes.starts_with(&[0xFF, 0xD8]) {
+        // Segmen JPEG: FF xx, panjang big-endian termasuk dua byte panjang itu sendiri
+        let mut offset = 2;
+        while let (Some(&0xFF), Some(&marker)) = (bytes.get(offset), bytes.get(offset + 1)) {
+            if marker == 0xDA || marker == 0xD9 {
+                break;
+            }
+            let length = u16::from_be_bytes([*bytes.get(offset + 2)?, *bytes.get(offset + 3)?]) as usize;
+            let segment = bytes.get(offset + 4..offset + 2 + length)?;
+            if marker == 0xE1 && segment.starts_with(b"Exif\0\0") {
+                return Some(&segment[6..]);
+            }
+            offset += 2 + length;
+        }
+        return None;
+    }
+
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        let mut offset = 8;
+        while let Some(header) = bytes.get(offset..offset + 8) {
+            let length = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+            let data = bytes.get(offset + 8..offset + 8 + length)?;
+            match &header[4..] {
+                b"eXIf" => return Some(data),
+                b"IEND" => break,
+                _ => {}
+            }
+            offset += 12 + length;
+        }
+        return None;
+    }
+
+    if bytes.len() >= 12 && &bytes[..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        let mut offset = 12;
+        while let Some(header) = bytes.get(offset..offset + 8) {
+            let length = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+            let data = bytes.get(offset + 8..offset + 8 + length)?;
+            if &header[..4] == b"EXIF" {
+                return Some(data.strip_prefix(b"Exif\0\0").unwrap_or(data));
+            }
+            // Chunk RIFF diisi hingga panjang genap
+            offset += 8 + length + length % 2;
+        }
+    }
+    None
+}
+
+/// Pembaca struktur TIFF dengan urutan byte dari header.
+struct TiffReader<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl TiffReader<'_> {
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let bytes = [*self.data.get(offset)?, *self.data.get(offset + 1)?];
+        Some(if self.big_endian { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) })
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self.data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if self.big_endian { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) })
+    }
+
+    /// Offset entry 12 byte dengan `tag` pada IFD di `ifd`.
+    fn find_entry(&self, ifd: usize, tag: u16) -> Option<usize> {
+        let count = self.u16(ifd)? as usize;
+        (0..count)
+            .map(|i| ifd + 2 + i * 12)
+            .find(|&entry| self.u16(entry) == Some(tag))
+    }
+
+    /// Nilai ASCII entry sebagai tanggal `YYYY-MM-DD HH:MM:SS`.
+    fn ascii_date(&self, entry: usize) -> Option<String> {
+        // Tipe 2 = ASCII; tanggal EXIF selalu 20 byte sehingga disimpan di offset
+        if self.u16(entry + 2)? != 2 || self.u32(entry + 4)? < 19 {
+            return None;
+        }
+        let offset = self.u32(entry + 8)? as usize;
+        let text = std::str::from_utf8(self.data.get(offset..offset + 19)?).ok()?;
+        let valid = text.char_indices().all(|(i, ch)| match i {
+            4 | 7 => ch == ':',
+            10 => ch == ' ',
+            13 | 16 => ch == ':',
+            _ => ch.is_ascii_digit(),
+        });
+        // Kamera tanpa jam yang disetel menulis tanggal nol
+        if !valid || text.starts_with("0000") {
+            return None;
+        }
+        Some(format!("{} {}", text[..10].replace(':', "-"), &text[11..]))
+    }
+}
@@ -0,0 +1,199 @@
+//! Montage (contact sheet): beberapa gambar disusun dalam grid lalu dikonversi sekaligus.
+
+use crate::theme::text_color;
+use crate::{AsciiArt, AsciiConverter, Cell, Orientation};
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, Rgba, RgbaImage};
+use std::path::Path;
+
+/// Opsi susunan montage.
+///
+/// * `columns` - Jumlah tile per baris
+/// * `cell_size` - Sisi tile persegi pada gambar gabungan (piksel); setiap gambar
+///   diperkecil agar muat dengan rasio aspek tetap lalu diletakkan di tengah
+/// * `labels` - Tambahkan satu baris nama file (tanpa ekstensi) di bawah setiap tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MontageOptions {
+    pub columns: u32,
+    pub cell_size: u32,
+    pub labels: bool,
+}
+
+impl Default for MontageOptions {
+    /// Membuat opsi default:
+    /// * columns: 4
+    /// * cell_size: 256
+    /// * labels: true
+    fn default() -> Self {
+        Self {
+            columns: 4,
+            cell_size: 256,
+            labels: true,
+        }
+    }
+}
+
+impl AsciiConverter {
+    /// Menyusun beberapa gambar menjadi montage lalu mengkonversinya dalam satu kali proses.
+    ///
+    /// Setara `convert_montage_with` tanpa label.
+    ///
+    /// # Arguments
+    ///
+    /// * `paths` - Path gambar, disusun baris demi baris
+    /// * `columns` - Jumlah tile per baris
+    /// * `cell_size` - Sisi tile persegi dalam piksel
+    pub fn convert_montage(&self, paths: &[&str], columns: u32, cell_size: u32) -> Result<String, String> {
+        self.convert_montage_with(paths, &MontageOptions { columns, cell_size, labels: false })
+    }
+
+    /// Menyusun beberapa gambar menjadi montage sesuai opsi lalu mengkonversinya.
+    ///
+    /// `width` dan `height` konfigurasi adalah ukuran seluruh montage; keduanya
+    /// dibulatkan ke bawah ke kelipatan jumlah kolom dan baris tile agar setiap
+    /// tile menempati jumlah sel yang sama. Baris label tidak termasuk `height`.
+    /// Area kosong di sekitar gambar di-render sebagai spasi. Orientasi, kanvas,
+    /// dan efek akhir berlaku untuk montage utuh termasuk labelnya, dan gambar
+    /// gabungan ikut dibatasi `limits.max_pixels`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - Montage yang sudah di-render sesuai konfigurasi
+    /// * `Err(String)` - Pesan error jika daftar kosong, ukuran tidak valid, atau gambar gagal dibuka
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, MontageOptions};
+    /// use image::{GrayImage, Luma};
+    ///
+    /// let dir = std::env::temp_dir();
+    /// let dark = dir.join("aspix_montage_dark.png");
+    /// let light = dir.join("aspix_montage_light.png");
+    /// GrayImage::from_pixel(8, 8, Luma([0])).save(&dark).unwrap();
+    /// GrayImage::from_pixel(8, 8, Luma([255])).save(&light).unwrap();
+    ///
+    /// let converter = AsciiConverter::new(8, 2);
+    /// let paths = [dark.to_str().unwrap(), light.to_str().unwrap()];
+    /// let options = MontageOptions { columns: 2, cell_size: 8, labels: true };
+    /// let montage = converter.convert_montage_with(&paths, &options).unwrap();
+    /// let lines: Vec<&str> = montage.lines().collect();
+    /// assert_eq!(lines.len(), 3);
+    /// assert!(lines[0].starts_with("@@@") && lines[0].ends_with(' '));
+    /// assert_eq!(lines[2], "asp…asp…");
+    /// ```
+    pub fn convert_montage_with(&self, paths: &[&str], options: &MontageOptions) -> Result<String, String> {
+        let art = self.montage_art(paths, options)?;
+        Ok(self.render_art(&art))
+    }
+
+    /// Membuat grid montage sebelum di-render.
+    fn montage_art(&self, paths: &[&str], options: &MontageOptions) -> Result<AsciiArt, String> {
+        if paths.is_empty() {
+            return Err("Montage membutuhkan minimal satu gambar".to_string());
+        }
+        if options.columns == 0 || options.cell_size == 0 {
+            return Err("Jumlah kolom dan ukuran tile montage harus lebih besar dari 0".to_string());
+        }
+
+        let columns = options.columns.min(paths.len() as u32);
+        let rows = (paths.len() as u32).div_ceil(columns);
+        let tile_columns = self.config().width / columns;
+        let tile_rows = self.config().height / rows;
+        if tile_columns == 0 || tile_rows == 0 {
+            return Err(format!(
+                "Ukuran output {}x{} terlalu kecil untuk montage {}x{} tile",
+                self.config().width,
+                self.config().height,
+                columns,
+                rows
+            ));
+        }
+
+        let size = options.cell_size;
+        let (Some(composite_width), Some(composite_height)) = (columns.checked_mul(size), rows.checked_mul(size)) else {
+            return Err("Ukuran gambar gabungan montage terlalu besar".to_string());
+        };
+        self.config().limits.check_dimensions(composite_width, composite_height)?;
+
+        // Isi area kosong dengan warna yang dipetakan ke spasi
+        let fill = if self.config().inverted() { 0 } else { 255 };
+        let mut composite = RgbaImage::from_pixel(composite_width, composite_height, Rgba([fill, fill, fill, 255]));
+        for (i, path) in paths.iter().enumerate() {
+            let img = self.open_image(path)?;
+            let fitted = img.resize(size, size, FilterType::Triangle).to_rgba8();
+            let x = (i as u32 % columns) * size + (size - fitted.width()) / 2;
+            let y = (i as u32 / columns) * size + (size - fitted.height()) / 2;
+            imageops::overlay(&mut composite, &fitted, x as i64, y as i64);
+        }
+
+        // Orientasi, kanvas, dan efek akhir diterapkan setelah label disisipkan
+        // agar posisi label dihitung dari grid tile yang belum diubah
+        let mut converter = AsciiConverter::with_config(crate::AsciiConfig {
+            width: tile_columns * columns,
+            height: tile_rows * rows,
+            orientation: Orientation::Normal,
+            canvas: None,
+            gradient: None,
+            crt: None,
+            retro: None,
+            ..self.config().clone()
+        });
+        converter.on_cell = self.on_cell.clone();
+        let art = converter.convert_image_to_art(&DynamicImage::ImageRgba8(composite))?;
+        if !options.labels {
+            return Ok(self.lay_out_art(art));
+        }
+
+        // Sisipkan satu baris label setelah setiap baris tile, berwarna kontras dengan latar tema
+        let label_color = text_color(self.config().theme);
+        let art_rows: Vec<&[Cell]> = art.rows().collect();
+        let rows_per_tile = (art.height() / rows).max(1) as usize;
+        let tile_width = (art.width() / columns) as usize;
+        let mut cells = Vec::new();
+        for (tile_row, chunk) in art_rows.chunks(rows_per_tile).enumerate() {
+            for row in chunk {
+                cells.extend_from_slice(row);
+            }
+            let mut label_row = vec![label_cell(' ', label_color); art.width() as usize];
+            for column in 0..columns as usize {
+                let Some(path) = paths.get(tile_row * columns as usize + column) else {
+                    break;
+                };
+                let label = fit_label(&file_label(path), tile_width);
+                let offset = column * tile_width + (tile_width - label.len()) / 2;
+                for (i, &ch) in label.iter().enumerate() {
+                    label_row[offset + i] = label_cell(ch, label_color);
+                }
+            }
+            cells.extend(label_row);
+        }
+        let height = (cells.len() / art.width().max(1) as usize) as u32;
+        AsciiArt::new(art.width(), height, cells).map(|art| self.lay_out_art(art))
+    }
+}
+
+/// Nama file tanpa ekstensi sebagai label tile.
+fn file_label(path: &str) -> String {
+    Path::new(path)
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// Memotong label agar muat di lebar tile, diakhiri `…` jika terpotong.
+fn fit_label(label: &str, width: usize) -> Vec<char> {
+    let chars: Vec<char> = label.chars().collect();
+    if chars.len() <= width {
+        return chars;
+    }
+    let mut fitted: Vec<char> = chars[..width.saturating_sub(1)].to_vec();
+    fitted.push('…');
+    fitted.truncate(width);
+    fitted
+}
+
+/// Sel teks label berwarna `color`.
+fn label_cell(ch: char, color: [u8; 3]) -> Cell {
+    Cell { ch, brightness: 1.0, color }
+}
@@ -0,0 +1,222 @@
+//! Pembuat banner login/MOTD dari gambar dan baris teks bertemplate.
+
+use crate::{AsciiConfig, AsciiConverter, LineEnding, ResourceLimits};
+use image::DynamicImage;
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Aturan escape output banner sesuai cara banner ditampilkan.
+///
+/// * `Raw` - Karakter ESC apa adanya, untuk `/etc/motd` yang ditampilkan dengan `cat` atau pam_motd
+/// * `Echo` - Untuk `echo -e` atau `printf '%b'`: backslash digandakan dan ESC ditulis `\033`
+/// * `Printf` - Seperti `Echo`, ditambah `%` ditulis `%%` agar aman sebagai format string `printf`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MotdEscape {
+    #[default]
+    Raw,
+    Echo,
+    Printf,
+}
+
+/// Builder banner MOTD.
+///
+/// Baris teks dapat memuat placeholder `{hostname}`, `{date}` (YYYY-MM-DD, UTC),
+/// `{time}` (HH:MM, UTC), serta variabel sendiri dari `with_variable`.
+/// Baris yang lebih lebar dari `width` dipotong.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::Motd;
+/// use image::{DynamicImage, GrayImage, Luma};
+///
+/// let logo = DynamicImage::ImageLuma8(GrayImage::from_fn(40, 20, |x, _| Luma([(x * 6) as u8])));
+/// let banner = Motd::new(logo)
+///     .with_width(20)
+///     .with_line("Server {team}")
+///     .with_line("Host: {hostname}")
+///     .with_variable("team", "infra")
+///     .render()
+///     .unwrap();
+///
+/// assert!(banner.lines().all(|line| line.chars().count() <= 20));
+/// assert!(banner.contains("Server infra"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Motd {
+    image: DynamicImage,
+    width: u32,
+    color: bool,
+    escape: MotdEscape,
+    lines: Vec<String>,
+    variables: Vec<(String, String)>,
+}
+
+impl Motd {
+    /// Membuat banner dari gambar dengan lebar 60 kolom, tanpa warna.
+    pub fn new(image: DynamicImage) -> Self {
+        Self {
+            image,
+            width: 60,
+            color: false,
+            escape: MotdEscape::Raw,
+            lines: Vec::new(),
+            variables: Vec::new(),
+        }
+    }
+
+    /// Membuka gambar logo dari path.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Motd)` - Builder banner
+    /// * `Err(String)` - Pesan error jika gambar gagal dibuka
+    pub fn open(image_path: &str) -> Result<Self, String> {
+        Self::open_with_limits(image_path, &ResourceLimits::default())
+    }
+
+    /// Membuka gambar logo dari path dengan batas sumber daya.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Motd)` - Builder banner
+    /// * `Err(String)` - Pesan error jika gambar gagal dibuka atau melampaui `limits`
+    pub fn open_with_limits(image_path: &str, limits: &ResourceLimits) -> Result<Self, String> {
+        let image = limits.open(Path::new(image_path))?;
+        Ok(Self::new(image))
+    }
+
+    /// Mengatur lebar maksimum banner dalam kolom (minimal 1).
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = width.max(1);
+        self
+    }
+
+    /// Mengaktifkan warna ANSI 24-bit pada gambar.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Mengatur aturan escape output.
+    pub fn with_escape(mut self, escape: MotdEscape) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Menambahkan satu baris teks di bawah gambar.
+    pub fn with_line(mut self, template: &str) -> Self {
+        self.lines.push(template.to_string());
+        self
+    }
+
+    /// Menambahkan variabel template `{name}`; menimpa placeholder bawaan dengan nama sama.
+    pub fn with_variable(mut self, name: &str, value: &str) -> Self {
+        self.variables.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Membuat banner siap pasang.
+    ///
+    /// Tinggi gambar mengikuti rasio aspek sumber dengan asumsi tinggi sel dua
+    /// kali lebarnya; baris teks diratakan ke tengah selebar gambar.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - Banner dengan newline di setiap akhir baris
+    /// * `Err(String)` - Pesan error jika konversi gagal
+    pub fn render(&self) -> Result<String, String> {
+        let aspect = self.image.height() as f32 / self.image.width().max(1) as f32;
+        let config = AsciiConfig {
+            width: self.width,
+            height: ((self.width as f32 * aspect / 2.0).round() as u32).max(1),
+            use_color: self.color,
+            ..Default::default()
+        };
+        let converter = AsciiConverter::with_config(config);
+        let art = converter.convert_image_to_art(&self.image)?;
+
+        let mut banner = if self.color {
+            art.to_ansi()
+        } else {
+            art.to_text_with(LineEnding::Lf, true)
+        };
+
+        let width = self.width as usize;
+        for template in &self.lines {
+            let line: String = self.expand(template).chars().take(width).collect();
+            let padding = (width - line.chars().count()) / 2;
+            banner.push_str(&" ".repeat(padding));
+            banner.push_str(&line);
+            banner.push('\n');
+        }
+
+        Ok(escape(&banner, self.escape))
+    }
+
+    /// Mengganti placeholder pada satu baris template.
+    fn expand(&self, template: &str) -> String {
+        let (date, time) = utc_date_time();
+        let mut line = template.to_string();
+        for (name, value) in &self.variables {
+            line = line.replace(&format!("{{{}}}", name), value);
+        }
+        line.replace("{hostname}", &hostname())
+            .replace("{date}", &date)
+            .replace("{time}", &time)
+    }
+}
+
+/// Nama host dari `/etc/hostname`, variabel `HOSTNAME`, atau `localhost`.
+fn hostname() -> String {
+    fs::read_to_string("/etc/hostname")
+        .ok()
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .unwrap_or_else(|| "localhost".to_string())
+}
+
+/// Tanggal (YYYY-MM-DD) dan jam (HH:MM) UTC saat ini.
+pub(crate) fn utc_date_time() -> (String, String) {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let days = (seconds / 86_400) as i64;
+    let minutes = seconds % 86_400 / 60;
+
+    // Konversi hari sejak epoch ke tanggal kalender Gregorian (algoritma civil_from_days)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (
+        format!("{:04}-{:02}-{:02}", year, month, day),
+        format!("{:02}:{:02}", minutes / 60, minutes % 60),
+    )
+}
+
+/// Menerapkan aturan escape pada banner.
+fn escape(banner: &str, rule: MotdEscape) -> String {
+    if rule == MotdEscape::Raw {
+        return banner.to_string();
+    }
+    let mut escaped = String::with_capacity(banner.len());
+    for ch in banner.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '\x1b' => escaped.push_str("\\033"),
+            '%' if rule == MotdEscape::Printf => escaped.push_str("%%"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
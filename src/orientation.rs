@@ -0,0 +1,89 @@
+//! Rotasi grid hasil konversi untuk layar vertikal atau banner menyamping.
+
+use crate::converter::QUADRANT_CHARS;
+use crate::{AsciiArt, Cell};
+
+/// Orientasi output pada tingkat grid sel.
+///
+/// Berbeda dengan `Rotation` yang memutar gambar sumber, orientasi memutar
+/// grid yang sudah jadi sehingga dapat diterapkan tanpa konversi ulang.
+///
+/// * `Normal` - Tanpa rotasi
+/// * `Rotate90` - Diputar 90 derajat searah jarum jam
+/// * `Rotate270` - Diputar 270 derajat searah jarum jam (90 derajat berlawanan)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    #[default]
+    Normal,
+    Rotate90,
+    Rotate270,
+}
+
+impl AsciiArt {
+    /// Memutar grid sesuai orientasi.
+    ///
+    /// Glyph berarah ikut diputar: blok quadrant dipetakan ke pola yang
+    /// sesuai, `-` dan `|` saling bertukar, begitu pula `/` dan `\`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, Orientation};
+    ///
+    /// let cell = |ch| Cell { ch, brightness: 0.5, color: [0, 0, 0] };
+    /// let art = AsciiArt::new(3, 1, vec![cell('a'), cell('-'), cell('▀')]).unwrap();
+    /// assert_eq!(art.oriented(Orientation::Rotate90).to_text(), "a\n|\n▐\n");
+    /// assert_eq!(art.oriented(Orientation::Rotate270).to_text(), "▌\n|\na\n");
+    /// ```
+    pub fn oriented(&self, orientation: Orientation) -> AsciiArt {
+        let (width, height) = (self.width(), self.height());
+        let cells: Vec<Cell> = match orientation {
+            Orientation::Normal => return self.clone(),
+            Orientation::Rotate90 => (0..width)
+                .flat_map(|y| (0..height).map(move |x| (x, y)))
+                .map(|(x, y)| rotate_cell(self.cells()[((height - 1 - x) * width + y) as usize], true))
+                .collect(),
+            Orientation::Rotate270 => (0..width)
+                .flat_map(|y| (0..height).map(move |x| (x, y)))
+                .map(|(x, y)| rotate_cell(self.cells()[(x * width + (width - 1 - y)) as usize], false))
+                .collect(),
+        };
+
+        AsciiArt::new(height, width, cells).expect("jumlah sel tidak berubah saat rotasi")
+    }
+}
+
+/// Memutar glyph berarah 90 derajat (`clockwise`) atau -90 derajat.
+fn rotate_cell(cell: Cell, clockwise: bool) -> Cell {
+    let ch = match cell.ch {
+        '-' => '|',
+        '|' => '-',
+        '/' => '\\',
+        '\\' => '/',
+        '─' => '│',
+        '│' => '─',
+        '╱' => '╲',
+        '╲' => '╱',
+        '┌' => if clockwise { '┐' } else { '└' },
+        '┐' => if clockwise { '┘' } else { '┌' },
+        '┘' => if clockwise { '└' } else { '┐' },
+        '└' => if clockwise { '┌' } else { '┘' },
+        ch => match QUADRANT_CHARS.iter().position(|&quadrant| quadrant == ch) {
+            Some(mask) => QUADRANT_CHARS[rotate_mask(mask, clockwise)],
+            None => ch,
+        },
+    };
+    Cell { ch, ..cell }
+}
+
+/// Memutar bitmask quadrant (TL=1, TR=2, BL=4, BR=8).
+fn rotate_mask(mask: usize, clockwise: bool) -> usize {
+    // Urutan searah jarum jam: TL → TR → BR → BL
+    const CYCLE: [usize; 4] = [1, 2, 8, 4];
+    CYCLE
+        .iter()
+        .enumerate()
+        .filter(|(_, &bit)| mask & bit != 0)
+        .map(|(i, _)| if clockwise { CYCLE[(i + 1) % 4] } else { CYCLE[(i + 3) % 4] })
+        .sum()
+}
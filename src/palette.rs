@@ -0,0 +1,151 @@
+//! Ekstraksi warna dominan dan kuantisasi warna median cut.
+//!
+//! Warna dominan gambar sumber dapat dipakai sebagai tema (misalnya warna latar
+//! halaman) atau, melalui `AsciiConfig::dominant_colors`, untuk mewarnai output
+//! hanya dengan N warna tersebut agar tampilannya kompak seperti poster.
+
+use image::imageops::FilterType;
+use image::DynamicImage;
+use std::collections::HashMap;
+
+/// Sisi terpanjang gambar yang dianalisis; gambar lebih besar diperkecil dulu agar cepat.
+const ANALYSIS_SIZE: u32 = 128;
+
+/// Satu warna dominan beserta porsinya.
+///
+/// * `color` - Warna RGB (rata-rata kelompok warna)
+/// * `share` - Porsi piksel gambar yang termasuk kelompok ini (0.0 - 1.0)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DominantColor {
+    pub color: [u8; 3],
+    pub share: f32,
+}
+
+/// Mengekstrak paling banyak `count` warna dominan gambar dengan median cut.
+///
+/// Hasil diurutkan dari porsi terbesar. Piksel transparan (alpha di bawah 128)
+/// diabaikan.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::palette;
+/// use image::{DynamicImage, Rgb, RgbImage};
+///
+/// let flag = RgbImage::from_fn(40, 30, |_, y| if y < 20 { Rgb([200, 16, 46]) } else { Rgb([255, 255, 255]) });
+/// let colors = palette::dominant_colors(&DynamicImage::ImageRgb8(flag), 2);
+/// assert_eq!(colors[0].color, [200, 16, 46]);
+/// assert_eq!(colors[1].color, [255, 255, 255]);
+/// assert!(colors[0].share > colors[1].share);
+/// ```
+pub fn dominant_colors(img: &DynamicImage, count: usize) -> Vec<DominantColor> {
+    let analyzed = if img.width().max(img.height()) > ANALYSIS_SIZE {
+        img.resize(ANALYSIS_SIZE, ANALYSIS_SIZE, FilterType::Triangle)
+    } else {
+        img.clone()
+    };
+    let colors: Vec<[u8; 3]> = analyzed
+        .to_rgba8()
+        .pixels()
+        .filter(|pixel| pixel[3] >= 128)
+        .map(|pixel| [pixel[0], pixel[1], pixel[2]])
+        .collect();
+    if colors.is_empty() || count == 0 {
+        return Vec::new();
+    }
+
+    let (palette, indices) = median_cut(&colors, count);
+    let mut population = vec![0usize; palette.len()];
+    for index in indices {
+        population[index] += 1;
+    }
+    let mut dominant: Vec<DominantColor> = palette
+        .into_iter()
+        .zip(population)
+        .map(|(color, pixels)| DominantColor { color, share: pixels as f32 / colors.len() as f32 })
+        .collect();
+    dominant.sort_by(|a, b| b.share.total_cmp(&a.share).then(a.color.cmp(&b.color)));
+    dominant
+}
+
+/// Mengelompokkan warna menjadi paling banyak `size` warna dengan median cut.
+///
+/// # Returns
+///
+/// Palet (rata-rata tertimbang setiap kelompok) dan indeks palet untuk
+/// setiap warna input, dalam urutan yang sama dengan input.
+pub(crate) fn median_cut(colors: &[[u8; 3]], size: usize) -> (Vec<[u8; 3]>, Vec<usize>) {
+    let mut counts: HashMap<[u8; 3], u64> = HashMap::new();
+    for &color in colors {
+        *counts.entry(color).or_default() += 1;
+    }
+    let mut unique: Vec<([u8; 3], u64)> = counts.into_iter().collect();
+    // Urutan deterministik agar hasil selalu sama untuk input yang sama
+    unique.sort_unstable();
+
+    let mut boxes = vec![unique];
+    while boxes.len() < size.max(1) {
+        // Pecah kotak dengan rentang kanal terlebar
+        let Some((index, channel)) = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, colors)| colors.len() > 1)
+            .map(|(i, colors)| {
+                let (channel, range) = widest_channel(colors);
+                (i, channel, range)
+            })
+            .max_by_key(|&(i, _, range)| (range, std::cmp::Reverse(i)))
+            .map(|(i, channel, _)| (i, channel))
+        else {
+            break;
+        };
+
+        let mut colors = boxes.swap_remove(index);
+        colors.sort_unstable_by_key(|(color, _)| color[channel]);
+        let total: u64 = colors.iter().map(|(_, count)| count).sum();
+        let mut seen = 0;
+        let split = colors
+            .iter()
+            .position(|(_, count)| {
+                seen += count;
+                seen * 2 >= total
+            })
+            .map_or(1, |i| i + 1)
+            .clamp(1, colors.len() - 1);
+        let upper = colors.split_off(split);
+        boxes.push(colors);
+        boxes.push(upper);
+    }
+
+    let mut lookup = HashMap::new();
+    let palette = boxes
+        .iter()
+        .enumerate()
+        .map(|(i, colors)| {
+            let total: u64 = colors.iter().map(|(_, count)| count).sum();
+            let mut sums = [0u64; 3];
+            for (color, count) in colors {
+                lookup.insert(*color, i);
+                for (sum, &channel) in sums.iter_mut().zip(color.iter()) {
+                    *sum += channel as u64 * count;
+                }
+            }
+            sums.map(|sum| (sum as f64 / total.max(1) as f64).round() as u8)
+        })
+        .collect();
+
+    let indices = colors.iter().map(|color| lookup[color]).collect();
+    (palette, indices)
+}
+
+/// Kanal dengan rentang nilai terbesar beserta rentangnya.
+fn widest_channel(colors: &[([u8; 3], u64)]) -> (usize, u8) {
+    (0..3)
+        .map(|channel| {
+            let min = colors.iter().map(|(color, _)| color[channel]).min().unwrap_or(0);
+            let max = colors.iter().map(|(color, _)| color[channel]).max().unwrap_or(0);
+            (channel, max - min)
+        })
+        .max_by_key(|&(channel, range)| (range, std::cmp::Reverse(channel)))
+        .unwrap_or((0, 0))
+}
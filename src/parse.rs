@@ -0,0 +1,279 @@
+//! Impor ASCII art yang sudah ada (teks biasa atau ANSI) kembali menjadi grid sel.
+//!
+//! Grid hasil parse dapat diedit, diwarnai ulang, diskalakan, lalu diekspor ke
+//! format lain seperti art hasil konversi gambar. Brightness setiap sel
+//! diperkirakan dari cakupan tinta glyph (`CoverageTable::builtin`).
+
+use crate::converter::color_luma;
+use crate::{cp437_char, AsciiArt, Cell, CoverageTable, VGA_PALETTE};
+use unicode_width::UnicodeWidthChar;
+
+/// Warna sel tanpa informasi warna (teks biasa atau foreground default ANSI).
+const DEFAULT_COLOR: [u8; 3] = [255, 255, 255];
+
+/// Lebar tab stop untuk karakter `\t`.
+const TAB_WIDTH: usize = 8;
+
+/// Tingkat komponen kubus warna 6×6×6 pada palet 256 warna xterm.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl AsciiArt {
+    /// Membuat grid dari ASCII art berupa teks biasa.
+    ///
+    /// Setiap baris teks menjadi satu baris grid; baris yang lebih pendek diisi spasi
+    /// hingga selebar baris terpanjang. Glyph selebar dua kolom diikuti satu sel spasi
+    /// (kebalikan dari penyelarasan pada `to_text_with`), tab diperluas ke kelipatan
+    /// 8 kolom, dan karakter kontrol lain diabaikan. Semua sel berwarna putih.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Isi art, dengan pemisah baris `\n` atau `\r\n`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiArt;
+    ///
+    /// let art = AsciiArt::parse_plain("@@.\r\n#\r\n");
+    /// assert_eq!((art.width(), art.height()), (3, 2));
+    /// assert_eq!(art.to_text(), "@@.\n#  \n");
+    /// assert!(art.get(0, 0).unwrap().brightness < art.get(2, 0).unwrap().brightness);
+    /// ```
+    pub fn parse_plain(text: &str) -> AsciiArt {
+        let mut grid = GridBuilder::new();
+        for ch in text.chars() {
+            grid.push(ch, DEFAULT_COLOR, None);
+        }
+        grid.finish()
+    }
+
+    /// Membuat grid dari ASCII art berwarna dengan escape ANSI.
+    ///
+    /// Mengenali kode SGR warna 16 warna (termasuk bold sebagai varian terang, seperti
+    /// output `to_ansi16`), 256 warna (`38;5;n`), dan truecolor (`38;2;r;g;b`), baik untuk
+    /// foreground maupun background. Spasi dengan warna latar ("photo mode") memakai
+    /// warna latar tersebut sebagai warna sel. Escape lain (posisi kursor, dll.)
+    /// diabaikan, dan parsing berhenti pada penanda EOF `0x1A`. Selebihnya sama
+    /// dengan `parse_plain`.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Isi art ANSI dalam UTF-8, misalnya hasil `to_ansi`; file `.ans`
+    ///   berkodekan CP437 dibaca dengan `parse_ans`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiArt;
+    ///
+    /// let art = AsciiArt::parse_ansi("\x1b[38;2;255;0;0m#\x1b[1;34m@\x1b[0m\n\x1b[48;5;21m \x1b[0m\n");
+    /// assert_eq!(art.to_text(), "#@\n  \n");
+    /// assert_eq!(art.get(0, 0).unwrap().color, [255, 0, 0]);
+    /// assert_eq!(art.get(1, 0).unwrap().color, [85, 85, 255]);
+    /// assert_eq!(art.get(0, 1).unwrap().color, [0, 0, 255]);
+    /// ```
+    pub fn parse_ansi(text: &str) -> AsciiArt {
+        let mut grid = GridBuilder::new();
+        let mut style = AnsiStyle::default();
+        let mut chars = text.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\x1a' => break,
+                '\x1b' if chars.peek() == Some(&'[') => {
+                    chars.next();
+                    let mut params = String::new();
+                    // Parameter CSI diakhiri satu byte final 0x40..=0x7E
+                    for ch in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&ch) {
+                            if ch == 'm' {
+                                style.apply_sgr(&params);
+                            }
+                            break;
+                        }
+                        params.push(ch);
+                    }
+                }
+                '\x1b' => {
+                    chars.next();
+                }
+                _ => grid.push(ch, style.foreground(), style.background),
+            }
+        }
+        grid.finish()
+    }
+
+    /// Membuat grid dari file ANSI art klasik (`.ans`) berkodekan CP437.
+    ///
+    /// Setiap byte didekode sebagai glyph CP437 (byte `0x80..=0xFF` menjadi glyph
+    /// seperti `█` atau `░`), lalu diparse seperti `parse_ansi`. Record SAUCE setelah
+    /// penanda EOF `0x1A` diabaikan, sehingga output `to_ans` dapat dibaca kembali.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Isi file `.ans`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiArt;
+    ///
+    /// let art = AsciiArt::parse_ans(b"\x1b[31m\xdb\xb0#\x1b[0m\r\n\x1aSAUCE00");
+    /// assert_eq!(art.to_text(), "█░#\n");
+    /// assert_eq!(art.get(0, 0).unwrap().color, [170, 0, 0]);
+    /// ```
+    pub fn parse_ans(bytes: &[u8]) -> AsciiArt {
+        let text: String = bytes.iter().map(|&byte| cp437_char(byte)).collect();
+        Self::parse_ansi(&text)
+    }
+}
+
+/// State warna SGR yang sedang aktif saat parsing ANSI.
+#[derive(Debug, Default)]
+struct AnsiStyle {
+    foreground: Option<Foreground>,
+    background: Option<[u8; 3]>,
+    bold: bool,
+}
+
+/// Warna foreground: indeks 16 warna (dapat dicerahkan oleh bold) atau warna pasti.
+#[derive(Debug, Clone, Copy)]
+enum Foreground {
+    Indexed(usize),
+    Rgb([u8; 3]),
+}
+
+impl AnsiStyle {
+    /// Warna foreground efektif; warna dasar 0-7 menjadi versi terang saat bold.
+    fn foreground(&self) -> [u8; 3] {
+        match self.foreground {
+            Some(Foreground::Indexed(index)) if self.bold && index < 8 => VGA_PALETTE[index + 8],
+            Some(Foreground::Indexed(index)) => VGA_PALETTE[index],
+            Some(Foreground::Rgb(color)) => color,
+            None => DEFAULT_COLOR,
+        }
+    }
+
+    /// Menerapkan parameter SGR (`ESC[...m`), mis. `"1;31"` atau `"38;2;255;0;0"`.
+    fn apply_sgr(&mut self, params: &str) {
+        // Parameter kosong atau tidak valid dianggap 0 (reset), seperti perilaku terminal
+        let codes: Vec<u32> = params.split(';').map(|code| code.parse().unwrap_or(0)).collect();
+        let mut codes = codes.into_iter();
+
+        while let Some(code) = codes.next() {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                22 => self.bold = false,
+                30..=37 => self.foreground = Some(Foreground::Indexed((code - 30) as usize)),
+                90..=97 => self.foreground = Some(Foreground::Indexed((code - 90) as usize + 8)),
+                39 => self.foreground = None,
+                40..=47 => self.background = Some(VGA_PALETTE[(code - 40) as usize]),
+                100..=107 => self.background = Some(VGA_PALETTE[(code - 100) as usize + 8]),
+                49 => self.background = None,
+                38 => {
+                    if let Some(color) = extended_color(&mut codes) {
+                        self.foreground = Some(Foreground::Rgb(color));
+                    }
+                }
+                48 => {
+                    if let Some(color) = extended_color(&mut codes) {
+                        self.background = Some(color);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Membaca warna lanjutan setelah kode 38/48: `5;n` (256 warna) atau `2;r;g;b` (truecolor).
+fn extended_color(codes: &mut impl Iterator<Item = u32>) -> Option<[u8; 3]> {
+    match codes.next()? {
+        5 => codes.next().map(|index| xterm_color(index.min(255) as u8)),
+        2 => {
+            let mut component = || codes.next().map(|value| value.min(255) as u8);
+            Some([component()?, component()?, component()?])
+        }
+        _ => None,
+    }
+}
+
+/// Warna RGB untuk indeks palet 256 warna xterm.
+fn xterm_color(index: u8) -> [u8; 3] {
+    match index {
+        0..=15 => VGA_PALETTE[index as usize],
+        16..=231 => {
+            let index = (index - 16) as usize;
+            [CUBE_LEVELS[index / 36], CUBE_LEVELS[index / 6 % 6], CUBE_LEVELS[index % 6]]
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+            [gray, gray, gray]
+        }
+    }
+}
+
+/// Penyusun grid baris demi baris dari aliran karakter.
+struct GridBuilder {
+    coverage: CoverageTable,
+    rows: Vec<Vec<Cell>>,
+    current: Vec<Cell>,
+}
+
+impl GridBuilder {
+    fn new() -> Self {
+        Self { coverage: CoverageTable::builtin(), rows: Vec::new(), current: Vec::new() }
+    }
+
+    /// Menambahkan satu karakter ke baris saat ini.
+    fn push(&mut self, ch: char, color: [u8; 3], background: Option<[u8; 3]>) {
+        match ch {
+            '\n' => self.rows.push(std::mem::take(&mut self.current)),
+            '\t' => {
+                let spaces = TAB_WIDTH - self.current.len() % TAB_WIDTH;
+                for _ in 0..spaces {
+                    self.current.push(self.cell(' ', color, background));
+                }
+            }
+            _ => match ch.width() {
+                Some(1) => self.current.push(self.cell(ch, color, background)),
+                Some(2) => {
+                    // Sel kedua menampung kolom yang tertutup glyph lebar
+                    self.current.push(self.cell(ch, color, background));
+                    self.current.push(self.cell(' ', color, background));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    /// Membuat sel dengan brightness dari cakupan glyph, atau dari warna latar untuk spasi berlatar.
+    fn cell(&self, ch: char, color: [u8; 3], background: Option<[u8; 3]>) -> Cell {
+        match background {
+            Some(background) if ch == ' ' => Cell { ch, brightness: color_luma(background), color: background },
+            _ => {
+                // Glyph yang tidak dikenal dianggap setengah padat
+                let coverage = self.coverage.coverage(ch).unwrap_or(0.5);
+                Cell { ch, brightness: 1.0 - coverage, color }
+            }
+        }
+    }
+
+    /// Menutup baris terakhir dan meratakan lebar semua baris.
+    fn finish(mut self) -> AsciiArt {
+        if !self.current.is_empty() {
+            self.rows.push(std::mem::take(&mut self.current));
+        }
+
+        let width = self.rows.iter().map(Vec::len).max().unwrap_or(0);
+        let padding = self.cell(' ', DEFAULT_COLOR, None);
+        let mut cells = Vec::with_capacity(width * self.rows.len());
+        for row in &self.rows {
+            cells.extend_from_slice(row);
+            cells.resize(cells.len() + width - row.len(), padding);
+        }
+
+        AsciiArt::new(width as u32, self.rows.len() as u32, cells).expect("setiap baris diratakan selebar baris terpanjang")
+    }
+}
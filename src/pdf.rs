@@ -0,0 +1,232 @@
+//! Renderer PDF (feature `pdf`).
+//!
+//! Menghasilkan dokumen PDF satu halaman yang menata ASCII art dengan font
+//! monospace Courier bawaan PDF, lengkap dengan warna per karakter. Karena
+//! Courier hanya mendukung Latin-1, glyph lain (blok Unicode, quadrant)
+//! diganti karakter ASCII dengan kepadatan terdekat.
+
+use crate::converter::ASCII_CHARS;
+use crate::{AsciiArt, AsciiConverter, CoverageTable};
+
+/// Lebar karakter Courier relatif terhadap ukuran font.
+const COURIER_ADVANCE: f32 = 0.6;
+
+/// Jumlah point per milimeter.
+const POINTS_PER_MM: f32 = 72.0 / 25.4;
+
+/// Ukuran halaman PDF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageSize {
+    #[default]
+    A4,
+    Letter,
+}
+
+impl PageSize {
+    /// Dimensi halaman dalam point (lebar, tinggi).
+    fn dimensions(&self) -> (f32, f32) {
+        match self {
+            PageSize::A4 => (595.28, 841.89),
+            PageSize::Letter => (612.0, 792.0),
+        }
+    }
+}
+
+/// Opsi tata letak untuk output PDF.
+///
+/// * `page_size` - Ukuran halaman (A4 atau Letter)
+/// * `margin_mm` - Margin di setiap sisi dalam milimeter
+/// * `font_size` - Ukuran font dalam point; `None` berarti dihitung otomatis agar art memenuhi halaman
+/// * `color` - Mewarnai setiap karakter sesuai warna sel
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfOptions {
+    pub page_size: PageSize,
+    pub margin_mm: f32,
+    pub font_size: Option<f32>,
+    pub color: bool,
+}
+
+impl Default for PdfOptions {
+    /// Halaman A4, margin 15 mm, ukuran font otomatis, berwarna.
+    fn default() -> Self {
+        Self {
+            page_size: PageSize::A4,
+            margin_mm: 15.0,
+            font_size: None,
+            color: true,
+        }
+    }
+}
+
+impl AsciiArt {
+    /// Me-render grid sebagai dokumen PDF satu halaman.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Ukuran halaman, margin, ukuran font, dan pewarnaan
+    ///
+    /// # Returns
+    ///
+    /// Isi file PDF dalam bentuk bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, PdfOptions};
+    ///
+    /// let art = AsciiArt::new(2, 1, vec![Cell { ch: '#', brightness: 0.3, color: [200, 0, 0] }; 2]).unwrap();
+    /// let pdf = art.to_pdf(&PdfOptions::default());
+    /// assert!(pdf.starts_with(b"%PDF-1.4"));
+    /// ```
+    pub fn to_pdf(&self, options: &PdfOptions) -> Vec<u8> {
+        let (page_width, page_height) = options.page_size.dimensions();
+        let margin = options.margin_mm * POINTS_PER_MM;
+        let usable_width = (page_width - 2.0 * margin).max(1.0);
+        let usable_height = (page_height - 2.0 * margin).max(1.0);
+
+        let font_size = options.font_size.unwrap_or_else(|| {
+            let by_width = usable_width / (self.width().max(1) as f32 * COURIER_ADVANCE);
+            let by_height = usable_height / self.height().max(1) as f32;
+            by_width.min(by_height)
+        });
+
+        let content = self.pdf_content(options, font_size, margin, page_height - margin);
+        build_document(page_width, page_height, &content)
+    }
+
+    /// Menyusun content stream PDF: satu baris teks per baris grid, warna diganti per run.
+    fn pdf_content(&self, options: &PdfOptions, font_size: f32, left: f32, top: f32) -> String {
+        let table = CoverageTable::builtin();
+        let mut content = format!(
+            "BT\n/F1 {size:.3} Tf\n{size:.3} TL\n{left:.3} {y:.3} Td\n",
+            size = font_size,
+            left = left,
+            y = top - font_size,
+        );
+
+        for row in self.rows() {
+            let mut active: Option<[u8; 3]> = None;
+            let mut run = String::new();
+
+            for cell in row {
+                let color = if options.color { cell.color } else { [0, 0, 0] };
+                if active != Some(color) {
+                    flush_run(&mut content, &mut run);
+                    let [r, g, b] = color;
+                    content.push_str(&format!(
+                        "{:.3} {:.3} {:.3} rg\n",
+                        r as f32 / 255.0,
+                        g as f32 / 255.0,
+                        b as f32 / 255.0
+                    ));
+                    active = Some(color);
+                }
+                push_escaped(&mut run, latin1_glyph(cell.ch, &table));
+            }
+
+            flush_run(&mut content, &mut run);
+            content.push_str("T*\n");
+        }
+
+        content.push_str("ET\n");
+        content
+    }
+}
+
+impl AsciiConverter {
+    /// Mengkonversi gambar dari path file menjadi dokumen PDF.
+    ///
+    /// PDF tidak termasuk `OutputFormat` karena hasilnya biner; pengaturan grid
+    /// tetap diambil dari konfigurasi converter, sedangkan tata letak halaman dari `options`.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_path` - Path ke file gambar yang akan dikonversi
+    /// * `options` - Ukuran halaman, margin, ukuran font, dan pewarnaan
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - Isi file PDF jika berhasil
+    /// * `Err(String)` - Pesan error jika gagal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, PdfOptions};
+    ///
+    /// let converter = AsciiConverter::new(100, 50);
+    /// if let Ok(pdf) = converter.convert_to_pdf("poster.jpg", &PdfOptions::default()) {
+    ///     std::fs::write("poster.pdf", pdf).unwrap();
+    /// }
+    /// ```
+    pub fn convert_to_pdf(&self, image_path: &str, options: &PdfOptions) -> Result<Vec<u8>, String> {
+        let art = self.convert_to_art(image_path)?;
+        Ok(art.to_pdf(options))
+    }
+}
+
+/// Menulis teks yang terkumpul sebagai operator `Tj`.
+fn flush_run(content: &mut String, run: &mut String) {
+    if !run.is_empty() {
+        content.push('(');
+        content.push_str(run);
+        content.push_str(") Tj\n");
+        run.clear();
+    }
+}
+
+/// Menambahkan karakter ke string literal PDF dengan escape yang diperlukan.
+fn push_escaped(run: &mut String, ch: char) {
+    match ch {
+        '(' | ')' | '\\' => {
+            run.push('\\');
+            run.push(ch);
+        }
+        _ => run.push(ch),
+    }
+}
+
+/// Mengganti glyph di luar ASCII yang dapat dicetak dengan karakter ASCII berkepadatan terdekat.
+fn latin1_glyph(ch: char, table: &CoverageTable) -> char {
+    if (' '..='~').contains(&ch) {
+        return ch;
+    }
+    let coverage = table.coverage(ch).unwrap_or(0.5);
+    let index = ((1.0 - coverage) * (ASCII_CHARS.len() - 1) as f32).round() as usize;
+    ASCII_CHARS[index.min(ASCII_CHARS.len() - 1)] as char
+}
+
+/// Menyusun dokumen PDF lengkap dengan tabel xref.
+fn build_document(page_width: f32, page_height: f32, content: &str) -> Vec<u8> {
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {:.2} {:.2}] \
+             /Resources << /Font << /F1 4 0 R >> >> /Contents 5 0 R >>",
+            page_width, page_height
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Courier /Encoding /WinAnsiEncoding >>".to_string(),
+        format!("<< /Length {} >>\nstream\n{}endstream", content.len(), content),
+    ];
+
+    let mut output = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(output.len());
+        output.push_str(&format!("{} 0 obj\n{}\nendobj\n", i + 1, object));
+    }
+
+    let xref_offset = output.len();
+    output.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+    for offset in offsets {
+        output.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    output.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+
+    output.into_bytes()
+}
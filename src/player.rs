@@ -0,0 +1,188 @@
+//! Pemutar animasi ASCII di terminal (feature `player`).
+//!
+//! Menangani seluruh urusan terminal: alternate screen, menyembunyikan
+//! kursor, raw mode agar `Ctrl-C` dapat ditangkap, serta jam frame yang
+//! memakai deadline absolut sehingga keterlambatan render tidak menumpuk.
+//!
+//! Tombol `q`, `Esc`, atau `Ctrl-C` menghentikan pemutaran dan terminal
+//! selalu dikembalikan ke kondisi semula, termasuk ketika terjadi panic.
+
+use crate::{AnimationConverter, AsciiFrame, FrameSource};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, terminal};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Pemutar frame ASCII dengan jam frame yang akurat.
+///
+/// Isi frame ditulis apa adanya, jadi gunakan output teks biasa atau ANSI
+/// (`use_color: false`, atau `OutputFormat::Ansi` / `OutputFormat::AnsiBackground`).
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use aspix::{AnimationConverter, AsciiConfig, GifSource, TerminalPlayer};
+///
+/// let converter = AnimationConverter::new(AsciiConfig { width: 80, height: 40, ..Default::default() });
+/// let mut source = GifSource::open("animation.gif").unwrap();
+/// TerminalPlayer::new().play_source(&converter, &mut source).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TerminalPlayer {
+    looping: bool,
+}
+
+impl TerminalPlayer {
+    /// Membuat pemutar yang memutar frame sekali.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mengatur apakah daftar frame diulang terus hingga dihentikan pengguna.
+    ///
+    /// Hanya berlaku untuk `play_frames`; sumber frame diputar hingga habis.
+    pub fn with_looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Memutar daftar frame yang sudah dikonversi.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Jika pemutaran selesai atau dihentikan pengguna
+    /// * `Err(String)` - Pesan error jika terminal gagal disiapkan atau ditulis
+    pub fn play_frames(&self, frames: &[AsciiFrame]) -> Result<(), String> {
+        if frames.is_empty() {
+            return Ok(());
+        }
+
+        let mut screen = Screen::enter()?;
+        let mut clock = FrameClock::new();
+        loop {
+            for frame in frames {
+                if !screen.show(frame, &mut clock)? {
+                    return Ok(());
+                }
+            }
+            if !self.looping {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Mengkonversi dan memutar frame dari sumber secara langsung.
+    ///
+    /// Waktu konversi setiap frame sudah diperhitungkan dalam jam frame,
+    /// sehingga kecepatan pemutaran tetap sesuai selama konversi lebih cepat
+    /// dari durasi frame.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Jika sumber habis atau pemutaran dihentikan pengguna
+    /// * `Err(String)` - Pesan error jika konversi frame atau terminal gagal
+    pub fn play_source<S: FrameSource + ?Sized>(&self, converter: &AnimationConverter, source: &mut S) -> Result<(), String> {
+        let mut screen = Screen::enter()?;
+        let mut clock = FrameClock::new();
+        while let Some(frame) = converter.convert_next(source) {
+            if !screen.show(&frame?, &mut clock)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Jam frame berbasis deadline absolut.
+struct FrameClock {
+    deadline: Instant,
+}
+
+impl FrameClock {
+    fn new() -> Self {
+        Self { deadline: Instant::now() }
+    }
+
+    /// Memajukan deadline sebesar durasi frame dan mengembalikan sisa waktu tunggu.
+    fn advance(&mut self, delay: Duration) -> Duration {
+        self.deadline += delay;
+        let now = Instant::now();
+        if self.deadline < now {
+            // Tertinggal terlalu jauh (misalnya terminal lambat): mulai ulang dari sekarang
+            if now - self.deadline > delay {
+                self.deadline = now;
+            }
+            return Duration::ZERO;
+        }
+        self.deadline - now
+    }
+}
+
+/// Terminal dalam mode pemutaran; dikembalikan ke kondisi semula saat di-drop.
+struct Screen {
+    stdout: io::Stdout,
+}
+
+impl Screen {
+    /// Masuk ke alternate screen, menyembunyikan kursor, dan mengaktifkan raw mode.
+    fn enter() -> Result<Self, String> {
+        terminal::enable_raw_mode().map_err(|e| format!("Gagal menyiapkan terminal: {}", e))?;
+        // Dibuat setelah raw mode aktif agar Drop selalu mengembalikannya
+        let mut screen = Self { stdout: io::stdout() };
+        execute!(screen.stdout, terminal::EnterAlternateScreen, cursor::Hide, terminal::Clear(terminal::ClearType::All))
+            .map_err(|e| format!("Gagal menyiapkan terminal: {}", e))?;
+        Ok(screen)
+    }
+
+    /// Menampilkan satu frame lalu menunggu hingga durasinya habis.
+    ///
+    /// Mengembalikan `false` jika pengguna menghentikan pemutaran.
+    fn show(&mut self, frame: &AsciiFrame, clock: &mut FrameClock) -> Result<bool, String> {
+        self.draw(&frame.content)
+            .map_err(|e| format!("Gagal menampilkan frame: {}", e))?;
+        wait_for(clock.advance(frame.delay))
+    }
+
+    /// Menggambar ulang frame dari pojok kiri atas tanpa membersihkan layar (menghindari flicker).
+    fn draw(&mut self, content: &str) -> io::Result<()> {
+        queue!(self.stdout, cursor::MoveTo(0, 0))?;
+        // Raw mode tidak menerjemahkan '\n' menjadi carriage return
+        for line in content.lines() {
+            write!(self.stdout, "{}", line)?;
+            queue!(self.stdout, terminal::Clear(terminal::ClearType::UntilNewLine))?;
+            write!(self.stdout, "\r\n")?;
+        }
+        queue!(self.stdout, terminal::Clear(terminal::ClearType::FromCursorDown))?;
+        self.stdout.flush()
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(self.stdout, cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Menunggu selama `timeout` sambil memantau tombol berhenti.
+///
+/// Mengembalikan `false` jika `q`, `Esc`, atau `Ctrl-C` ditekan.
+fn wait_for(timeout: Duration) -> Result<bool, String> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !event::poll(remaining).map_err(|e| format!("Gagal membaca input: {}", e))? {
+            return Ok(true);
+        }
+        let event = event::read().map_err(|e| format!("Gagal membaca input: {}", e))?;
+        if let Event::Key(key) = event {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+            if ctrl_c || matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                return Ok(false);
+            }
+        }
+    }
+}
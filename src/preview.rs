@@ -0,0 +1,153 @@
+//! Mode preview interaktif di terminal (feature `preview`).
+//!
+//! Membuka TUI sederhana yang me-render ulang ASCII art setiap kali
+//! pengaturan diubah, sehingga parameter dapat disetel sebelum batch konversi.
+//!
+//! Tombol:
+//!
+//! * `←` / `→` - Kurangi / tambah lebar output
+//! * `↑` / `↓` - Tambah / kurangi tinggi output
+//! * `c` / `C` - Kurangi / tambah contrast
+//! * `b` / `B` - Kurangi / tambah brightness
+//! * `m` - Ganti set karakter (dasar → detail → densitas tinggi)
+//! * `i` - Toggle invert
+//! * `Enter` - Selesai dan kembalikan konfigurasi
+//! * `q` / `Esc` - Batal
+
+use crate::{AsciiConfig, AsciiConverter};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::{cursor, execute, queue, terminal};
+use std::io::{self, Write};
+use std::path::Path;
+
+/// Langkah perubahan untuk contrast dan brightness per tombol.
+const ADJUST_STEP: f32 = 0.1;
+
+/// Langkah perubahan dimensi per tombol.
+const SIZE_STEP: u32 = 2;
+
+/// Menjalankan preview interaktif untuk gambar di `image_path`.
+///
+/// Mode warna dinonaktifkan selama preview karena output HTML tidak dapat
+/// ditampilkan di terminal.
+///
+/// # Arguments
+///
+/// * `image_path` - Path ke file gambar
+/// * `config` - Konfigurasi awal
+///
+/// # Returns
+///
+/// * `Ok(Some(AsciiConfig))` - Konfigurasi akhir jika pengguna menekan `Enter`
+/// * `Ok(None)` - Jika pengguna membatalkan dengan `q` atau `Esc`
+/// * `Err(String)` - Pesan error jika gambar atau terminal gagal dibuka, atau gambar melampaui `config.limits`
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use aspix::{preview, AsciiConfig};
+///
+/// if let Ok(Some(config)) = preview::run("image.jpg", AsciiConfig::default()) {
+///     println!("contrast terpilih: {}", config.contrast);
+/// }
+/// ```
+pub fn run(image_path: &str, config: AsciiConfig) -> Result<Option<AsciiConfig>, String> {
+    let img = config.limits.open(Path::new(image_path))?;
+    let mut config = AsciiConfig { use_color: false, ..config };
+
+    let mut stdout = io::stdout();
+    terminal::enable_raw_mode().map_err(|e| format!("Gagal menyiapkan terminal: {}", e))?;
+    execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)
+        .map_err(|e| format!("Gagal menyiapkan terminal: {}", e))?;
+
+    let result = event_loop(&img, &mut config, &mut stdout);
+
+    // Kembalikan terminal ke kondisi semula apa pun hasilnya
+    let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+
+    result.map(|accepted| accepted.then_some(config))
+}
+
+/// Loop utama: render, tunggu tombol, ubah konfigurasi.
+///
+/// Mengembalikan `true` jika pengguna menerima konfigurasi.
+fn event_loop(img: &image::DynamicImage, config: &mut AsciiConfig, stdout: &mut io::Stdout) -> Result<bool, String> {
+    loop {
+        render(img, config, stdout).map_err(|e| format!("Gagal menampilkan preview: {}", e))?;
+
+        let event = event::read().map_err(|e| format!("Gagal membaca input: {}", e))?;
+        let Event::Key(key) = event else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Left => config.width = config.width.saturating_sub(SIZE_STEP).max(SIZE_STEP),
+            KeyCode::Right => config.width += SIZE_STEP,
+            KeyCode::Up => config.height += SIZE_STEP,
+            KeyCode::Down => config.height = config.height.saturating_sub(SIZE_STEP).max(SIZE_STEP),
+            KeyCode::Char('c') => config.contrast = (config.contrast - ADJUST_STEP).max(0.0),
+            KeyCode::Char('C') => config.contrast += ADJUST_STEP,
+            KeyCode::Char('b') => config.brightness = (config.brightness - ADJUST_STEP).max(0.0),
+            KeyCode::Char('B') => config.brightness += ADJUST_STEP,
+            KeyCode::Char('i') => config.invert = !config.invert,
+            KeyCode::Char('m') => cycle_charset(config),
+            KeyCode::Enter => return Ok(true),
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(false),
+            _ => {}
+        }
+    }
+}
+
+/// Berpindah ke set karakter berikutnya: dasar → detail → densitas tinggi.
+fn cycle_charset(config: &mut AsciiConfig) {
+    let (detailed, high_density) = match (config.use_detailed_chars, config.use_high_density) {
+        (false, false) => (true, false),
+        (true, false) => (false, true),
+        _ => (false, false),
+    };
+    config.use_detailed_chars = detailed;
+    config.use_high_density = high_density;
+}
+
+/// Nama set karakter yang aktif untuk baris status.
+fn charset_name(config: &AsciiConfig) -> &'static str {
+    if config.custom_chars.is_some() {
+        "kustom"
+    } else if config.use_high_density {
+        "densitas tinggi"
+    } else if config.use_detailed_chars {
+        "detail"
+    } else {
+        "dasar"
+    }
+}
+
+/// Me-render ulang ASCII art dan baris status ke layar.
+fn render(img: &image::DynamicImage, config: &AsciiConfig, stdout: &mut io::Stdout) -> io::Result<()> {
+    let converter = AsciiConverter::with_config(config.clone());
+    let ascii = converter
+        .process_image(img)
+        .unwrap_or_else(|e| format!("Error: {}", e));
+
+    queue!(stdout, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0))?;
+    // Raw mode tidak menerjemahkan '\n' menjadi carriage return
+    for line in ascii.lines() {
+        write!(stdout, "{}\r\n", line)?;
+    }
+    write!(
+        stdout,
+        "\r\n{}x{} | contrast {:.1} | brightness {:.1} | charset {} | invert {} \
+         | ←→↑↓ ukuran, c/C, b/B, m, i, Enter selesai, q batal",
+        config.width,
+        config.height,
+        config.contrast,
+        config.brightness,
+        charset_name(config),
+        if config.invert { "ya" } else { "tidak" },
+    )?;
+    stdout.flush()
+}
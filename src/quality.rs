@@ -0,0 +1,138 @@
+//! Penilaian kualitas hasil konversi terhadap gambar sumber.
+//!
+//! ASCII art dirasterisasi kembali menjadi grayscale melalui tabel cakupan
+//! glyph, gambar sumber diperkecil ke ukuran grid yang sama, lalu keduanya
+//! dibandingkan dengan MSE, PSNR, dan SSIM. Skor ini dapat dipakai untuk
+//! pencarian parameter otomatis maupun regression test kualitas rendering.
+
+use crate::{ascii_to_image, AsciiArt, CoverageTable};
+use image::{imageops::FilterType, DynamicImage, GrayImage};
+
+/// Ukuran jendela (per sisi) untuk perhitungan SSIM lokal.
+const SSIM_WINDOW: u32 = 4;
+
+/// Konstanta stabilisasi SSIM untuk rentang nilai 0.0 - 1.0.
+const SSIM_C1: f64 = 0.01 * 0.01;
+const SSIM_C2: f64 = 0.03 * 0.03;
+
+/// Hasil perbandingan antara gambar sumber dan ASCII art.
+///
+/// * `mse` - Mean squared error pada rentang 0.0 - 1.0 (lebih kecil lebih baik)
+/// * `psnr` - Peak signal-to-noise ratio dalam dB (lebih besar lebih baik)
+/// * `ssim` - Structural similarity rata-rata, -1.0 - 1.0 (lebih besar lebih baik)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QualityReport {
+    pub mse: f64,
+    pub psnr: f64,
+    pub ssim: f64,
+}
+
+/// Menilai kemiripan ASCII art dengan gambar sumber menggunakan tabel cakupan bawaan.
+///
+/// # Arguments
+///
+/// * `source` - Gambar sumber yang dikonversi
+/// * `art` - Hasil konversi terstruktur
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{quality, AsciiConverter};
+/// use image::{DynamicImage, Luma, GrayImage};
+///
+/// let source = DynamicImage::ImageLuma8(GrayImage::from_fn(64, 32, |x, _| Luma([(x * 4) as u8])));
+/// let art = AsciiConverter::new(32, 16).convert_image_to_art(&source).unwrap();
+/// let report = quality::score(&source, &art);
+/// assert!(report.ssim > 0.5);
+/// ```
+pub fn score(source: &DynamicImage, art: &AsciiArt) -> QualityReport {
+    score_with_table(source, art, &CoverageTable::builtin())
+}
+
+/// Menilai kemiripan ASCII art dengan gambar sumber menggunakan tabel cakupan tertentu.
+///
+/// Gunakan tabel hasil kalibrasi font agar skor mencerminkan tampilan sebenarnya.
+pub fn score_with_table(source: &DynamicImage, art: &AsciiArt, table: &CoverageTable) -> QualityReport {
+    let rendered = ascii_to_image(&art.to_text(), table, 1, 1);
+    let reference = source
+        .resize_exact(rendered.width(), rendered.height(), FilterType::Triangle)
+        .into_luma8();
+
+    compare(&reference, &rendered)
+}
+
+/// Membandingkan dua gambar grayscale berukuran sama.
+fn compare(a: &GrayImage, b: &GrayImage) -> QualityReport {
+    let pixels = (a.width() * a.height()) as f64;
+    if pixels == 0.0 {
+        return QualityReport { mse: 0.0, psnr: f64::INFINITY, ssim: 1.0 };
+    }
+
+    let mse = a
+        .pixels()
+        .zip(b.pixels())
+        .map(|(pa, pb)| {
+            let diff = (pa[0] as f64 - pb[0] as f64) / 255.0;
+            diff * diff
+        })
+        .sum::<f64>()
+        / pixels;
+
+    let psnr = if mse > 0.0 { 10.0 * (1.0 / mse).log10() } else { f64::INFINITY };
+
+    QualityReport { mse, psnr, ssim: mean_ssim(a, b) }
+}
+
+/// SSIM rata-rata atas jendela yang saling tumpang tindih setengah ukuran.
+///
+/// Jika gambar lebih kecil dari satu jendela, seluruh gambar dipakai sebagai satu jendela.
+fn mean_ssim(a: &GrayImage, b: &GrayImage) -> f64 {
+    let window_w = SSIM_WINDOW.min(a.width());
+    let window_h = SSIM_WINDOW.min(a.height());
+    let step = (SSIM_WINDOW / 2).max(1);
+
+    let mut total = 0.0;
+    let mut windows = 0;
+    let mut y = 0;
+    while y + window_h <= a.height() {
+        let mut x = 0;
+        while x + window_w <= a.width() {
+            total += window_ssim(a, b, x, y, window_w, window_h);
+            windows += 1;
+            x += step;
+        }
+        y += step;
+    }
+
+    if windows == 0 { 1.0 } else { total / windows as f64 }
+}
+
+/// SSIM untuk satu jendela.
+fn window_ssim(a: &GrayImage, b: &GrayImage, x0: u32, y0: u32, w: u32, h: u32) -> f64 {
+    let n = (w * h) as f64;
+    let (mut sum_a, mut sum_b) = (0.0, 0.0);
+    for y in y0..y0 + h {
+        for x in x0..x0 + w {
+            sum_a += a.get_pixel(x, y)[0] as f64 / 255.0;
+            sum_b += b.get_pixel(x, y)[0] as f64 / 255.0;
+        }
+    }
+    let (mean_a, mean_b) = (sum_a / n, sum_b / n);
+
+    let (mut var_a, mut var_b, mut covariance) = (0.0, 0.0, 0.0);
+    for y in y0..y0 + h {
+        for x in x0..x0 + w {
+            let da = a.get_pixel(x, y)[0] as f64 / 255.0 - mean_a;
+            let db = b.get_pixel(x, y)[0] as f64 / 255.0 - mean_b;
+            var_a += da * da;
+            var_b += db * db;
+            covariance += da * db;
+        }
+    }
+    var_a /= n;
+    var_b /= n;
+    covariance /= n;
+
+    ((2.0 * mean_a * mean_b + SSIM_C1) * (2.0 * covariance + SSIM_C2))
+        / ((mean_a * mean_a + mean_b * mean_b + SSIM_C1) * (var_a + var_b + SSIM_C2))
+}
@@ -0,0 +1,80 @@
+//! Pewarnaan ulang grid yang sudah dikonversi tanpa memproses gambar dari awal.
+
+use crate::emoji::weighted_distance;
+use crate::{AsciiArt, ColorGradient, ColorMode};
+
+/// Skema warna baru untuk `AsciiArt::recolor`.
+///
+/// Warna sel saat ini dianggap sebagai warna sumber, jadi hasil terbaik didapat
+/// dari art yang dikonversi dengan `ColorMode::Source` dan `color_saturation: 1.0`.
+///
+/// * `Source` - Warna sel saat ini dengan intensitas `saturation` (0.0 - 1.0), seperti `ColorMode::Source`
+/// * `Duotone` - Gradasi dua warna berdasarkan brightness sel, seperti `ColorMode::Duotone`
+/// * `Tint` - Satu warna dengan intensitas mengikuti brightness, seperti `ColorMode::Tint`
+/// * `Gradient` - Gradien berdasarkan posisi sel, lihat `AsciiArt::apply_gradient`
+/// * `Palette` - Warna terdekat dari palet tetap (jarak RGB berbobot persepsi); palet kosong
+///   tidak mengubah warna
+/// * `Monochrome` - Satu warna yang sama untuk semua sel
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiArt, Cell, Recolor};
+///
+/// let cell = Cell { ch: '#', brightness: 0.5, color: [200, 40, 30] };
+/// let art = AsciiArt::new(2, 1, vec![cell; 2]).unwrap();
+///
+/// let mut matrix = art.clone();
+/// matrix.recolor(&Recolor::Tint([0, 255, 70]));
+/// assert_eq!(matrix.cells()[0].color, [0, 127, 35]);
+///
+/// let mut cga = art.clone();
+/// cga.recolor(&Recolor::Palette(vec![[0, 0, 0], [255, 85, 85], [85, 255, 255]]));
+/// assert_eq!(cga.cells()[0].color, [255, 85, 85]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Recolor {
+    Source { saturation: f32 },
+    Duotone { dark: [u8; 3], light: [u8; 3] },
+    Tint([u8; 3]),
+    Gradient(ColorGradient),
+    Palette(Vec<[u8; 3]>),
+    Monochrome([u8; 3]),
+}
+
+impl AsciiArt {
+    /// Mengganti warna semua sel dengan skema warna baru; karakter dan brightness tidak berubah.
+    ///
+    /// Satu hasil konversi dapat diekspor dalam beberapa gaya warna dengan
+    /// memanggil fungsi ini pada salinan grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `scheme` - Skema warna yang diterapkan
+    pub fn recolor(&mut self, scheme: &Recolor) {
+        if let Recolor::Gradient(gradient) = scheme {
+            return self.apply_gradient(gradient);
+        }
+
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let cell = self.get_mut(x, y).expect("koordinat di dalam grid");
+                let source = cell.color.map(|c| c as f32 / 255.0);
+                cell.color = match scheme {
+                    Recolor::Source { saturation } => {
+                        ColorMode::Source.apply(source, cell.brightness, saturation.clamp(0.0, 1.0))
+                    }
+                    &Recolor::Duotone { dark, light } => ColorMode::Duotone { dark, light }.apply(source, cell.brightness, 1.0),
+                    &Recolor::Tint(color) => ColorMode::Tint(color).apply(source, cell.brightness, 1.0),
+                    Recolor::Palette(palette) => palette
+                        .iter()
+                        .copied()
+                        .min_by_key(|&color| weighted_distance(cell.color, color))
+                        .unwrap_or(cell.color),
+                    &Recolor::Monochrome(color) => color,
+                    Recolor::Gradient(_) => unreachable!("gradien sudah diterapkan di atas"),
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Laporan konversi: waktu per tahap dan statistik hasil.
+
+use crate::{AsciiArt, AsciiConverter};
+use image::DynamicImage;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Waktu yang dihabiskan setiap tahap pipeline.
+///
+/// * `decode` - Membuka dan mendekode gambar (nol jika gambar sudah di memory)
+/// * `prepare` - Orientasi, crop, resize, dan penyesuaian gambar
+/// * `mapping` - Pemetaan blok piksel ke karakter, orientasi grid, dan gradien
+/// * `render` - Pembuatan output teks/HTML/ANSI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StageTimings {
+    pub decode: Duration,
+    pub prepare: Duration,
+    pub mapping: Duration,
+    pub render: Duration,
+}
+
+impl StageTimings {
+    /// Total waktu semua tahap.
+    pub fn total(&self) -> Duration {
+        self.decode + self.prepare + self.mapping + self.render
+    }
+}
+
+/// Catatan tahap pipeline yang diisi oleh `AsciiConverter::art_traced`.
+///
+/// Diambil dari converter yang benar-benar menjalankan tahap tersebut, termasuk
+/// converter turunan `pixel_art`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct PipelineProbe {
+    pub(crate) prepare: Duration,
+    pub(crate) mapping: Duration,
+    pub(crate) internal_width: u32,
+    pub(crate) internal_height: u32,
+    pub(crate) block_size: u32,
+}
+
+/// Statistik satu konversi.
+///
+/// * `timings` - Waktu per tahap
+/// * `source_width` / `source_height` - Dimensi gambar sumber dalam piksel
+/// * `internal_width` / `internal_height` - Dimensi gambar setelah resize
+/// * `block_size` - Ukuran blok piksel internal (per sisi) yang diwakili satu sel
+/// * `char_histogram` - Jumlah pemakaian setiap karakter, urut dari yang paling sering
+/// * `average_brightness` - Rata-rata brightness sel (0.0 - 1.0, sebelum `invert`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConversionStats {
+    pub timings: StageTimings,
+    pub source_width: u32,
+    pub source_height: u32,
+    pub internal_width: u32,
+    pub internal_height: u32,
+    pub block_size: u32,
+    pub char_histogram: Vec<(char, usize)>,
+    pub average_brightness: f32,
+}
+
+/// Hasil `convert_with_report`.
+///
+/// * `art` - Grid hasil konversi
+/// * `content` - Hasil render sesuai konfigurasi
+/// * `stats` - Statistik konversi
+#[derive(Debug, Clone)]
+pub struct ConversionReport {
+    pub art: AsciiArt,
+    pub content: String,
+    pub stats: ConversionStats,
+}
+
+impl AsciiConverter {
+    /// Mengkonversi file gambar sekaligus mengumpulkan statistik konversi.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ConversionReport)` - Grid, hasil render, dan statistiknya
+    /// * `Err(String)` - Pesan error jika gagal
+    pub fn convert_with_report(&self, image_path: &str) -> Result<ConversionReport, String> {
+        let started = Instant::now();
+        let img = self.open_image(image_path)?;
+        let decode = started.elapsed();
+
+        let mut report = self.convert_image_with_report(&img)?;
+        report.stats.timings.decode = decode;
+        Ok(report)
+    }
+
+    /// Seperti `convert_with_report` untuk gambar yang sudah ada di memory.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiConverter;
+    /// use image::{DynamicImage, GrayImage, Luma};
+    ///
+    /// let image = DynamicImage::ImageLuma8(GrayImage::from_pixel(40, 20, Luma([0])));
+    /// let converter = AsciiConverter::new(10, 5);
+    /// let report = converter.convert_image_with_report(&image).unwrap();
+    /// assert_eq!(report.art, converter.convert_image_to_art(&image).unwrap());
+    ///
+    /// assert_eq!((report.stats.source_width, report.stats.source_height), (40, 20));
+    /// assert_eq!(report.stats.block_size, 1);
+    /// assert_eq!(report.stats.char_histogram, vec![('@', 50)]);
+    /// assert_eq!(report.stats.average_brightness, 0.0);
+    /// println!("Total: {:?}", report.stats.timings.total());
+    /// ```
+    pub fn convert_image_with_report(&self, img: &DynamicImage) -> Result<ConversionReport, String> {
+        let mut probe = PipelineProbe::default();
+        let art = self.art_traced(img, Instant::now(), None, Some(&mut probe))?;
+
+        let started = Instant::now();
+        let content = self.render_art(&art);
+        let render = started.elapsed();
+
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for cell in art.cells() {
+            *counts.entry(cell.ch).or_default() += 1;
+        }
+        let mut char_histogram: Vec<(char, usize)> = counts.into_iter().collect();
+        char_histogram.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let cell_count = art.cells().len().max(1) as f32;
+        let average_brightness = art.cells().iter().map(|cell| cell.brightness).sum::<f32>() / cell_count;
+
+        let stats = ConversionStats {
+            timings: StageTimings {
+                decode: Duration::ZERO,
+                prepare: probe.prepare,
+                mapping: probe.mapping,
+                render,
+            },
+            source_width: img.width(),
+            source_height: img.height(),
+            internal_width: probe.internal_width,
+            internal_height: probe.internal_height,
+            block_size: probe.block_size,
+            char_histogram,
+            average_brightness,
+        };
+
+        Ok(ConversionReport { art, content, stats })
+    }
+}
@@ -0,0 +1,143 @@
+//! Resampling grid `AsciiArt` ke ukuran lain tanpa memproses ulang gambar.
+
+use crate::{AsciiArt, Cell};
+
+/// Metode resampling grid sel.
+///
+/// * `Nearest` - Menyalin sel sumber terdekat apa adanya (cepat, karakter tidak berubah)
+/// * `Area` - Merata-rata brightness dan warna semua sel sumber yang tertutup sel tujuan
+///   (tertimbang luas), lalu memilih karakter ulang
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResampleFilter {
+    Nearest,
+    #[default]
+    Area,
+}
+
+impl AsciiArt {
+    /// Mengubah ukuran grid dengan rata-rata area.
+    ///
+    /// Lihat `scale_with` untuk memilih metode resampling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell};
+    ///
+    /// let cell = |ch, brightness| Cell { ch, brightness, color: [0, 0, 0] };
+    /// let art = AsciiArt::new(4, 1, vec![cell('@', 0.0), cell('@', 0.0), cell(' ', 1.0), cell(' ', 1.0)]).unwrap();
+    /// let small = art.scale(2, 1);
+    /// assert_eq!(small.to_text(), "@ \n");
+    /// ```
+    pub fn scale(&self, new_width: u32, new_height: u32) -> AsciiArt {
+        self.scale_with(new_width, new_height, ResampleFilter::Area)
+    }
+
+    /// Mengubah ukuran grid dengan metode resampling tertentu.
+    ///
+    /// Pada `ResampleFilter::Area`, karakter dipilih ulang dari karakter yang
+    /// sudah ada di grid sumber: karakter milik sel sumber dengan brightness
+    /// terdekat. Dengan begitu ramp dan `invert` yang dipakai saat konversi
+    /// tetap terjaga tanpa perlu mengetahui konfigurasinya.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_width` - Lebar grid tujuan dalam karakter
+    /// * `new_height` - Tinggi grid tujuan dalam baris
+    /// * `filter` - Metode resampling
+    ///
+    /// # Returns
+    ///
+    /// Grid baru berukuran `new_width × new_height`. Jika grid sumber kosong,
+    /// semua sel berisi spasi.
+    pub fn scale_with(&self, new_width: u32, new_height: u32, filter: ResampleFilter) -> AsciiArt {
+        let cells = if self.cells().is_empty() {
+            vec![Cell { ch: ' ', brightness: 0.0, color: [0, 0, 0] }; (new_width as usize) * (new_height as usize)]
+        } else {
+            match filter {
+                ResampleFilter::Nearest => self.resample_nearest(new_width, new_height),
+                ResampleFilter::Area => self.resample_area(new_width, new_height),
+            }
+        };
+
+        AsciiArt::new(new_width, new_height, cells).expect("jumlah sel selalu sesuai dimensi tujuan")
+    }
+
+    /// Menyalin sel sumber yang pusatnya paling dekat dengan pusat sel tujuan.
+    fn resample_nearest(&self, new_width: u32, new_height: u32) -> Vec<Cell> {
+        let x_ratio = self.width() as f32 / new_width.max(1) as f32;
+        let y_ratio = self.height() as f32 / new_height.max(1) as f32;
+
+        let mut cells = Vec::with_capacity((new_width as usize) * (new_height as usize));
+        for y in 0..new_height {
+            let sy = (((y as f32 + 0.5) * y_ratio) as u32).min(self.height() - 1);
+            for x in 0..new_width {
+                let sx = (((x as f32 + 0.5) * x_ratio) as u32).min(self.width() - 1);
+                cells.push(self.cells()[(sy * self.width() + sx) as usize]);
+            }
+        }
+        cells
+    }
+
+    /// Rata-rata tertimbang luas dari sel sumber yang tertutup setiap sel tujuan.
+    fn resample_area(&self, new_width: u32, new_height: u32) -> Vec<Cell> {
+        // Pasangan (brightness, karakter) terurut untuk memilih ulang karakter
+        let mut palette: Vec<(f32, char)> = self.cells().iter().map(|cell| (cell.brightness, cell.ch)).collect();
+        palette.sort_by(|a, b| a.0.total_cmp(&b.0));
+        palette.dedup_by(|a, b| a.0 == b.0);
+
+        let x_ratio = self.width() as f32 / new_width.max(1) as f32;
+        let y_ratio = self.height() as f32 / new_height.max(1) as f32;
+
+        let mut cells = Vec::with_capacity((new_width as usize) * (new_height as usize));
+        for y in 0..new_height {
+            let (y0, y1) = (y as f32 * y_ratio, (y + 1) as f32 * y_ratio);
+            for x in 0..new_width {
+                let (x0, x1) = (x as f32 * x_ratio, (x + 1) as f32 * x_ratio);
+
+                let mut total_weight = 0.0;
+                let mut total_brightness = 0.0;
+                let mut total_rgb = [0.0f32; 3];
+                for sy in y0.floor() as u32..(y1.ceil() as u32).min(self.height()) {
+                    let weight_y = overlap(sy, y0, y1);
+                    for sx in x0.floor() as u32..(x1.ceil() as u32).min(self.width()) {
+                        let weight = weight_y * overlap(sx, x0, x1);
+                        let cell = &self.cells()[(sy * self.width() + sx) as usize];
+                        total_weight += weight;
+                        total_brightness += cell.brightness * weight;
+                        for (total, &channel) in total_rgb.iter_mut().zip(cell.color.iter()) {
+                            *total += channel as f32 * weight;
+                        }
+                    }
+                }
+
+                let total_weight = total_weight.max(f32::EPSILON);
+                let brightness = total_brightness / total_weight;
+                let color = total_rgb.map(|c| (c / total_weight).round().clamp(0.0, 255.0) as u8);
+                cells.push(Cell { ch: nearest_glyph(&palette, brightness), brightness, color });
+            }
+        }
+        cells
+    }
+}
+
+/// Panjang irisan antara sel sumber `[index, index + 1)` dan rentang `[start, end)`.
+fn overlap(index: u32, start: f32, end: f32) -> f32 {
+    ((index + 1) as f32).min(end) - (index as f32).max(start)
+}
+
+/// Karakter dengan brightness terdekat pada palet yang terurut.
+fn nearest_glyph(palette: &[(f32, char)], brightness: f32) -> char {
+    let index = palette.partition_point(|&(value, _)| value < brightness);
+    let candidates = [index.checked_sub(1), Some(index).filter(|&i| i < palette.len())];
+    candidates
+        .into_iter()
+        .flatten()
+        .min_by(|&a, &b| {
+            (palette[a].0 - brightness)
+                .abs()
+                .total_cmp(&(palette[b].0 - brightness).abs())
+        })
+        .map(|i| palette[i].1)
+        .unwrap_or(' ')
+}
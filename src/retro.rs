@@ -0,0 +1,216 @@
+//! Simulasi terminal DOS klasik: charset CP437, palet 16 warna VGA, dan layar 80×25.
+
+use crate::emoji::weighted_distance;
+use crate::{AsciiArt, Cell, CoverageTable};
+
+/// Ukuran layar teks standar DOS.
+pub const RETRO_COLUMNS: u32 = 80;
+pub const RETRO_ROWS: u32 = 25;
+
+/// Glyph CP437 untuk byte `0x80..=0xFF`.
+const CP437_HIGH: &str = "ÇüéâäàåçêëèïîìÄÅÉæÆôöòûùÿÖÜ¢£¥₧ƒáíóúñÑªº¿⌐¬½¼¡«»░▒▓│┤╡╢╖╕╣║╗╝╜╛┐└┴┬├─┼╞╟╚╔╩╦╠═╬╧╨╤╥╙╘╒╓╫╪┘┌█▄▌▐▀αßΓπΣσµτΦΘΩδ∞φε∩≡±≥≤⌠⌡÷≈°∙·√ⁿ²■\u{a0}";
+
+/// Blok arsir CP437 dari kosong sampai penuh, pengganti glyph di luar CP437.
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+/// Palet 16 warna VGA dalam urutan SGR ANSI: hitam, merah, hijau, coklat, biru,
+/// magenta, cyan, abu-abu terang, lalu delapan versi terangnya.
+pub const VGA_PALETTE: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [170, 0, 0],
+    [0, 170, 0],
+    [170, 85, 0],
+    [0, 0, 170],
+    [170, 0, 170],
+    [0, 170, 170],
+    [170, 170, 170],
+    [85, 85, 85],
+    [255, 85, 85],
+    [85, 255, 85],
+    [255, 255, 85],
+    [85, 85, 255],
+    [255, 85, 255],
+    [85, 255, 255],
+    [255, 255, 255],
+];
+
+/// Stilisasi "terminal retro" ala art ANSI era DOS.
+///
+/// Glyph di luar CP437 diganti blok arsir dengan cakupan tinta terdekat,
+/// warna dikuantisasi ke `VGA_PALETTE`, dan output ANSI memakai kode SGR
+/// 16 warna (bold untuk foreground terang) alih-alih truecolor.
+///
+/// * `clamp_size` - Batasi grid output ke 80×25 (`RETRO_COLUMNS` × `RETRO_ROWS`)
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, AsciiConverter, OutputFormat, RetroTerminal};
+///
+/// let converter = AsciiConverter::with_config(AsciiConfig {
+///     width: 120,
+///     height: 50,
+///     use_color: true,
+///     output_format: OutputFormat::Ansi,
+///     use_quadrants: true,
+///     retro: Some(RetroTerminal::default()),
+///     ..Default::default()
+/// });
+/// // Grid diperkecil proporsional agar muat di layar 80×25
+/// assert_eq!((converter.config().width, converter.config().height), (60, 25));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetroTerminal {
+    pub clamp_size: bool,
+}
+
+impl Default for RetroTerminal {
+    /// Membuat opsi default:
+    /// * clamp_size: true
+    fn default() -> Self {
+        Self { clamp_size: true }
+    }
+}
+
+/// Mengembalikan byte CP437 untuk sebuah karakter yang dapat dicetak.
+///
+/// Karakter kontrol (termasuk glyph CP437 `0x01..=0x1F`) tidak dipetakan
+/// karena bertabrakan dengan kode kontrol pada stream ANSI.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::cp437_byte;
+///
+/// assert_eq!(cp437_byte('#'), Some(b'#'));
+/// assert_eq!(cp437_byte('█'), Some(0xDB));
+/// assert_eq!(cp437_byte('▘'), None);
+/// ```
+pub fn cp437_byte(ch: char) -> Option<u8> {
+    if (' '..='~').contains(&ch) {
+        return Some(ch as u8);
+    }
+    CP437_HIGH.chars().position(|glyph| glyph == ch).map(|i| 0x80 + i as u8)
+}
+
+/// Mengembalikan karakter untuk sebuah byte CP437, kebalikan dari `cp437_byte`.
+///
+/// Byte `0x00..=0x7F` dibaca sebagai ASCII (termasuk kode kontrol seperti ESC dan
+/// `0x1A`), sedangkan `0x80..=0xFF` menjadi glyph CP437-nya.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::cp437_char;
+///
+/// assert_eq!(cp437_char(b'#'), '#');
+/// assert_eq!(cp437_char(0xDB), '█');
+/// assert_eq!(cp437_char(0x1B), '\x1b');
+/// ```
+pub fn cp437_char(byte: u8) -> char {
+    match byte {
+        0x00..=0x7F => byte as char,
+        _ => CP437_HIGH.chars().nth(byte as usize - 0x80).expect("CP437_HIGH berisi 128 glyph"),
+    }
+}
+
+/// Indeks warna `VGA_PALETTE` terdekat dengan sebuah warna RGB.
+pub fn nearest_vga_index(color: [u8; 3]) -> usize {
+    (0..VGA_PALETTE.len())
+        .min_by_key(|&i| weighted_distance(color, VGA_PALETTE[i]))
+        .unwrap_or(0)
+}
+
+impl AsciiArt {
+    /// Menerapkan batasan terminal retro: glyph CP437 dan palet 16 warna VGA.
+    ///
+    /// Glyph diagonal `╱`/`╲` menjadi `/`/`\`; glyph lain di luar CP437 diganti
+    /// blok arsir (` ░▒▓█`) dengan cakupan tinta terdekat menurut
+    /// `CoverageTable::builtin`, atau `?` jika cakupannya tidak diketahui.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell};
+    ///
+    /// let cells = vec![
+    ///     Cell { ch: '▙', brightness: 0.3, color: [250, 240, 90] },
+    ///     Cell { ch: '╱', brightness: 0.5, color: [10, 20, 160] },
+    /// ];
+    /// let mut art = AsciiArt::new(2, 1, cells).unwrap();
+    /// art.apply_retro();
+    /// assert_eq!(art.to_text(), "▓/\n");
+    /// assert_eq!(art.cells()[0].color, [255, 255, 85]);
+    /// assert_eq!(art.cells()[1].color, [0, 0, 170]);
+    /// ```
+    pub fn apply_retro(&mut self) {
+        let table = CoverageTable::builtin();
+
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let cell = self.get_mut(x, y).expect("koordinat di dalam grid");
+                cell.color = VGA_PALETTE[nearest_vga_index(cell.color)];
+                if cp437_byte(cell.ch).is_some() {
+                    continue;
+                }
+                cell.ch = match cell.ch {
+                    '╱' => '/',
+                    '╲' => '\\',
+                    ch => table
+                        .coverage(ch)
+                        .map(|coverage| SHADES[(coverage * (SHADES.len() - 1) as f32).round() as usize])
+                        .unwrap_or('?'),
+                };
+            }
+        }
+    }
+
+    /// Me-render grid sebagai ANSI 16 warna ala ANSI.SYS.
+    ///
+    /// Warna sel dipetakan ke `VGA_PALETTE` terdekat; warna terang memakai
+    /// atribut bold (`ESC[1;3Xm`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell};
+    ///
+    /// let cell = |color| Cell { ch: '#', brightness: 0.5, color };
+    /// let art = AsciiArt::new(3, 1, vec![cell([170, 0, 0]), cell([170, 0, 0]), cell([255, 255, 80])]).unwrap();
+    /// assert_eq!(art.to_ansi16(), "\x1b[0;31m##\x1b[1;33m#\x1b[0m\n");
+    /// ```
+    pub fn to_ansi16(&self) -> String {
+        self.ansi16_rows(|output, cell| output.push(cell.ch), |index| match index {
+            0..=7 => format!("\x1b[0;3{}m", index),
+            _ => format!("\x1b[1;3{}m", index - 8),
+        })
+    }
+
+    /// Me-render grid sebagai spasi dengan warna latar ANSI 16 warna.
+    ///
+    /// Latar terang memakai kode aixterm `ESC[10Xm`.
+    pub fn to_ansi16_background(&self) -> String {
+        self.ansi16_rows(|output, _| output.push(' '), |index| match index {
+            0..=7 => format!("\x1b[4{}m", index),
+            _ => format!("\x1b[10{}m", index - 8),
+        })
+    }
+
+    /// Menyusun output ANSI 16 warna per baris dengan penggabungan warna berurutan.
+    fn ansi16_rows(&self, push_cell: impl Fn(&mut String, &Cell), sgr: impl Fn(usize) -> String) -> String {
+        let mut output = String::new();
+        for row in self.rows() {
+            let mut active: Option<usize> = None;
+            for cell in row {
+                let index = nearest_vga_index(cell.color);
+                if active != Some(index) {
+                    output.push_str(&sgr(index));
+                    active = Some(index);
+                }
+                push_cell(&mut output, cell);
+            }
+            output.push_str("\x1b[0m\n");
+        }
+        output
+    }
+}
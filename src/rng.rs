@@ -0,0 +1,40 @@
+//! Generator bilangan acak deterministik untuk dithering dan pemilihan acak.
+//!
+//! Semua keacakan dalam pipeline harus melalui `Rng` yang dibuat dari
+//! `AsciiConfig::seed`, sehingga input dan konfigurasi yang sama selalu
+//! menghasilkan output yang identik byte per byte.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Generator SplitMix64: cepat, kecil, dan cukup baik untuk noise visual.
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Membuat generator dari seed; `None` memakai waktu sistem sehingga tidak deterministik.
+    pub(crate) fn from_seed(seed: Option<u64>) -> Self {
+        let state = seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0)
+        });
+        Self { state }
+    }
+
+    /// Menghasilkan bilangan 64-bit berikutnya.
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Menghasilkan bilangan acak seragam dalam rentang 0.0 - 1.0.
+    pub(crate) fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
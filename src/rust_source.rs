@@ -0,0 +1,208 @@
+//! Generator modul Rust berisi hasil konversi untuk disematkan saat kompilasi.
+
+use crate::AsciiConverter;
+use std::collections::HashMap;
+
+/// Panjang match minimum dan maksimum kompresi LZ.
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = MIN_MATCH + 0x7F;
+/// Panjang literal maksimum per token.
+const MAX_LITERAL: usize = 0x80;
+/// Jarak mundur maksimum sebuah match.
+const MAX_OFFSET: usize = u16::MAX as usize;
+
+/// Keyword Rust (strict, reserved, dan edisi 2018+) yang tidak dapat dipakai sebagai identifier.
+const KEYWORDS: [&str; 51] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
+    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+    "abstract", "become", "box", "do", "final", "macro", "override", "priv", "typeof", "unsized", "virtual",
+    "yield", "try",
+];
+
+/// Dekompresor yang ikut ditulis ke modul hasil saat `compress` aktif.
+///
+/// Nama fungsinya diberi awalan nama aksesor (`art_decompress`) agar beberapa
+/// modul hasil dapat di-`include!` ke scope yang sama. Format token: byte `0x00..=0x7F` diikuti `n + 1` byte literal; byte
+/// `0x80..=0xFF` adalah match sepanjang `(b & 0x7F) + 3` dengan offset u16 little-endian.
+const DECOMPRESSOR: &str = "fn decompress(data: &[u8]) -> String {
+    let mut out: Vec<u8> = Vec::new();
+    let mut i = 0;
+    while i < data.len() {
+        let tag = data[i] as usize;
+        i += 1;
+        if tag < 0x80 {
+            out.extend_from_slice(&data[i..i + tag + 1]);
+            i += tag + 1;
+        } else {
+            let len = (tag & 0x7F) + 3;
+            let start = out.len() - (data[i] as usize | (data[i + 1] as usize) << 8);
+            i += 2;
+            for k in 0..len {
+                out.push(out[start + k]);
+            }
+        }
+    }
+    String::from_utf8(out).expect(\"data art selalu UTF-8 valid\")
+}
+";
+
+/// Opsi generator modul Rust.
+///
+/// * `const_name` - Nama konstanta (`pub const ART: &str`); dengan `compress`, nama
+///   fungsi aksesornya adalah versi huruf kecilnya (`pub fn art() -> &'static str`); keduanya
+///   tidak boleh berupa keyword Rust
+/// * `compress` - Simpan art terkompresi LZ dan dekompresi secara lazy saat pertama dipakai
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RustSourceOptions {
+    pub const_name: String,
+    pub compress: bool,
+}
+
+impl Default for RustSourceOptions {
+    /// Membuat opsi default:
+    /// * const_name: "ART"
+    /// * compress: false
+    fn default() -> Self {
+        Self {
+            const_name: "ART".to_string(),
+            compress: false,
+        }
+    }
+}
+
+/// Membuat sumber modul Rust yang berisi `content` (teks, ANSI, atau HTML).
+///
+/// Tanpa kompresi, setiap baris menjadi satu literal dalam `concat!` dengan
+/// escape yang aman (termasuk karakter ESC pada ANSI). Dengan kompresi, data
+/// disimpan sebagai array byte dan dekompresornya ikut ditulis sehingga modul
+/// tidak membutuhkan dependensi tambahan.
+///
+/// # Returns
+///
+/// * `Ok(String)` - Sumber modul Rust
+/// * `Err(String)` - Pesan error jika `const_name` (atau nama aksesornya) bukan identifier
+///   yang valid atau berupa keyword
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{to_rust_source, RustSourceOptions};
+///
+/// let source = to_rust_source("@\"#\n..\n", &RustSourceOptions::default()).unwrap();
+/// assert!(source.contains("pub const ART: &str = concat!(\n    \"@\\\"#\\n\",\n    \"..\\n\",\n);"));
+///
+/// let options = RustSourceOptions { const_name: "LOGO".to_string(), compress: true };
+/// let source = to_rust_source(&"    ".repeat(100), &options).unwrap();
+/// assert!(source.contains("pub fn logo() -> &'static str"));
+/// assert!(source.contains("fn logo_decompress(data: &[u8]) -> String"));
+///
+/// // `fn` adalah keyword sehingga aksesor `FN` yang terkompresi tidak valid
+/// let options = RustSourceOptions { const_name: "FN".to_string(), compress: true };
+/// assert!(to_rust_source("", &options).is_err());
+/// ```
+pub fn to_rust_source(content: &str, options: &RustSourceOptions) -> Result<String, String> {
+    let name = &options.const_name;
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && name.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+    let lower = name.to_lowercase();
+    let keyword = |ident: &str| ident == "_" || KEYWORDS.contains(&ident);
+    if !valid || keyword(name) || (options.compress && keyword(&lower)) {
+        return Err(format!("Nama konstanta `{}` bukan identifier Rust yang valid", name));
+    }
+
+    let mut source = String::from("// Dibuat oleh aspix; jangan diedit manual.\n\n");
+    if options.compress {
+        let data = compress(content.as_bytes());
+        source.push_str(&format!("static {}_DATA: [u8; {}] = [", name, data.len()));
+        for (i, byte) in data.iter().enumerate() {
+            source.push_str(if i % 16 == 0 { "\n    " } else { " " });
+            source.push_str(&format!("{},", byte));
+        }
+        source.push_str("\n];\n\n");
+        source.push_str(&format!(
+            "pub fn {lower}() -> &'static str {{\n    static CACHE: std::sync::OnceLock<String> = std::sync::OnceLock::new();\n    CACHE.get_or_init(|| {lower}_decompress(&{name}_DATA))\n}}\n\n",
+            lower = lower,
+            name = name
+        ));
+        source.push_str(&DECOMPRESSOR.replacen("fn decompress(", &format!("fn {}_decompress(", lower), 1));
+    } else {
+        source.push_str(&format!("pub const {}: &str = concat!(\n", name));
+        for line in content.split_inclusive('\n') {
+            source.push_str(&format!("    \"{}\",\n", line.escape_debug()));
+        }
+        source.push_str(");\n");
+    }
+    Ok(source)
+}
+
+impl AsciiConverter {
+    /// Mengkonversi gambar lalu membungkus hasilnya sebagai modul Rust.
+    ///
+    /// Hasil render mengikuti konfigurasi (teks, ANSI, atau HTML).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, RustSourceOptions};
+    ///
+    /// let converter = AsciiConverter::new(60, 20);
+    /// if let Ok(source) = converter.convert_to_rust_source("logo.png", &RustSourceOptions::default()) {
+    ///     std::fs::write(std::env::temp_dir().join("logo.rs"), source).unwrap();
+    /// }
+    /// ```
+    pub fn convert_to_rust_source(&self, image_path: &str, options: &RustSourceOptions) -> Result<String, String> {
+        let content = self.convert(image_path)?;
+        to_rust_source(&content, options)
+    }
+}
+
+/// Kompresi LZ greedy sederhana dengan indeks hash 3 byte.
+fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut literals: Vec<u8> = Vec::new();
+    let mut last_seen: HashMap<[u8; 3], usize> = HashMap::new();
+    let mut i = 0;
+
+    let flush = |output: &mut Vec<u8>, literals: &mut Vec<u8>| {
+        for chunk in literals.chunks(MAX_LITERAL) {
+            output.push((chunk.len() - 1) as u8);
+            output.extend_from_slice(chunk);
+        }
+        literals.clear();
+    };
+
+    while i < input.len() {
+        let candidate = (i + MIN_MATCH <= input.len())
+            .then(|| [input[i], input[i + 1], input[i + 2]])
+            .and_then(|key| last_seen.insert(key, i))
+            .filter(|&start| i - start <= MAX_OFFSET);
+
+        let length = candidate.map_or(0, |start| {
+            (0..MAX_MATCH.min(input.len() - i))
+                .take_while(|&k| input[start + k] == input[i + k])
+                .count()
+        });
+
+        if let (Some(start), true) = (candidate, length >= MIN_MATCH) {
+            flush(&mut output, &mut literals);
+            let offset = i - start;
+            output.push(0x80 | (length - MIN_MATCH) as u8);
+            output.extend_from_slice(&(offset as u16).to_le_bytes());
+            for position in i + 1..i + length {
+                if position + MIN_MATCH <= input.len() {
+                    last_seen.insert([input[position], input[position + 1], input[position + 2]], position);
+                }
+            }
+            i += length;
+        } else {
+            literals.push(input[i]);
+            i += 1;
+        }
+    }
+    flush(&mut output, &mut literals);
+    output
+}
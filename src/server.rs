@@ -0,0 +1,195 @@
+//! Handler HTTP siap pakai untuk menjalankan aspix sebagai microservice.
+//!
+//! Endpoint `POST /convert` menerima form multipart dengan field `image`
+//! (file gambar) dan field opsional `config` (JSON `ServerConfig`), lalu
+//! mengembalikan ASCII art sebagai teks, HTML, atau ANSI.
+
+use crate::{AsciiConfig, AsciiConverter, OutputFormat};
+use axum::extract::{DefaultBodyLimit, Multipart};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use serde::Deserialize;
+
+/// Ukuran upload maksimum default (16 MB).
+pub const DEFAULT_UPLOAD_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Lebar dan tinggi output maksimum yang diterima dari klien.
+pub const MAX_SERVER_DIMENSION: u32 = 1000;
+
+/// Jumlah piksel maksimum gambar yang diunggah, untuk menolak decompression bomb.
+pub const MAX_SERVER_PIXELS: u64 = 100_000_000;
+
+/// Format respons server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseFormat {
+    #[default]
+    Text,
+    Html,
+    Ansi,
+    AnsiBackground,
+    Emoji,
+}
+
+/// Konfigurasi konversi yang dikirim klien sebagai JSON.
+///
+/// Semua field opsional; field yang tidak dikirim memakai nilai default `AsciiConfig`.
+///
+/// * `width` - Lebar output dalam karakter
+/// * `height` - Tinggi output dalam baris
+/// * `format` - Format respons (`text`, `html`, `ansi`, `ansi_background`, `emoji`)
+/// * `detailed` - Gunakan set karakter detail
+/// * `high_density` - Gunakan set karakter kepadatan tinggi
+/// * `invert` - Membalik brightness
+/// * `contrast` - Faktor contrast
+/// * `brightness` - Penyesuaian brightness
+/// * `quadrants` - Gunakan karakter blok kuadran
+/// * `chars` - Set karakter kustom dari gelap ke terang
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{ResponseFormat, ServerConfig};
+///
+/// let config: ServerConfig = serde_json::from_str(r#"{"width": 40, "format": "html"}"#).unwrap();
+/// assert_eq!(config.width, Some(40));
+/// assert_eq!(config.format, ResponseFormat::Html);
+/// assert!(config.to_ascii_config().use_color);
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub format: ResponseFormat,
+    pub detailed: bool,
+    pub high_density: bool,
+    pub invert: bool,
+    pub contrast: Option<f32>,
+    pub brightness: Option<f32>,
+    pub quadrants: bool,
+    pub chars: Option<String>,
+}
+
+impl ServerConfig {
+    /// Membuat `AsciiConfig` dari konfigurasi klien.
+    pub fn to_ascii_config(&self) -> AsciiConfig {
+        let defaults = AsciiConfig::default();
+        let output_format = match self.format {
+            ResponseFormat::Text | ResponseFormat::Html => OutputFormat::Html,
+            ResponseFormat::Ansi => OutputFormat::Ansi,
+            ResponseFormat::AnsiBackground => OutputFormat::AnsiBackground,
+            ResponseFormat::Emoji => OutputFormat::Emoji,
+        };
+        AsciiConfig {
+            width: self.width.unwrap_or(defaults.width),
+            height: self.height.unwrap_or(defaults.height),
+            use_detailed_chars: self.detailed,
+            use_high_density: self.high_density,
+            use_color: self.format != ResponseFormat::Text,
+            output_format,
+            invert: self.invert,
+            contrast: self.contrast.unwrap_or(defaults.contrast),
+            brightness: self.brightness.unwrap_or(defaults.brightness),
+            use_quadrants: self.quadrants,
+            custom_chars: self.chars.as_ref().map(|chars| chars.chars().collect()),
+            ..defaults
+        }
+    }
+}
+
+/// Membuat router dengan endpoint `POST /convert` dan batas upload default.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// #[tokio::main]
+/// async fn main() {
+///     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
+///     axum::serve(listener, aspix::router()).await.unwrap();
+/// }
+/// ```
+///
+/// Lalu dari shell:
+///
+/// ```text
+/// curl -F image=@foto.jpg -F 'config={"width":80,"format":"ansi"}' localhost:3000/convert
+/// ```
+pub fn router() -> Router {
+    Router::new()
+        .route("/convert", post(convert_handler))
+        .layer(DefaultBodyLimit::max(DEFAULT_UPLOAD_LIMIT))
+}
+
+/// Handler multipart untuk dipasang pada router milik aplikasi sendiri.
+///
+/// Mengembalikan `400 Bad Request` untuk form yang tidak valid atau ukuran
+/// output di atas `MAX_SERVER_DIMENSION`, `413 Payload Too Large` untuk gambar
+/// di atas `MAX_SERVER_PIXELS`, `415 Unsupported Media Type` untuk format gambar yang tidak
+/// dapat didekode, dan `422 Unprocessable Entity` jika gambar gagal dikonversi.
+pub async fn convert_handler(mut multipart: Multipart) -> Response {
+    let mut image = None;
+    let mut config = ServerConfig::default();
+
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            Err(e) => return error_response(StatusCode::BAD_REQUEST, format!("Gagal membaca form: {}", e)),
+        };
+        match field.name() {
+            Some("image") => match field.bytes().await {
+                Ok(bytes) => image = Some(bytes),
+                Err(e) => return error_response(StatusCode::BAD_REQUEST, format!("Gagal membaca gambar: {}", e)),
+            },
+            Some("config") => {
+                let text = match field.text().await {
+                    Ok(text) => text,
+                    Err(e) => return error_response(StatusCode::BAD_REQUEST, format!("Gagal membaca config: {}", e)),
+                };
+                config = match serde_json::from_str(&text) {
+                    Ok(config) => config,
+                    Err(e) => return error_response(StatusCode::BAD_REQUEST, format!("Config tidak valid: {}", e)),
+                };
+            }
+            _ => {}
+        }
+    }
+
+    let Some(image) = image else {
+        return error_response(StatusCode::BAD_REQUEST, "Field `image` wajib diisi".to_string());
+    };
+
+    let mut ascii_config = config.to_ascii_config();
+    ascii_config.limits.max_pixels = Some(MAX_SERVER_PIXELS);
+    if ascii_config.width.max(ascii_config.height) > MAX_SERVER_DIMENSION {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Ukuran output melebihi batas {} karakter", MAX_SERVER_DIMENSION),
+        );
+    }
+
+    let converter = AsciiConverter::with_config(ascii_config);
+    if let Err(limit) = converter.check_limits(&image) {
+        return error_response(StatusCode::PAYLOAD_TOO_LARGE, String::from(limit));
+    }
+    if let Err(unsupported) = converter.check_format(&image) {
+        return error_response(StatusCode::UNSUPPORTED_MEDIA_TYPE, String::from(unsupported));
+    }
+    match converter.convert_bytes_async(image.to_vec()).await {
+        Ok(body) => {
+            let content_type = match config.format {
+                ResponseFormat::Html => "text/html; charset=utf-8",
+                _ => "text/plain; charset=utf-8",
+            };
+            ([(header::CONTENT_TYPE, content_type)], body).into_response()
+        }
+        Err(e) => error_response(StatusCode::UNPROCESSABLE_ENTITY, e),
+    }
+}
+
+fn error_response(status: StatusCode, message: String) -> Response {
+    (status, message).into_response()
+}
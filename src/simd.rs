@@ -0,0 +1,67 @@
+//! Jalur cepat SIMD (feature `simd`) untuk luminance dan indeks karakter.
+//!
+//! Memproses 8 piksel sekaligus dengan `wide::f32x8`. Urutan operasi sama
+//! persis dengan jalur skalar sehingga hasil konversi identik.
+
+use wide::{f32x8, i32x8};
+
+/// Jumlah lane per vektor.
+const LANES: usize = 8;
+
+/// Menghitung luminance (0.0 - 1.0) setiap piksel RGBA8.
+///
+/// * `grayscale` - `true` memakai rumus luma sRGB bilangan bulat milik crate `image`
+///   (sama dengan `to_luma8`), `false` memakai bobot 0.3/0.59/0.11 untuk mode warna
+pub(crate) fn luminance(pixels: &[u8], grayscale: bool) -> Vec<f32> {
+    let mut output = Vec::with_capacity(pixels.len() / 4);
+    let mut chunks = pixels.chunks_exact(LANES * 4);
+
+    for chunk in &mut chunks {
+        let channel = |c: usize| f32x8::new(std::array::from_fn(|i| chunk[i * 4 + c] as f32));
+        let (r, g, b) = (channel(0), channel(1), channel(2));
+        let luma = if grayscale {
+            ((r * f32x8::splat(2126.0) + g * f32x8::splat(7152.0) + b * f32x8::splat(722.0))
+                / f32x8::splat(10000.0))
+            .floor()
+                / f32x8::splat(255.0)
+        } else {
+            let scale = f32x8::splat(255.0);
+            (r / scale) * f32x8::splat(0.3) + (g / scale) * f32x8::splat(0.59) + (b / scale) * f32x8::splat(0.11)
+        };
+        output.extend_from_slice(&luma.to_array());
+    }
+
+    output.extend(chunks.remainder().chunks_exact(4).map(|p| scalar_luminance([p[0], p[1], p[2]], grayscale)));
+    output
+}
+
+/// Versi skalar `luminance` untuk sisa piksel yang tidak memenuhi satu vektor.
+fn scalar_luminance([r, g, b]: [u8; 3], grayscale: bool) -> f32 {
+    if grayscale {
+        ((2126 * r as u32 + 7152 * g as u32 + 722 * b as u32) / 10000) as f32 / 255.0
+    } else {
+        (r as f32 / 255.0) * 0.3 + (g as f32 / 255.0) * 0.59 + (b as f32 / 255.0) * 0.11
+    }
+}
+
+/// Memetakan brightness ke indeks ramp dengan `levels` karakter.
+pub(crate) fn ramp_indices(brightness: &[f32], invert: bool, levels: usize) -> Vec<usize> {
+    let max_index = levels.saturating_sub(1);
+    let mut output = Vec::with_capacity(brightness.len());
+    let mut chunks = brightness.chunks_exact(LANES);
+
+    for chunk in &mut chunks {
+        let mut values = f32x8::new(chunk.try_into().expect("panjang chunk selalu 8"));
+        if invert {
+            values = f32x8::splat(1.0) - values;
+        }
+        let indices: i32x8 = (values * f32x8::splat(max_index as f32)).trunc_int();
+        output.extend(indices.to_array().iter().map(|&i| (i.max(0) as usize).min(max_index)));
+    }
+
+    output.extend(chunks.remainder().iter().map(|&value| {
+        let mapped = if invert { 1.0 - value } else { value };
+        ((mapped * max_index as f32) as usize).min(max_index)
+    }));
+    output
+}
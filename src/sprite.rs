@@ -0,0 +1,85 @@
+//! Pemotongan sprite sheet menjadi tile yang dikonversi satu per satu.
+
+use crate::{AsciiArt, AsciiConverter};
+use image::DynamicImage;
+use std::path::Path;
+
+impl AsciiConverter {
+    /// Memotong sprite sheet berbentuk grid lalu mengkonversi setiap tile secara terpisah.
+    ///
+    /// Setiap tile dikonversi dengan konfigurasi converter (termasuk `width` dan `height`).
+    /// Sheet selalu didekode pada resolusi penuh (dalam batas `limits`); `tile_height` pada
+    /// konfigurasi diabaikan.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_path` - Path ke file sprite sheet
+    /// * `tile_width` - Lebar satu tile dalam piksel
+    /// * `tile_height` - Tinggi satu tile dalam piksel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<AsciiArt>)` - Hasil konversi setiap tile, urut baris demi baris
+    /// * `Err(String)` - Pesan error jika gagal
+    pub fn convert_sprite_sheet(&self, image_path: &str, tile_width: u32, tile_height: u32) -> Result<Vec<AsciiArt>, String> {
+        let sheet = self.config().limits.open(Path::new(image_path))?;
+        self.convert_sprite_sheet_image(&sheet, tile_width, tile_height)
+    }
+
+    /// Memotong sprite sheet yang sudah ada di memory lalu mengkonversi setiap tile.
+    ///
+    /// Tile yang terpotong di tepi kanan atau bawah (sisa piksel yang tidak
+    /// memenuhi satu tile penuh) dilewati.
+    ///
+    /// # Arguments
+    ///
+    /// * `sheet` - Gambar sprite sheet
+    /// * `tile_width` - Lebar satu tile dalam piksel
+    /// * `tile_height` - Tinggi satu tile dalam piksel
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<AsciiArt>)` - Hasil konversi setiap tile, urut baris demi baris
+    /// * `Err(String)` - Pesan error jika ukuran tile tidak valid
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::AsciiConverter;
+    /// use image::{DynamicImage, GrayImage, Luma};
+    ///
+    /// // Sheet 2x2 tile berukuran 16x16: kiri gelap, kanan terang
+    /// let sheet = GrayImage::from_fn(32, 32, |x, _| Luma([if x < 16 { 0 } else { 255 }]));
+    /// let converter = AsciiConverter::new(4, 2);
+    /// let sprites = converter.convert_sprite_sheet_image(&DynamicImage::ImageLuma8(sheet), 16, 16).unwrap();
+    /// assert_eq!(sprites.len(), 4);
+    /// assert_eq!(sprites[0].to_text(), "@@@@\n@@@@\n");
+    /// assert_eq!(sprites[1].to_text(), "    \n    \n");
+    /// ```
+    pub fn convert_sprite_sheet_image(&self, sheet: &DynamicImage, tile_width: u32, tile_height: u32) -> Result<Vec<AsciiArt>, String> {
+        if tile_width == 0 || tile_height == 0 {
+            return Err("Ukuran tile harus lebih besar dari 0".to_string());
+        }
+
+        let columns = sheet.width() / tile_width;
+        let rows = sheet.height() / tile_height;
+        if columns == 0 || rows == 0 {
+            return Err(format!(
+                "Ukuran tile {}x{} lebih besar dari sprite sheet {}x{}",
+                tile_width,
+                tile_height,
+                sheet.width(),
+                sheet.height()
+            ));
+        }
+
+        let mut sprites = Vec::with_capacity((columns * rows) as usize);
+        for row in 0..rows {
+            for column in 0..columns {
+                let tile = sheet.crop_imm(column * tile_width, row * tile_height, tile_width, tile_height);
+                sprites.push(self.convert_image_to_art(&tile)?);
+            }
+        }
+        Ok(sprites)
+    }
+}
@@ -0,0 +1,214 @@
+//! Track subtitle SRT/WebVTT dan penempelan caption ke grid ASCII.
+
+use crate::{AsciiArt, Cell};
+use std::fs;
+use std::time::Duration;
+
+/// Warna default teks caption.
+pub const CAPTION_COLOR: [u8; 3] = [255, 255, 255];
+
+/// Satu cue subtitle.
+///
+/// * `start` - Waktu mulai tampil
+/// * `end` - Waktu selesai tampil (eksklusif)
+/// * `text` - Teks cue tanpa tag format, baris dipisah `\n`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubtitleCue {
+    pub start: Duration,
+    pub end: Duration,
+    pub text: String,
+}
+
+/// Daftar cue subtitle dari file SRT atau WebVTT.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::SubtitleTrack;
+/// use std::time::Duration;
+///
+/// let track = SubtitleTrack::parse("1\n00:00:01,000 --> 00:00:02,500\n<i>Halo</i> dunia\n").unwrap();
+/// assert_eq!(track.text_at(Duration::from_millis(1500)), Some("Halo dunia".to_string()));
+/// assert_eq!(track.text_at(Duration::from_secs(3)), None);
+///
+/// let vtt = SubtitleTrack::parse("WEBVTT\n\n00:01.000 --> 00:02.000 align:center\nHai\n").unwrap();
+/// assert_eq!(vtt.cues()[0].start, Duration::from_secs(1));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubtitleTrack {
+    cues: Vec<SubtitleCue>,
+}
+
+impl SubtitleTrack {
+    /// Membaca file subtitle `.srt` atau `.vtt`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SubtitleTrack)` - Track hasil parsing
+    /// * `Err(String)` - Pesan error jika file gagal dibaca atau formatnya tidak valid
+    pub fn open(path: &str) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| format!("Gagal membuka subtitle: {}", e))?;
+        Self::parse(&text)
+    }
+
+    /// Mem-parsing teks SRT atau WebVTT (format dideteksi dari isinya).
+    ///
+    /// Blok `NOTE`, `STYLE`, dan `REGION` pada WebVTT dilewati, pengaturan cue
+    /// setelah waktu selesai diabaikan, dan tag seperti `<i>` atau `<v Nama>` dihapus.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let text = text.trim_start_matches('\u{feff}').replace("\r\n", "\n");
+        let mut cues = Vec::new();
+
+        for block in text.split("\n\n").map(str::trim).filter(|block| !block.is_empty()) {
+            let mut lines = block.lines();
+            let Some(first) = lines.next() else {
+                continue;
+            };
+            if first.starts_with("WEBVTT") || ["NOTE", "STYLE", "REGION"].iter().any(|kind| first.starts_with(kind)) {
+                continue;
+            }
+
+            // Nomor urut SRT atau identifier cue WebVTT bersifat opsional
+            let timing = if first.contains("-->") {
+                first
+            } else {
+                match lines.next() {
+                    Some(line) if line.contains("-->") => line,
+                    _ => return Err(format!("Baris waktu subtitle tidak ditemukan: {}", first)),
+                }
+            };
+
+            let (start, end) = timing
+                .split_once("-->")
+                .expect("baris waktu selalu memuat -->");
+            let end = end.split_whitespace().next().unwrap_or_default();
+            let cue_text = lines.map(strip_tags).collect::<Vec<_>>().join("\n");
+
+            cues.push(SubtitleCue {
+                start: parse_timestamp(start.trim())?,
+                end: parse_timestamp(end)?,
+                text: cue_text,
+            });
+        }
+
+        cues.sort_by_key(|cue| cue.start);
+        Ok(Self { cues })
+    }
+
+    /// Semua cue, urut berdasarkan waktu mulai.
+    pub fn cues(&self) -> &[SubtitleCue] {
+        &self.cues
+    }
+
+    /// Teks yang tampil pada waktu `time`; cue yang tumpang tindih digabung per baris.
+    pub fn text_at(&self, time: Duration) -> Option<String> {
+        let active: Vec<&str> = self
+            .cues
+            .iter()
+            .take_while(|cue| cue.start <= time)
+            .filter(|cue| time < cue.end && !cue.text.is_empty())
+            .map(|cue| cue.text.as_str())
+            .collect();
+        (!active.is_empty()).then(|| active.join("\n"))
+    }
+}
+
+impl AsciiArt {
+    /// Menempelkan caption di baris-baris paling bawah grid.
+    ///
+    /// Teks dibungkus per kata sesuai lebar grid dan diratakan ke tengah;
+    /// setiap baris yang ditempati caption dikosongkan terlebih dahulu agar
+    /// teks mudah dibaca. Baris yang melebihi tinggi grid dibuang dari atas.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - Teks caption, boleh berisi beberapa baris
+    /// * `color` - Warna teks caption
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiArt, Cell, CAPTION_COLOR};
+    ///
+    /// let cell = Cell { ch: '#', brightness: 0.2, color: [0, 0, 0] };
+    /// let mut art = AsciiArt::new(10, 3, vec![cell; 30]).unwrap();
+    /// art.overlay_caption("halo semua", CAPTION_COLOR);
+    /// assert_eq!(art.to_text(), "##########\n##########\nhalo semua\n");
+    /// ```
+    pub fn overlay_caption(&mut self, text: &str, color: [u8; 3]) {
+        let width = self.width() as usize;
+        if width == 0 {
+            return;
+        }
+
+        let lines: Vec<Vec<char>> = text.lines().flat_map(|line| wrap_words(line, width)).collect();
+        let visible = lines.len().min(self.height() as usize);
+        let first_row = self.height() as usize - visible;
+
+        for (offset, line) in lines[lines.len() - visible..].iter().enumerate() {
+            let y = (first_row + offset) as u32;
+            let padding = (width - line.len()) / 2;
+            for x in 0..width {
+                let ch = x.checked_sub(padding).and_then(|i| line.get(i)).copied().unwrap_or(' ');
+                if let Some(cell) = self.get_mut(x as u32, y) {
+                    *cell = Cell { ch, brightness: 1.0, color };
+                }
+            }
+        }
+    }
+}
+
+/// Mem-parsing waktu `HH:MM:SS,mmm`, `HH:MM:SS.mmm`, atau `MM:SS.mmm`.
+fn parse_timestamp(text: &str) -> Result<Duration, String> {
+    let invalid = || format!("Format waktu subtitle tidak valid: {}", text);
+    let (clock, millis) = text.split_once([',', '.']).ok_or_else(invalid)?;
+    let millis: u64 = millis.parse().map_err(|_| invalid())?;
+
+    let mut seconds = 0u64;
+    for part in clock.split(':') {
+        seconds = seconds * 60 + part.parse::<u64>().map_err(|_| invalid())?;
+    }
+    Ok(Duration::from_millis(seconds * 1000 + millis))
+}
+
+/// Menghapus tag format seperti `<i>`, `</b>`, atau `<v Nama>`.
+fn strip_tags(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for ch in line.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => result.push(ch),
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Membungkus satu baris per kata; kata yang lebih panjang dari `width` dipotong paksa.
+fn wrap_words(line: &str, width: usize) -> Vec<Vec<char>> {
+    let mut lines = Vec::new();
+    let mut current: Vec<char> = Vec::new();
+
+    for word in line.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        if !current.is_empty() && current.len() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        while current.len() + word.len() > width {
+            let split = width - current.len();
+            current.extend(word.drain(..split));
+            lines.push(std::mem::take(&mut current));
+        }
+        current.extend(word);
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
@@ -0,0 +1,134 @@
+//! Penyisipan hasil konversi ke file template (halaman HTML, skrip shell, kode Rust).
+
+use crate::art::escape_html;
+use crate::AsciiConverter;
+use std::fs;
+
+/// Penanda default tempat hasil konversi disisipkan.
+pub const DEFAULT_PLACEHOLDER: &str = "{{ascii}}";
+
+/// Aturan escape hasil konversi sesuai konteks placeholder di template.
+///
+/// * `None` - Disisipkan apa adanya (misalnya output HTML lengkap atau file teks)
+/// * `Html` - Karakter khusus HTML di-escape, untuk teks biasa di dalam `<pre>`
+/// * `ShellSingleQuoted` - Untuk placeholder di dalam tanda kutip tunggal shell (`'{{ascii}}'`)
+/// * `RustString` - Untuk placeholder di dalam string literal Rust (`"{{ascii}}"`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TemplateEscape {
+    #[default]
+    None,
+    Html,
+    ShellSingleQuoted,
+    RustString,
+}
+
+/// Template output dengan penanda tempat hasil konversi disisipkan.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{OutputTemplate, TemplateEscape};
+///
+/// let template = OutputTemplate::new("pub const LOGO: &str = \"{{ascii}}\";\n")
+///     .with_escape(TemplateEscape::RustString);
+/// let source = template.render("@\"\\\n").unwrap();
+/// assert_eq!(source, "pub const LOGO: &str = \"@\\\"\\\\\\n\";\n");
+///
+/// let script = OutputTemplate::new("echo '{{ascii}}'").with_escape(TemplateEscape::ShellSingleQuoted);
+/// assert_eq!(script.render("it's").unwrap(), "echo 'it'\\''s'");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputTemplate {
+    content: String,
+    placeholder: String,
+    escape: TemplateEscape,
+}
+
+impl OutputTemplate {
+    /// Membuat template dari string dengan penanda `DEFAULT_PLACEHOLDER`.
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+            placeholder: DEFAULT_PLACEHOLDER.to_string(),
+            escape: TemplateEscape::None,
+        }
+    }
+
+    /// Membaca template dari file.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(OutputTemplate)` - Template dari file
+    /// * `Err(String)` - Pesan error jika file gagal dibaca
+    pub fn open(template_path: &str) -> Result<Self, String> {
+        let content = fs::read_to_string(template_path)
+            .map_err(|e| format!("Gagal membaca template: {}", e))?;
+        Ok(Self::new(&content))
+    }
+
+    /// Mengganti penanda tempat hasil disisipkan.
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = placeholder.to_string();
+        self
+    }
+
+    /// Mengatur aturan escape hasil konversi.
+    pub fn with_escape(mut self, escape: TemplateEscape) -> Self {
+        self.escape = escape;
+        self
+    }
+
+    /// Menyisipkan `ascii` di setiap penanda pada template.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - Template yang sudah terisi
+    /// * `Err(String)` - Pesan error jika penanda tidak ditemukan
+    pub fn render(&self, ascii: &str) -> Result<String, String> {
+        if self.placeholder.is_empty() || !self.content.contains(&self.placeholder) {
+            return Err(format!("Penanda `{}` tidak ditemukan di template", self.placeholder));
+        }
+        Ok(self.content.replace(&self.placeholder, &escape(ascii, self.escape)))
+    }
+}
+
+impl AsciiConverter {
+    /// Menyisipkan hasil ASCII art ke template lalu menyimpannya ke file.
+    ///
+    /// # Arguments
+    ///
+    /// * `ascii` - String ASCII art yang akan disisipkan
+    /// * `template` - Template dengan penanda tempat hasil disisipkan
+    /// * `output_path` - Path file output
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Jika berhasil menyimpan
+    /// * `Err(String)` - Pesan error jika penanda tidak ditemukan atau file gagal disimpan
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, OutputTemplate, TemplateEscape};
+    ///
+    /// let converter = AsciiConverter::new(100, 50);
+    /// if let (Ok(ascii), Ok(template)) = (converter.convert("logo.png"), OutputTemplate::open("page.html")) {
+    ///     let template = template.with_escape(TemplateEscape::Html);
+    ///     converter.save_with_template(&ascii, &template, "index.html").unwrap();
+    /// }
+    /// ```
+    pub fn save_with_template(&self, ascii: &str, template: &OutputTemplate, output_path: &str) -> Result<(), String> {
+        let rendered = template.render(ascii)?;
+        self.save_to_file(&rendered, output_path)
+    }
+}
+
+/// Menerapkan aturan escape pada hasil konversi.
+fn escape(ascii: &str, rule: TemplateEscape) -> String {
+    match rule {
+        TemplateEscape::None => ascii.to_string(),
+        TemplateEscape::Html => escape_html(ascii),
+        TemplateEscape::ShellSingleQuoted => ascii.replace('\'', "'\\''"),
+        TemplateEscape::RustString => ascii.escape_debug().to_string(),
+    }
+}
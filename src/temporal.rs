@@ -0,0 +1,84 @@
+//! Penghalusan temporal antar frame animasi untuk mengurangi flicker.
+//!
+//! Setiap frame dikuantisasi ke ramp secara terpisah, sehingga noise kecil
+//! pada video atau GIF membuat sel berganti karakter bolak-balik di sekitar
+//! batas tingkat ramp.
+
+use crate::{AsciiArt, Cell};
+
+/// Strategi penghalusan temporal.
+///
+/// * `Blend` - Brightness dan warna setiap sel dicampur dengan frame sebelumnya
+///   (`alpha` 1.0 berarti tanpa penghalusan, nilai kecil lebih stabil tetapi
+///   gerakan tampak tertinggal); glyph dipilih ulang dari brightness hasil campuran
+/// * `Hysteresis` - Sel mempertahankan tampilan frame sebelumnya sampai
+///   brightness-nya berubah lebih dari `threshold` (0.0 - 1.0)
+///
+/// Sel yang glyph-nya bukan hasil langsung ramp (mode quadrant, dithering,
+/// hook sel, atau background removal) tidak dipilih ulang oleh `Blend`.
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AnimationConverter, AsciiConfig, ImageSequence, TemporalSmoothing};
+/// use image::{DynamicImage, GrayImage, Luma};
+/// use std::time::Duration;
+///
+/// // Brightness bergetar di sekitar batas dua tingkat ramp
+/// let frames = [120u8, 108, 120, 108]
+///     .iter()
+///     .map(|&value| DynamicImage::ImageLuma8(GrayImage::from_pixel(4, 2, Luma([value]))))
+///     .collect();
+/// let mut source = ImageSequence::new(frames, Duration::from_millis(40));
+///
+/// let converter = AnimationConverter::new(AsciiConfig { width: 2, height: 1, ..Default::default() })
+///     .with_smoothing(TemporalSmoothing::Hysteresis { threshold: 0.1 });
+/// let frames = converter.convert_all(&mut source).unwrap();
+/// assert!(frames.iter().all(|frame| frame.content == frames[0].content));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemporalSmoothing {
+    Blend { alpha: f32 },
+    Hysteresis { threshold: f32 },
+}
+
+impl TemporalSmoothing {
+    /// Menghaluskan grid `current` terhadap grid frame sebelumnya.
+    ///
+    /// `glyph` memetakan brightness ke glyph ramp (lihat `AsciiConverter::glyph_mapper`).
+    pub(crate) fn apply(&self, current: AsciiArt, previous: &AsciiArt, glyph: Option<&dyn Fn(f32) -> char>) -> AsciiArt {
+        if previous.width() != current.width() || previous.height() != current.height() {
+            return current;
+        }
+
+        let cells = current
+            .cells()
+            .iter()
+            .zip(previous.cells())
+            .map(|(&cell, &before)| match *self {
+                TemporalSmoothing::Blend { alpha } => {
+                    let alpha = alpha.clamp(0.0, 1.0);
+                    let brightness = before.brightness + (cell.brightness - before.brightness) * alpha;
+                    let color = [0, 1, 2].map(|i| {
+                        (before.color[i] as f32 + (cell.color[i] as f32 - before.color[i] as f32) * alpha).round() as u8
+                    });
+                    // Glyph hanya dipilih ulang jika sel memang berasal dari ramp
+                    let ch = match glyph {
+                        Some(glyph) if glyph(cell.brightness) == cell.ch => glyph(brightness),
+                        _ => cell.ch,
+                    };
+                    Cell { ch, brightness, color }
+                }
+                TemporalSmoothing::Hysteresis { threshold } => {
+                    if (cell.brightness - before.brightness).abs() <= threshold {
+                        before
+                    } else {
+                        cell
+                    }
+                }
+            })
+            .collect();
+
+        AsciiArt::new(current.width(), current.height(), cells).expect("dimensi grid sama dengan frame sebelumnya")
+    }
+}
@@ -0,0 +1,218 @@
+//! Helper snapshot (golden file) untuk regression test output ASCII art.
+//!
+//! Output dinormalisasi terlebih dahulu (pemisah baris, spasi di akhir baris,
+//! dan opsional escape warna ANSI) agar perbedaan platform atau terminal tidak
+//! membuat test gagal, lalu dibandingkan dengan file snapshot. Jika berbeda,
+//! pesan error memuat diff per baris yang mudah dibaca.
+//!
+//! Snapshot yang belum ada membuat test gagal agar snapshot yang lupa di-commit
+//! tidak lolos diam-diam di CI. Set environment variable `ASPIX_UPDATE_SNAPSHOTS=1`
+//! untuk membuat snapshot baru atau menimpa snapshot lama dengan output terbaru.
+
+use std::fs;
+use std::path::Path;
+
+/// Environment variable untuk membuat atau menimpa snapshot dengan output terbaru.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "ASPIX_UPDATE_SNAPSHOTS";
+
+/// Opsi normalisasi output sebelum dibandingkan.
+///
+/// * `line_endings` - Mengubah `\r\n` menjadi `\n`
+/// * `trim_trailing_whitespace` - Menghapus spasi di akhir setiap baris
+/// * `strip_ansi` - Menghapus escape ANSI (warna, posisi kursor) sehingga hanya karakter yang dibandingkan
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::testing::{normalize, NormalizeOptions};
+///
+/// let options = NormalizeOptions { strip_ansi: true, ..Default::default() };
+/// assert_eq!(normalize("\x1b[38;2;255;0;0m## \x1b[0m\r\n", &options), "##\n");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    pub line_endings: bool,
+    pub trim_trailing_whitespace: bool,
+    pub strip_ansi: bool,
+}
+
+impl Default for NormalizeOptions {
+    /// Menormalisasi pemisah baris dan spasi akhir, tanpa menghapus warna.
+    fn default() -> Self {
+        Self {
+            line_endings: true,
+            trim_trailing_whitespace: true,
+            strip_ansi: false,
+        }
+    }
+}
+
+/// Menormalisasi output sesuai opsi.
+///
+/// Output yang tidak kosong selalu diakhiri tepat satu `\n` agar perbedaan
+/// newline di akhir file tidak dianggap perubahan.
+pub fn normalize(output: &str, options: &NormalizeOptions) -> String {
+    let stripped = if options.strip_ansi { strip_ansi(output) } else { output.to_string() };
+    let text = if options.line_endings { stripped.replace("\r\n", "\n") } else { stripped };
+
+    let mut normalized = String::with_capacity(text.len());
+    for line in text.trim_end_matches('\n').split('\n') {
+        normalized.push_str(if options.trim_trailing_whitespace { line.trim_end_matches([' ', '\t']) } else { line });
+        normalized.push('\n');
+    }
+    if text.is_empty() {
+        normalized.clear();
+    }
+    normalized
+}
+
+/// Membandingkan output dengan file snapshot setelah normalisasi.
+///
+/// Jika `ASPIX_UPDATE_SNAPSHOTS` diisi, snapshot ditulis dari output (beserta direktori
+/// induknya) tanpa dibandingkan; tanpa variabel tersebut, snapshot yang belum ada
+/// menghasilkan error. Isi snapshot ikut dinormalisasi dengan opsi yang sama sebelum
+/// dibandingkan.
+///
+/// # Arguments
+///
+/// * `path` - Path file snapshot
+/// * `output` - Output yang diuji
+/// * `options` - Opsi normalisasi
+///
+/// # Returns
+///
+/// * `Ok(())` - Jika output sama dengan snapshot (atau snapshot ditulis ulang)
+/// * `Err(String)` - Diff per baris jika berbeda, pesan jika snapshot belum ada, atau pesan error I/O
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::testing::{compare_snapshot, NormalizeOptions};
+///
+/// let path = std::env::temp_dir().join("aspix-snapshot-doc/logo.txt");
+/// let _ = std::fs::remove_file(&path);
+/// let options = NormalizeOptions::default();
+///
+/// // Snapshot yang belum ada tidak dibuat diam-diam
+/// assert!(compare_snapshot(&path, "@@\n..\n", &options).unwrap_err().contains("belum ada"));
+///
+/// std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+/// std::fs::write(&path, "@@ \r\n..\r\n").unwrap();
+/// compare_snapshot(&path, "@@\n..\n", &options).unwrap();
+///
+/// let error = compare_snapshot(&path, "@@\n:.\n", &options).unwrap_err();
+/// assert!(error.contains("- ..") && error.contains("+ :."));
+/// ```
+pub fn compare_snapshot(path: impl AsRef<Path>, output: &str, options: &NormalizeOptions) -> Result<(), String> {
+    let path = path.as_ref();
+    let actual = normalize(output, options);
+
+    let update = std::env::var_os(UPDATE_SNAPSHOTS_ENV).is_some_and(|value| !value.is_empty() && value != "0");
+    if update {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Gagal membuat direktori snapshot: {}", e))?;
+        }
+        return fs::write(path, &actual).map_err(|e| format!("Gagal menulis snapshot: {}", e));
+    }
+    if !path.exists() {
+        return Err(format!(
+            "Snapshot {} belum ada (set {}=1 untuk membuatnya)",
+            path.display(),
+            UPDATE_SNAPSHOTS_ENV
+        ));
+    }
+
+    let stored = fs::read_to_string(path).map_err(|e| format!("Gagal membaca snapshot: {}", e))?;
+    let expected = normalize(&stored, options);
+    if expected == actual {
+        return Ok(());
+    }
+
+    Err(format!(
+        "Output berbeda dengan snapshot {} (set {}=1 untuk memperbarui)\n{}",
+        path.display(),
+        UPDATE_SNAPSHOTS_ENV,
+        diff_lines(&expected, &actual)
+    ))
+}
+
+/// Seperti `compare_snapshot`, tetapi panic dengan diff jika berbeda; cocok dipakai di dalam `#[test]`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use aspix::{testing, AsciiConverter};
+///
+/// let art = AsciiConverter::new(40, 20).convert("tests/fixtures/logo.png").unwrap();
+/// testing::assert_snapshot("tests/snapshots/logo.html", &art, &Default::default());
+/// ```
+#[track_caller]
+pub fn assert_snapshot(path: impl AsRef<Path>, output: &str, options: &NormalizeOptions) {
+    if let Err(message) = compare_snapshot(path, output, options) {
+        panic!("{}", message);
+    }
+}
+
+/// Membuat diff per baris antara `expected` dan `actual`.
+///
+/// Baris yang hanya ada di `expected` diawali `- `, yang hanya ada di `actual`
+/// diawali `+ `, dan baris yang sama hanya diberi indentasi. Setiap baris berbeda
+/// diberi nomor baris pada `actual` (atau `expected` untuk baris yang dihapus).
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::testing::diff_lines;
+///
+/// assert_eq!(diff_lines("@@\n..\n", "@@\n:.\n"), "     @@\n2  - ..\n2  + :.\n");
+/// ```
+pub fn diff_lines(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // Panjang longest common subsequence untuk setiap pasangan sufiks
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    let mut output = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            output.push_str(&format!("     {}\n", new[j]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            output.push_str(&format!("{:<3}- {}\n", i + 1, old[i]));
+            i += 1;
+        } else {
+            output.push_str(&format!("{:<3}+ {}\n", j + 1, new[j]));
+            j += 1;
+        }
+    }
+    output
+}
+
+/// Menghapus escape ANSI: sekuens CSI (`ESC[...X`) dan escape dua karakter lainnya.
+fn strip_ansi(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\x1b' {
+            output.push(ch);
+            continue;
+        }
+        if chars.next() == Some('[') {
+            // Parameter CSI diakhiri satu byte final 0x40..=0x7E
+            for ch in chars.by_ref() {
+                if ('\x40'..='\x7e').contains(&ch) {
+                    break;
+                }
+            }
+        }
+    }
+    output
+}
@@ -0,0 +1,210 @@
+//! Penyesuaian arah ramp dan latar HTML terhadap tema terminal terang atau gelap.
+
+use crate::converter::color_luma;
+use std::sync::OnceLock;
+
+/// Batas waktu menunggu balasan query OSC 11 dari terminal.
+#[cfg(all(unix, feature = "theme-query"))]
+const OSC_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(100);
+
+/// Panjang maksimum balasan OSC 11 yang dibaca.
+#[cfg(all(unix, feature = "theme-query"))]
+const OSC_MAX_RESPONSE: usize = 64;
+
+/// Tema latar tempat art ditampilkan.
+///
+/// Ramp bawaan memetakan area gelap ke glyph padat, yang benar untuk latar
+/// terang (tinta gelap di atas kertas). Pada latar gelap, glyph padat justru
+/// tampak terang, sehingga `Dark` membalik arah ramp (setara `invert`).
+///
+/// * `Dark` - Latar gelap: ramp dibalik, latar HTML hitam
+/// * `Light` - Latar terang: ramp apa adanya, latar HTML putih
+/// * `Auto` - Mengikuti terminal pengguna; dideteksi dengan `Theme::detect` saat
+///   pertama kali dipakai untuk pemetaan atau render, lalu hasilnya dipakai ulang
+///   selama proses berjalan
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, AsciiConverter, Theme};
+/// use image::DynamicImage;
+///
+/// let converter = AsciiConverter::with_config(AsciiConfig {
+///     width: 4,
+///     height: 1,
+///     theme: Some(Theme::Dark),
+///     ..Default::default()
+/// });
+/// // Gambar hitam tidak menyalakan piksel apa pun di terminal gelap
+/// assert_eq!(converter.convert_image(&DynamicImage::new_rgb8(8, 2)).unwrap(), "    \n");
+///
+/// // Pada tema terang, teks dokumen HTML dan pengisi kanvas berwarna hitam
+/// use aspix::{Canvas, OutputFormat};
+///
+/// let converter = AsciiConverter::with_config(AsciiConfig {
+///     width: 1,
+///     height: 1,
+///     use_color: true,
+///     output_format: OutputFormat::Html,
+///     canvas: Some(Canvas { width: 2, height: 1, fill_char: '.', ..Default::default() }),
+///     theme: Some(Theme::Light),
+///     ..Default::default()
+/// });
+/// let html = converter.convert_image(&DynamicImage::new_rgb8(2, 2)).unwrap();
+/// assert!(html.contains("background-color: #fff; color: #000;"));
+/// assert!(html.contains("<span style=\"color:rgb(0,0,0)\">.</span>"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Theme {
+    Dark,
+    Light,
+    Auto,
+}
+
+impl Theme {
+    /// Mendeteksi tema terminal saat ini.
+    ///
+    /// Urutan deteksi: query warna latar OSC 11 ke `/dev/tty`, lalu variabel
+    /// `COLORFGBG`, dan jika keduanya tidak tersedia dianggap `Dark`. Selalu
+    /// menghasilkan `Dark` atau `Light`.
+    ///
+    /// Query OSC 11 hanya tersedia di Unix dengan feature `theme-query`. Tanpa
+    /// feature itu terminal tidak pernah ditanya, dan deteksi langsung memakai
+    /// `COLORFGBG` atau `Dark` tanpa pemberitahuan apa pun.
+    ///
+    /// Query OSC 11 mengaktifkan raw mode sesaat dan menunggu balasan hingga
+    /// 100 ms; `Theme::Auto` memanggilnya sekali per proses.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::Theme;
+    ///
+    /// assert_ne!(Theme::detect(), Theme::Auto);
+    /// ```
+    pub fn detect() -> Theme {
+        #[cfg(all(unix, feature = "theme-query"))]
+        if let Some(background) = query_background() {
+            return Theme::from_background(background);
+        }
+        colorfgbg_theme().unwrap_or(Theme::Dark)
+    }
+
+    /// Menentukan tema dari warna latar: `Light` jika luminance-nya di atas setengah.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::Theme;
+    ///
+    /// assert_eq!(Theme::from_background([253, 246, 227]), Theme::Light);
+    /// assert_eq!(Theme::from_background([40, 42, 54]), Theme::Dark);
+    /// ```
+    pub fn from_background(color: [u8; 3]) -> Theme {
+        if color_luma(color) > 0.5 {
+            Theme::Light
+        } else {
+            Theme::Dark
+        }
+    }
+
+    /// Mengembalikan tema efektif: `Auto` diganti hasil `detect`, tema lain apa adanya.
+    ///
+    /// Deteksi untuk `Auto` hanya dijalankan sekali per proses dan hasilnya disimpan.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::Theme;
+    ///
+    /// assert_eq!(Theme::Light.resolve(), Theme::Light);
+    /// assert_eq!(Theme::Auto.resolve(), Theme::Auto.resolve());
+    /// assert_ne!(Theme::Auto.resolve(), Theme::Auto);
+    /// ```
+    pub fn resolve(self) -> Theme {
+        static DETECTED: OnceLock<Theme> = OnceLock::new();
+        match self {
+            Theme::Auto => *DETECTED.get_or_init(Theme::detect),
+            theme => theme,
+        }
+    }
+}
+
+/// Warna teks netral (label, pengisi kanvas) yang terbaca di atas latar tema.
+pub(crate) fn text_color(theme: Option<Theme>) -> [u8; 3] {
+    match theme.map(Theme::resolve) {
+        Some(Theme::Light) => [0, 0, 0],
+        _ => [255, 255, 255],
+    }
+}
+
+/// Mengganti latar dan warna teks dokumen HTML bawaan (putih di atas hitam) sesuai tema.
+pub(crate) fn themed_html(html: String, theme: Option<Theme>) -> String {
+    match theme.map(Theme::resolve) {
+        Some(Theme::Light) => html.replacen("background-color: #000; color: #fff;", "background-color: #fff; color: #000;", 1),
+        _ => html,
+    }
+}
+
+/// Tema dari `COLORFGBG` (`"fg;bg"`), yang diisi oleh rxvt, Konsole, dan beberapa terminal lain.
+fn colorfgbg_theme() -> Option<Theme> {
+    let value = std::env::var("COLORFGBG").ok()?;
+    let background: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    // Indeks 7 (abu-abu terang) dan 9-15 (varian terang) adalah latar terang
+    Some(if background == 7 || (9..=15).contains(&background) { Theme::Light } else { Theme::Dark })
+}
+
+/// Menanyakan warna latar terminal lewat OSC 11 dalam raw mode.
+///
+/// Balasan dibaca byte demi byte dengan `poll`, sehingga terminal yang tidak
+/// mendukung query hanya membuat deteksi gagal setelah `OSC_TIMEOUT` tanpa
+/// meninggalkan pembaca yang masih menunggu input.
+#[cfg(all(unix, feature = "theme-query"))]
+fn query_background() -> Option<[u8; 3]> {
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsRawFd;
+    use std::time::Instant;
+
+    let mut tty = std::fs::OpenOptions::new().read(true).write(true).open("/dev/tty").ok()?;
+    crossterm::terminal::enable_raw_mode().ok()?;
+    let response = (|| {
+        tty.write_all(b"\x1b]11;?\x07").ok()?;
+        tty.flush().ok()?;
+
+        let deadline = Instant::now() + OSC_TIMEOUT;
+        let mut response = Vec::new();
+        let mut byte = [0u8; 1];
+        while response.len() < OSC_MAX_RESPONSE {
+            let remaining = deadline.checked_duration_since(Instant::now())?;
+            let mut descriptor = libc::pollfd { fd: tty.as_raw_fd(), events: libc::POLLIN, revents: 0 };
+            // SAFETY: `descriptor` valid selama panggilan dan jumlahnya tepat satu
+            let ready = unsafe { libc::poll(&mut descriptor, 1, remaining.as_millis().max(1) as libc::c_int) };
+            if ready <= 0 || tty.read(&mut byte).ok()? != 1 {
+                return None;
+            }
+            response.push(byte[0]);
+            // Balasan diakhiri BEL atau ST (`ESC \`)
+            if byte[0] == 0x07 || response.ends_with(b"\x1b\\") {
+                break;
+            }
+        }
+        Some(response)
+    })();
+    let _ = crossterm::terminal::disable_raw_mode();
+
+    parse_osc11(&String::from_utf8_lossy(&response?))
+}
+
+/// Mengurai balasan `ESC ] 11 ; rgb:RRRR/GGGG/BBBB` menjadi warna 8-bit.
+#[cfg(all(unix, feature = "theme-query"))]
+fn parse_osc11(response: &str) -> Option<[u8; 3]> {
+    let rgb = &response[response.find("rgb:")? + 4..];
+    let mut channels = rgb.split('/').map(|channel| {
+        let digits: String = channel.chars().take_while(char::is_ascii_hexdigit).collect();
+        let value = u32::from_str_radix(&digits, 16).ok()?;
+        // Setiap kanal berisi 1-4 digit hex; skalakan ke 0-255
+        let max = (1u32 << (4 * digits.len().min(4) as u32)) - 1;
+        Some((value * 255 / max) as u8)
+    });
+    Some([channels.next()??, channels.next()??, channels.next()??])
+}
@@ -0,0 +1,308 @@
+//! Pipeline tiled untuk gambar berukuran sangat besar.
+//!
+//! Resize penuh dengan `FilterType::Lanczos3` membutuhkan buffer perantara
+//! berukuran `lebar_target × tinggi_sumber` dalam `f32`, sehingga panorama
+//! ratusan megapiksel dapat menghabiskan memori. Modul ini melakukan
+//! down-sampling per strip baris sehingga memori tambahan hanya sebanding
+//! dengan ukuran target.
+//!
+//! Saat membuka file, gambar juga tidak perlu di-decode pada resolusi penuh:
+//! JPEG memakai decode yang diperkecil (DCT scaling), PNG di-decode baris demi
+//! baris, dan TIFF strip/tile demi strip/tile, masing-masing langsung ke
+//! akumulator berukuran kecil.
+
+use crate::linear::{linear_table, linear_to_u8, resize_linear};
+use crate::{ConversionError, ResourceLimits};
+use image::codecs::jpeg::JpegDecoder;
+use image::imageops::FilterType;
+use image::{io::Reader as ImageReader, DynamicImage, GenericImageView, ImageFormat, Rgba, RgbaImage};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Cursor, Read, Seek};
+use std::path::Path;
+use tiff::decoder::{ChunkType, Decoder as TiffDecoder, DecodingResult};
+use tiff::tags::Tag;
+use tiff::ColorType as TiffColorType;
+
+/// Membuka gambar dari path dengan decode yang diperkecil bila formatnya mendukung.
+///
+/// Hasilnya mempertahankan rasio aspek sumber dan tidak lebih kecil dari ukuran
+/// target, sehingga rotasi, crop, dan resize akhir tetap bekerja seperti biasa.
+///
+/// * JPEG - decoder diminta langsung menghasilkan ukuran terkecil yang masih
+///   lebih besar atau sama dengan ukuran target (hingga 1/8)
+/// * PNG non-interlaced - di-decode baris demi baris ke akumulator rata-rata area
+/// * TIFF chunky 8/16-bit (grayscale/RGB, dengan atau tanpa alpha) - di-decode per
+///   strip atau tile ke akumulator yang sama
+///
+/// PNG interlaced, TIFF planar atau bertipe warna lain, dan format lainnya
+/// di-decode pada resolusi penuh dengan batas alokasi dari `limits`.
+pub(crate) fn open_downscaled(
+    path: &Path,
+    target_width: u32,
+    target_height: u32,
+    linear: bool,
+    limits: &ResourceLimits,
+) -> Result<DynamicImage, ConversionError> {
+    let reader = ImageReader::open(path)
+        .map_err(|e| format!("Gagal membuka gambar: {}", e))?
+        .with_guessed_format()
+        .map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+    let open = || File::open(path).map(BufReader::new).map_err(|e| format!("Gagal membuka gambar: {}", e));
+    downscale(reader, open, target_width, target_height, linear, limits)
+}
+
+/// Seperti `open_downscaled`, untuk data gambar yang sudah ada di memory.
+pub(crate) fn decode_downscaled(
+    bytes: &[u8],
+    target_width: u32,
+    target_height: u32,
+    linear: bool,
+    limits: &ResourceLimits,
+) -> Result<DynamicImage, ConversionError> {
+    let reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| format!("Gagal membuka gambar: {}", e))?;
+    downscale(reader, || Ok(Cursor::new(bytes)), target_width, target_height, linear, limits)
+}
+
+/// Memilih decode yang diperkecil sesuai format; `open` membuka ulang sumber dari awal.
+fn downscale<R: BufRead + Seek, S: BufRead + Seek>(
+    reader: ImageReader<R>,
+    open: impl Fn() -> Result<S, String>,
+    target_width: u32,
+    target_height: u32,
+    linear: bool,
+    limits: &ResourceLimits,
+) -> Result<DynamicImage, ConversionError> {
+    let streamed = match reader.format() {
+        Some(ImageFormat::Jpeg) => {
+            let mut decoder = JpegDecoder::new(open()?).map_err(|e| format!("Gagal mendekode gambar: {}", e))?;
+            decoder
+                .scale(clamp_u16(target_width), clamp_u16(target_height))
+                .map_err(|e| format!("Gagal mendekode gambar: {}", e))?;
+            return Ok(DynamicImage::from_decoder(decoder).map_err(|e| format!("Gagal mendekode gambar: {}", e))?);
+        }
+        Some(ImageFormat::Png) => decode_png_reduced(open()?, target_width, target_height, linear)?,
+        Some(ImageFormat::Tiff) => decode_tiff_reduced(open()?, target_width, target_height, linear)?,
+        _ => None,
+    };
+
+    match streamed {
+        Some(image) => Ok(image),
+        None => {
+            let image = limits.decode(reader).map_err(|e| format!("Gagal mendekode gambar: {}", e))?;
+            limits.check_decoded(&image)?;
+            Ok(image)
+        }
+    }
+}
+
+/// Mendekode PNG baris demi baris; `None` jika gambar interlaced.
+fn decode_png_reduced<R: Read>(
+    file: R,
+    target_width: u32,
+    target_height: u32,
+    linear: bool,
+) -> Result<Option<DynamicImage>, String> {
+    let error = |e: png::DecodingError| format!("Gagal mendekode PNG: {}", e);
+    let mut decoder = png::Decoder::new(file);
+    // Palet dan kedalaman di bawah 8 bit diperluas, 16 bit dipotong ke 8 bit
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(error)?;
+    let (width, height, interlaced) = {
+        let info = reader.info();
+        (info.width, info.height, info.interlaced)
+    };
+    if interlaced {
+        return Ok(None);
+    }
+    let channels = match reader.output_color_type().0 {
+        png::ColorType::Grayscale => 1,
+        png::ColorType::GrayscaleAlpha => 2,
+        png::ColorType::Rgb => 3,
+        png::ColorType::Rgba => 4,
+        png::ColorType::Indexed => return Ok(None),
+    };
+
+    let (reduced_width, reduced_height) = reduced_size(width, height, target_width, target_height);
+    let mut accumulator = AreaAccumulator::new((width, height), (reduced_width, reduced_height), linear);
+    let mut y = 0;
+    while let Some(row) = reader.next_row().map_err(error)? {
+        accumulator.add_row(y, row.data(), channels);
+        y += 1;
+    }
+    Ok(Some(accumulator.finish()))
+}
+
+/// Mendekode TIFF per strip atau tile; `None` jika tata letak atau tipe warnanya tidak didukung.
+fn decode_tiff_reduced<R: Read + Seek>(
+    file: R,
+    target_width: u32,
+    target_height: u32,
+    linear: bool,
+) -> Result<Option<DynamicImage>, String> {
+    let error = |e: tiff::TiffError| format!("Gagal mendekode TIFF: {}", e);
+    let mut decoder = TiffDecoder::new(file).map_err(error)?;
+    let (width, height) = decoder.dimensions().map_err(error)?;
+    let channels = match decoder.colortype().map_err(error)? {
+        TiffColorType::Gray(8 | 16) => 1,
+        TiffColorType::GrayA(8 | 16) => 2,
+        TiffColorType::RGB(8 | 16) => 3,
+        TiffColorType::RGBA(8 | 16) => 4,
+        _ => return Ok(None),
+    };
+    // Planar (PlanarConfiguration = 2) menyimpan setiap channel di chunk terpisah
+    if decoder.find_tag_unsigned::<u16>(Tag::PlanarConfiguration).map_err(error)?.unwrap_or(1) != 1 {
+        return Ok(None);
+    }
+
+    let (chunk_width, chunk_height) = decoder.chunk_dimensions();
+    let (chunk_count, chunks_across) = match decoder.get_chunk_type() {
+        ChunkType::Strip => (decoder.strip_count().map_err(error)?, 1),
+        ChunkType::Tile => (decoder.tile_count().map_err(error)?, width.div_ceil(chunk_width.max(1))),
+    };
+
+    let (reduced_width, reduced_height) = reduced_size(width, height, target_width, target_height);
+    let mut accumulator = AreaAccumulator::new((width, height), (reduced_width, reduced_height), linear);
+    for index in 0..chunk_count {
+        let (data_width, data_height) = decoder.chunk_data_dimensions(index);
+        let data = match decoder.read_chunk(index).map_err(error)? {
+            DecodingResult::U8(data) => data,
+            DecodingResult::U16(data) => data.into_iter().map(|v| (v >> 8) as u8).collect(),
+            _ => return Ok(None),
+        };
+        let left = (index % chunks_across) * chunk_width;
+        let top = (index / chunks_across) * chunk_height;
+        let row_len = data_width as usize * channels;
+        for (y, row) in data.chunks_exact(row_len).take(data_height as usize).enumerate() {
+            accumulator.add_span(left, top + y as u32, row, channels);
+        }
+    }
+    Ok(Some(accumulator.finish()))
+}
+
+/// Ukuran decode yang diperkecil dengan faktor bulat, tetap tidak lebih kecil dari target.
+fn reduced_size(width: u32, height: u32, target_width: u32, target_height: u32) -> (u32, u32) {
+    let factor = (width / target_width.max(1)).min(height / target_height.max(1)).max(1);
+    (width.div_ceil(factor), height.div_ceil(factor))
+}
+
+/// Memperkecil gambar dengan rata-rata area, diproses per strip baris.
+///
+/// Setiap piksel sumber dijumlahkan ke sel target yang menaunginya, sehingga
+/// hanya akumulator seukuran target yang dialokasikan. Jika gambar sumber
+/// lebih kecil dari target pada salah satu sumbu, fungsi ini kembali ke
+/// resize biasa karena tidak ada yang perlu di-down-sample. Dengan `linear`,
+/// warna dirata-rata di ruang linear-light.
+pub(crate) fn downsample_in_strips(
+    img: &DynamicImage,
+    target_width: u32,
+    target_height: u32,
+    tile_height: u32,
+    linear: bool,
+) -> DynamicImage {
+    let (width, height) = img.dimensions();
+    if width < target_width || height < target_height || target_width == 0 || target_height == 0 {
+        return if linear {
+            resize_linear(img, target_width, target_height, FilterType::Triangle)
+        } else {
+            img.resize_exact(target_width, target_height, FilterType::Triangle)
+        };
+    }
+    let mut accumulator = AreaAccumulator::new((width, height), (target_width, target_height), linear);
+    let strip = tile_height.max(1);
+    let mut strip_y = 0;
+    while strip_y < height {
+        let strip_h = strip.min(height - strip_y);
+        let view = img.view(0, strip_y, width, strip_h);
+        for (x, y, pixel) in view.pixels() {
+            accumulator.add(x, strip_y + y, pixel.0);
+        }
+        strip_y += strip_h;
+    }
+    accumulator.finish()
+}
+
+/// Akumulator rata-rata area: setiap piksel sumber dijumlahkan ke sel target yang
+/// menaunginya, sehingga memori yang dipakai hanya seukuran target.
+struct AreaAccumulator {
+    source_height: u32,
+    target_width: u32,
+    target_height: u32,
+    column_map: Vec<usize>,
+    sums: Vec<[f32; 4]>,
+    counts: Vec<u32>,
+    table: Option<[f32; 256]>,
+}
+
+impl AreaAccumulator {
+    /// Dengan `linear`, warna dirata-rata di ruang linear-light.
+    fn new(source: (u32, u32), target: (u32, u32), linear: bool) -> Self {
+        let ((width, height), (target_width, target_height)) = (source, target);
+        let cells = target_width as usize * target_height as usize;
+        Self {
+            source_height: height,
+            target_width,
+            target_height,
+            // Peta kolom sumber ke kolom target dihitung sekali saja
+            column_map: (0..width)
+                .map(|x| (x as u64 * target_width as u64 / width as u64) as usize)
+                .collect(),
+            sums: vec![[0.0; 4]; cells],
+            counts: vec![0; cells],
+            table: linear.then(linear_table),
+        }
+    }
+
+    /// Menambahkan satu piksel RGBA sumber.
+    fn add(&mut self, x: u32, y: u32, pixel: [u8; 4]) {
+        let (Some(&column), true) = (self.column_map.get(x as usize), y < self.source_height) else {
+            return;
+        };
+        let target_y = (y as u64 * self.target_height as u64 / self.source_height as u64) as usize;
+        let index = target_y * self.target_width as usize + column;
+        for c in 0..3 {
+            self.sums[index][c] += self.table.as_ref().map_or(pixel[c] as f32, |table| table[pixel[c] as usize]);
+        }
+        self.sums[index][3] += pixel[3] as f32;
+        self.counts[index] += 1;
+    }
+
+    /// Menambahkan satu baris penuh sampel 8-bit dengan `channels` channel per piksel.
+    fn add_row(&mut self, y: u32, samples: &[u8], channels: usize) {
+        self.add_span(0, y, samples, channels);
+    }
+
+    /// Menambahkan rentang baris yang dimulai dari kolom `left` (strip atau tile).
+    ///
+    /// Grayscale diperluas ke RGB, dan alpha dianggap penuh jika tidak ada.
+    fn add_span(&mut self, left: u32, y: u32, samples: &[u8], channels: usize) {
+        for (i, pixel) in samples.chunks_exact(channels).enumerate() {
+            let rgba = match *pixel {
+                [v] => [v, v, v, 255],
+                [v, a] => [v, v, v, a],
+                [r, g, b] => [r, g, b, 255],
+                [r, g, b, a, ..] => [r, g, b, a],
+                _ => continue,
+            };
+            self.add(left + i as u32, y, rgba);
+        }
+    }
+
+    fn finish(self) -> DynamicImage {
+        let linear = self.table.is_some();
+        let output = RgbaImage::from_fn(self.target_width, self.target_height, |x, y| {
+            let index = (y * self.target_width + x) as usize;
+            let count = self.counts[index].max(1) as f32;
+            let [r, g, b, a] = self.sums[index].map(|sum| sum / count);
+            let component = |value: f32| if linear { linear_to_u8(value) } else { value.round() as u8 };
+            Rgba([component(r), component(g), component(b), a.round() as u8])
+        });
+        DynamicImage::ImageRgba8(output)
+    }
+}
+
+/// Membatasi dimensi ke rentang `u16` yang diterima decoder JPEG.
+fn clamp_u16(value: u32) -> u16 {
+    value.clamp(1, u16::MAX as u32) as u16
+}
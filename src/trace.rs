@@ -0,0 +1,25 @@
+//! Instrumentasi pipeline konversi dengan span `tracing` (feature `tracing`).
+//!
+//! Setiap tahap pipeline (`decode`, `resize`, `adjust`, `map`, `render`) dibungkus
+//! span level `INFO` beserta field input (path, ukuran data, dimensi), sehingga layanan
+//! yang memakai subscriber dapat melihat durasi tiap tahap dan mengaitkan konversi
+//! lambat dengan inputnya. Tanpa feature `tracing`, span tidak dibuat sama sekali.
+
+/// Membuka span tahap pipeline dan mengembalikan guard-nya; span ditutup saat guard di-drop.
+///
+/// Tanpa feature `tracing` makro ini menghasilkan `NoSpan` dan field tidak dievaluasi.
+macro_rules! stage_span {
+    ($name:literal $(, $($fields:tt)*)?) => {{
+        #[cfg(feature = "tracing")]
+        let guard = tracing::info_span!($name $(, $($fields)*)?).entered();
+        #[cfg(not(feature = "tracing"))]
+        let guard = $crate::trace::NoSpan;
+        guard
+    }};
+}
+
+pub(crate) use stage_span;
+
+/// Pengganti guard span ketika feature `tracing` tidak aktif.
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct NoSpan;
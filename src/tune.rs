@@ -0,0 +1,138 @@
+//! Optimasi parameter otomatis berdasarkan skor kualitas.
+
+use crate::converter::{ASCII_CHARS, DETAILED_ASCII_CHARS, HIGH_DENSITY_CHARS};
+use crate::quality::{self, QualityReport};
+use crate::{AsciiConfig, AsciiConverter, BayerMatrix};
+use image::DynamicImage;
+
+/// Ruang pencarian untuk `AsciiConverter::auto_tune`.
+///
+/// Setiap kombinasi nilai dicoba (grid search), sehingga jumlah konversi
+/// adalah hasil kali panjang semua daftar.
+///
+/// * `contrast` - Nilai contrast yang dicoba
+/// * `brightness` - Nilai brightness yang dicoba
+/// * `ramps` - Ramp karakter (gelap → terang) yang dicoba sebagai `custom_chars`
+/// * `invert` - Nilai `invert` yang dicoba
+/// * `dither_noise` - Amplitudo noise dithering acak yang dicoba
+/// * `ordered_dither` - Pola dithering Bayer yang dicoba (`None` = tanpa ordered dithering)
+#[derive(Debug, Clone)]
+pub struct SearchSpace {
+    pub contrast: Vec<f32>,
+    pub brightness: Vec<f32>,
+    pub ramps: Vec<Vec<char>>,
+    pub invert: Vec<bool>,
+    pub dither_noise: Vec<f32>,
+    pub ordered_dither: Vec<Option<BayerMatrix>>,
+}
+
+impl Default for SearchSpace {
+    /// Ruang pencarian default: 4 contrast × 3 brightness × 3 ramp bawaan × tanpa/dengan
+    /// Bayer 4×4, tanpa invert; `dither_noise` mengikuti konfigurasi dasar.
+    fn default() -> Self {
+        Self {
+            contrast: vec![0.8, 1.0, 1.2, 1.5],
+            brightness: vec![0.8, 1.0, 1.2],
+            ramps: vec![
+                ASCII_CHARS.iter().map(|&b| b as char).collect(),
+                DETAILED_ASCII_CHARS.iter().map(|&b| b as char).collect(),
+                HIGH_DENSITY_CHARS.iter().filter_map(|s| s.chars().next()).collect(),
+            ],
+            invert: vec![false],
+            dither_noise: Vec::new(),
+            ordered_dither: vec![None, Some(BayerMatrix::Bayer4)],
+        }
+    }
+}
+
+impl AsciiConverter {
+    /// Mencari kombinasi parameter yang menghasilkan skor kemiripan terbaik untuk gambar tertentu.
+    ///
+    /// Konfigurasi converter saat ini dipakai sebagai dasar (ukuran, mode warna, dll.);
+    /// hanya parameter dalam `search_space` yang divariasikan. Kandidat dinilai
+    /// dengan `quality::score` berdasarkan SSIM tertinggi, lalu MSE terendah jika seri.
+    /// Daftar kosong pada ruang pencarian berarti nilai dari konfigurasi dasar dipertahankan.
+    ///
+    /// # Arguments
+    ///
+    /// * `img` - Gambar yang akan dioptimasi
+    /// * `search_space` - Nilai-nilai parameter yang dicoba
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((AsciiConfig, QualityReport))` - Konfigurasi terbaik beserta skornya
+    /// * `Err(String)` - Pesan error jika konversi gagal
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConverter, SearchSpace};
+    /// use image::{DynamicImage, GrayImage, Luma};
+    ///
+    /// let img = DynamicImage::ImageLuma8(GrayImage::from_fn(80, 40, |x, y| Luma([((x + y) * 2) as u8])));
+    /// let converter = AsciiConverter::new(40, 20);
+    /// let (config, report) = converter.auto_tune(&img, &SearchSpace::default()).unwrap();
+    /// println!(
+    ///     "contrast {} brightness {} dither {:?} ssim {:.3}",
+    ///     config.contrast, config.brightness, config.ordered_dither, report.ssim
+    /// );
+    /// ```
+    pub fn auto_tune(&self, img: &DynamicImage, search_space: &SearchSpace) -> Result<(AsciiConfig, QualityReport), String> {
+        let base = self.config();
+        let contrasts = values_or(&search_space.contrast, base.contrast);
+        let brightnesses = values_or(&search_space.brightness, base.brightness);
+        let inverts = values_or(&search_space.invert, base.invert);
+        let noises = values_or(&search_space.dither_noise, base.dither_noise);
+        let patterns = values_or(&search_space.ordered_dither, base.ordered_dither);
+        let ramps: Vec<Option<Vec<char>>> = if search_space.ramps.is_empty() {
+            vec![base.custom_chars.clone()]
+        } else {
+            search_space.ramps.iter().cloned().map(Some).collect()
+        };
+
+        let mut best: Option<(AsciiConfig, QualityReport)> = None;
+        for &contrast in &contrasts {
+            for &brightness in &brightnesses {
+                for ramp in &ramps {
+                    for &invert in &inverts {
+                        for &dither_noise in &noises {
+                            for &ordered_dither in &patterns {
+                                let candidate = AsciiConfig {
+                                    contrast,
+                                    brightness,
+                                    invert,
+                                    custom_chars: ramp.clone(),
+                                    dither_noise,
+                                    ordered_dither,
+                                    ..base.clone()
+                                };
+                                let art = AsciiConverter::with_config(candidate.clone()).convert_image_to_art(img)?;
+                                let report = quality::score(img, &art);
+
+                                if best.as_ref().is_none_or(|(_, current)| is_better(&report, current)) {
+                                    best = Some((candidate, report));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        best.ok_or_else(|| "Ruang pencarian kosong".to_string())
+    }
+}
+
+/// Mengembalikan daftar nilai, atau nilai dasar jika daftar kosong.
+fn values_or<T: Clone>(values: &[T], base: T) -> Vec<T> {
+    if values.is_empty() {
+        vec![base]
+    } else {
+        values.to_vec()
+    }
+}
+
+/// SSIM lebih tinggi menang; jika sama, MSE lebih rendah menang.
+fn is_better(candidate: &QualityReport, current: &QualityReport) -> bool {
+    candidate.ssim > current.ssim || (candidate.ssim == current.ssim && candidate.mse < current.mse)
+}
@@ -0,0 +1,171 @@
+//! Validasi konfigurasi sebelum konversi.
+
+use crate::AsciiConfig;
+
+/// Jumlah piksel maksimum gambar internal (lebar × tinggi setelah skala), juga
+/// dipakai sebagai jumlah sel maksimum kanvas.
+///
+/// Setara sekitar 1 GB buffer RGBA; konfigurasi di atas batas ini hampir
+/// pasti merupakan kesalahan `scale` atau dimensi.
+pub const MAX_INTERNAL_PIXELS: u64 = 1 << 28;
+
+/// Masalah pada `AsciiConfig` yang ditemukan oleh `validate`.
+///
+/// * `ZeroDimension` - Field ukuran bernilai nol
+/// * `Negative` - Field yang tidak boleh negatif bernilai negatif
+/// * `OutOfRange` - Field berada di luar rentang `min..=max`
+/// * `NotFinite` - Field bernilai NaN atau tak hingga
+/// * `InvalidScale` - `scale` tidak positif
+/// * `InternalImageTooLarge` - Gambar internal melebihi `MAX_INTERNAL_PIXELS`
+/// * `CanvasTooLarge` - Jumlah sel `canvas` melebihi `MAX_INTERNAL_PIXELS`
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigIssue {
+    ZeroDimension { field: &'static str },
+    Negative { field: &'static str, value: f32 },
+    OutOfRange { field: &'static str, value: f32, min: f32, max: f32 },
+    NotFinite { field: &'static str },
+    InvalidScale { value: f32 },
+    InternalImageTooLarge { width: u64, height: u64 },
+    CanvasTooLarge { width: u32, height: u32 },
+}
+
+impl ConfigIssue {
+    /// Pesan yang menjelaskan masalah beserta cara memperbaikinya.
+    pub fn message(&self) -> String {
+        match self {
+            ConfigIssue::ZeroDimension { field } => format!("`{}` harus lebih dari 0", field),
+            ConfigIssue::Negative { field, value } => {
+                format!("`{}` tidak boleh negatif (bernilai {})", field, value)
+            }
+            ConfigIssue::OutOfRange { field, value, min, max } => {
+                format!("`{}` harus berada di antara {} dan {} (bernilai {})", field, min, max, value)
+            }
+            ConfigIssue::NotFinite { field } => format!("`{}` harus berupa angka terhingga", field),
+            ConfigIssue::InvalidScale { value } => format!("`scale` harus lebih dari 0 (bernilai {})", value),
+            ConfigIssue::InternalImageTooLarge { width, height } => format!(
+                "gambar internal {}x{} melebihi batas {} piksel; kurangi `scale`, `width`, atau `height`",
+                width, height, MAX_INTERNAL_PIXELS
+            ),
+            ConfigIssue::CanvasTooLarge { width, height } => format!(
+                "kanvas {}x{} melebihi batas {} sel; kurangi `canvas.width` atau `canvas.height`",
+                width, height, MAX_INTERNAL_PIXELS
+            ),
+        }
+    }
+}
+
+impl AsciiConfig {
+    /// Memeriksa konfigurasi dan mengumpulkan semua masalah yang ditemukan.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Konfigurasi valid
+    /// * `Err(Vec<ConfigIssue>)` - Semua masalah, bukan hanya yang pertama
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use aspix::{AsciiConfig, AsciiConverter, ConfigIssue};
+    /// use image::DynamicImage;
+    ///
+    /// let config = AsciiConfig { width: 0, contrast: -1.0, ..Default::default() };
+    /// let issues = config.validate().unwrap_err();
+    /// assert_eq!(issues[0], ConfigIssue::ZeroDimension { field: "width" });
+    /// assert_eq!(issues[1], ConfigIssue::Negative { field: "contrast", value: -1.0 });
+    ///
+    /// // Converter dengan konfigurasi tidak valid menolak setiap konversi
+    /// let converter = AsciiConverter::with_config(config);
+    /// let error = converter.convert_image(&DynamicImage::new_rgb8(4, 4)).unwrap_err();
+    /// assert!(error.starts_with("Konfigurasi tidak valid"));
+    /// ```
+    pub fn validate(&self) -> Result<(), Vec<ConfigIssue>> {
+        let mut issues = Vec::new();
+
+        for (field, value) in [("width", Some(self.width)), ("height", Some(self.height)), ("tile_height", self.tile_height)] {
+            if value == Some(0) {
+                issues.push(ConfigIssue::ZeroDimension { field });
+            }
+        }
+        for (field, value) in [("html_palette_size", self.html_palette_size), ("dominant_colors", self.dominant_colors)] {
+            if value == Some(0) {
+                issues.push(ConfigIssue::ZeroDimension { field });
+            }
+        }
+        if let Some(canvas) = &self.canvas {
+            for (field, value) in [("canvas.width", canvas.width), ("canvas.height", canvas.height)] {
+                if value == 0 {
+                    issues.push(ConfigIssue::ZeroDimension { field });
+                }
+            }
+            if canvas.width as u64 * canvas.height as u64 > MAX_INTERNAL_PIXELS {
+                issues.push(ConfigIssue::CanvasTooLarge { width: canvas.width, height: canvas.height });
+            }
+        }
+        for (field, value) in [("limits.max_pixels", self.limits.max_pixels), ("limits.max_bytes", self.limits.max_bytes)] {
+            if value == Some(0) {
+                issues.push(ConfigIssue::ZeroDimension { field });
+            }
+        }
+
+        let non_negative = [
+            ("contrast", self.contrast),
+            ("brightness", self.brightness),
+            ("saturation", self.saturation),
+            ("sharpen_amount", self.sharpen_amount),
+            ("sharpen_radius", self.sharpen_radius),
+            ("dither_noise", self.dither_noise),
+            ("channel_gains.r", self.channel_gains[0]),
+            ("channel_gains.g", self.channel_gains[1]),
+            ("channel_gains.b", self.channel_gains[2]),
+        ];
+        for (field, value) in non_negative {
+            if !value.is_finite() {
+                issues.push(ConfigIssue::NotFinite { field });
+            } else if value < 0.0 {
+                issues.push(ConfigIssue::Negative { field, value });
+            }
+        }
+
+        let mut unit_range = vec![("color_saturation", self.color_saturation)];
+        if let Some(mixed) = &self.mixed_charset {
+            unit_range.push(("mixed_charset.dark_threshold", mixed.dark_threshold));
+            unit_range.push(("mixed_charset.light_threshold", mixed.light_threshold));
+        }
+        if let Some(crt) = &self.crt {
+            unit_range.push(("crt.scanline", crt.scanline));
+            unit_range.push(("crt.bleed", crt.bleed));
+            unit_range.push(("crt.vignette", crt.vignette));
+        }
+        if let Some(adaptive) = &self.adaptive_density {
+            unit_range.push(("adaptive_density.detail_threshold", adaptive.detail_threshold));
+        }
+        for (field, value) in unit_range {
+            if !value.is_finite() {
+                issues.push(ConfigIssue::NotFinite { field });
+            } else if !(0.0..=1.0).contains(&value) {
+                issues.push(ConfigIssue::OutOfRange { field, value, min: 0.0, max: 1.0 });
+            }
+        }
+
+        if !self.hue_rotation.is_finite() {
+            issues.push(ConfigIssue::NotFinite { field: "hue_rotation" });
+        }
+
+        if !self.scale.is_finite() || self.scale <= 0.0 {
+            issues.push(ConfigIssue::InvalidScale { value: self.scale });
+        } else {
+            let scale = if self.use_quadrants { 2.0 } else { self.internal_scale() as f64 };
+            let width = (self.width as f64 * scale) as u64;
+            let height = (self.height as f64 * scale) as u64;
+            if width.saturating_mul(height) > MAX_INTERNAL_PIXELS || width > u32::MAX as u64 || height > u32::MAX as u64 {
+                issues.push(ConfigIssue::InternalImageTooLarge { width, height });
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Watch mode: konversi ulang otomatis saat file sumber berubah (feature `watch`).
+
+use crate::AsciiConverter;
+use notify::{Event, RecursiveMode, Watcher};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+/// Jeda untuk mengumpulkan rentetan event dari satu kali simpan di editor.
+const DEBOUNCE: Duration = Duration::from_millis(150);
+
+impl AsciiConverter {
+    /// Memantau file gambar dan menjalankan konversi ulang setiap kali file berubah.
+    ///
+    /// Konversi pertama dijalankan segera. Direktori induk yang dipantau (bukan
+    /// file itu sendiri), sehingga editor yang menyimpan dengan cara
+    /// menulis file sementara lalu me-rename tetap terdeteksi. Fungsi ini
+    /// memblokir thread pemanggil sampai `callback` mengembalikan `false`.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_path` - Path ke file gambar yang dipantau
+    /// * `callback` - Dipanggil dengan hasil setiap konversi; kembalikan `false` untuk berhenti
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Jika pemantauan dihentikan oleh callback
+    /// * `Err(String)` - Pesan error jika watcher gagal dibuat
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use aspix::AsciiConverter;
+    ///
+    /// let converter = AsciiConverter::new(100, 50);
+    /// converter.watch("image.png", |result| {
+    ///     match result {
+    ///         Ok(ascii) => println!("{}", ascii),
+    ///         Err(e) => eprintln!("Error: {}", e),
+    ///     }
+    ///     true
+    /// }).unwrap();
+    /// ```
+    pub fn watch<F>(&self, image_path: &str, mut callback: F) -> Result<(), String>
+    where
+        F: FnMut(Result<String, String>) -> bool,
+    {
+        let path = Path::new(image_path);
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| format!("Path bukan file: {}", image_path))?
+            .to_os_string();
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event: notify::Result<Event>| {
+            let _ = tx.send(event);
+        })
+        .map_err(|e| format!("Gagal membuat watcher: {}", e))?;
+        watcher
+            .watch(&directory, RecursiveMode::NonRecursive)
+            .map_err(|e| format!("Gagal memantau direktori: {}", e))?;
+
+        if !callback(self.convert(image_path)) {
+            return Ok(());
+        }
+
+        while let Ok(event) = rx.recv() {
+            if !touches(&event, &file_name) {
+                continue;
+            }
+
+            // Buang event lanjutan dari penyimpanan yang sama
+            while rx.recv_timeout(DEBOUNCE).is_ok() {}
+
+            if !callback(self.convert(image_path)) {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Mengecek apakah event mengenai file target.
+///
+/// Cukup membandingkan nama file karena hanya satu direktori yang dipantau
+/// secara non-rekursif.
+fn touches(event: &notify::Result<Event>, file_name: &OsStr) -> bool {
+    match event {
+        Ok(event) => {
+            (event.kind.is_create() || event.kind.is_modify())
+                && event.paths.iter().any(|path| path.file_name() == Some(file_name))
+        }
+        Err(_) => false,
+    }
+}
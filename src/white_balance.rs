@@ -0,0 +1,104 @@
+//! White balance otomatis untuk menetralkan color cast (lampu tungsten, bayangan kebiruan).
+//!
+//! Kedua metode menghasilkan gain per channel yang dikalikan ke gambar sebelum
+//! contrast dan brightness, bersama `AsciiConfig::channel_gains`.
+
+use image::DynamicImage;
+
+/// Persentil channel yang dianggap "putih" oleh `WhitePatch`, agar piksel specular tidak mendominasi.
+const WHITE_PATCH_PERCENTILE: f32 = 0.99;
+
+/// Batas gain per channel agar channel yang hampir kosong tidak diperkuat berlebihan.
+const MAX_GAIN: f32 = 4.0;
+
+/// Metode white balance otomatis.
+///
+/// * `GrayWorld` - Rata-rata warna gambar diasumsikan abu-abu netral; setiap channel
+///   diskalakan ke rata-rata ketiga channel sehingga kecerahan keseluruhan terjaga
+/// * `WhitePatch` - Bagian paling terang gambar diasumsikan putih; setiap channel
+///   diskalakan sehingga persentil ke-99-nya menjadi 255 (gambar juga menjadi lebih terang)
+///
+/// # Examples
+///
+/// ```rust
+/// use aspix::{AsciiConfig, WhiteBalance};
+/// use image::{DynamicImage, Rgb, RgbImage};
+///
+/// // Foto dengan cast oranye dari lampu tungsten
+/// let tungsten = DynamicImage::ImageRgb8(RgbImage::from_fn(4, 4, |x, _| {
+///     let value = 40 + x as u8 * 40;
+///     Rgb([value + 60, value + 20, value])
+/// }));
+/// let [red, green, blue] = WhiteBalance::GrayWorld.gains(&tungsten);
+/// assert!(red < 1.0 && blue > 1.0);
+/// assert!((green - 1.0).abs() < 0.1);
+///
+/// let config = AsciiConfig { white_balance: Some(WhiteBalance::GrayWorld), ..Default::default() };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhiteBalance {
+    GrayWorld,
+    WhitePatch,
+}
+
+impl WhiteBalance {
+    /// Menghitung gain `[r, g, b]` untuk gambar; piksel transparan (alpha di bawah 128) diabaikan.
+    ///
+    /// Gain dibatasi hingga 0.25 - 4.0. Gambar tanpa piksel yang dapat dianalisis
+    /// menghasilkan `[1.0, 1.0, 1.0]`.
+    pub fn gains(&self, img: &DynamicImage) -> [f32; 3] {
+        let rgba = img.to_rgba8();
+        let pixels: Vec<[u8; 3]> = rgba
+            .pixels()
+            .filter(|pixel| pixel[3] >= 128)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2]])
+            .collect();
+        if pixels.is_empty() {
+            return [1.0; 3];
+        }
+
+        let reference: [f32; 3] = match self {
+            WhiteBalance::GrayWorld => {
+                let mut sums = [0u64; 3];
+                for pixel in &pixels {
+                    for c in 0..3 {
+                        sums[c] += pixel[c] as u64;
+                    }
+                }
+                sums.map(|sum| sum as f32 / pixels.len() as f32)
+            }
+            WhiteBalance::WhitePatch => {
+                let mut histograms = [[0usize; 256]; 3];
+                for pixel in &pixels {
+                    for c in 0..3 {
+                        histograms[c][pixel[c] as usize] += 1;
+                    }
+                }
+                let target = (pixels.len() as f32 * WHITE_PATCH_PERCENTILE).ceil() as usize;
+                histograms.map(|histogram| {
+                    let mut seen = 0;
+                    let level = histogram
+                        .iter()
+                        .position(|&count| {
+                            seen += count;
+                            seen >= target
+                        })
+                        .unwrap_or(255);
+                    level as f32
+                })
+            }
+        };
+
+        let target = match self {
+            WhiteBalance::GrayWorld => reference.iter().sum::<f32>() / 3.0,
+            WhiteBalance::WhitePatch => 255.0,
+        };
+        reference.map(|level| {
+            if level <= 0.0 {
+                1.0
+            } else {
+                (target / level).clamp(1.0 / MAX_GAIN, MAX_GAIN)
+            }
+        })
+    }
+}